@@ -0,0 +1,105 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using BenchmarkDotNet.Reports;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 记录的基准数据：每项测试的平均耗时（纳秒）
+/// 只在同一台机器上比较才有意义，因此同时记录了机器信息
+/// </summary>
+sealed class Baseline
+{
+    private static readonly JsonSerializerOptions JsonOptions = new JsonSerializerOptions { WriteIndented = true };
+
+    public string Machine { get; set; } = string.Empty;
+    public DateTime RecordedAt { get; set; }
+    public SortedDictionary<string, double> MeanNanoseconds { get; set; } = new SortedDictionary<string, double>(StringComparer.Ordinal);
+
+    /// <summary>
+    /// 从本次运行的结果生成基准数据
+    /// </summary>
+    public static Baseline FromSummaries(IEnumerable<Summary> summaries)
+    {
+        var baseline = new Baseline
+        {
+            Machine = DescribeMachine(),
+            RecordedAt = DateTime.UtcNow
+        };
+        foreach (var (name, mean) in GetMeans(summaries))
+        {
+            baseline.MeanNanoseconds[name] = mean;
+        }
+        return baseline;
+    }
+
+    public static Baseline Load(string path)
+    {
+        if (!File.Exists(path))
+            throw new FileNotFoundException($"基准数据文件不存在，请先使用 --save-baseline 记录: {path}", path);
+
+        return JsonSerializer.Deserialize<Baseline>(File.ReadAllText(path), JsonOptions)
+            ?? throw new InvalidDataException($"基准数据文件为空: {path}");
+    }
+
+    public void Save(string path)
+    {
+        File.WriteAllText(path, JsonSerializer.Serialize(this, JsonOptions));
+    }
+
+    /// <summary>
+    /// 将本次运行的结果与基准数据比较，输出每项测试的耗时比例
+    /// </summary>
+    /// <param name="summaries">本次运行的结果</param>
+    /// <param name="threshold">允许的最大耗时比例（本次 / 基准），超过时视为性能退化</param>
+    /// <returns>没有测试超过阈值时返回true</returns>
+    public bool Compare(IEnumerable<Summary> summaries, double threshold)
+    {
+        if (Machine != DescribeMachine())
+            Console.WriteLine($"// 警告: 基准数据在其他机器上记录（{Machine}），比较结果仅供参考");
+
+        bool passed = true;
+        foreach (var (name, mean) in GetMeans(summaries))
+        {
+            if (!MeanNanoseconds.TryGetValue(name, out var baselineMean))
+            {
+                Console.WriteLine($"// 新增  {name}: {mean:F1} ns（基准数据中没有该测试）");
+                continue;
+            }
+
+            double ratio = mean / baselineMean;
+            bool regressed = ratio > threshold;
+            passed &= !regressed;
+            Console.WriteLine($"// {(regressed ? "退化" : "正常")}  {name}: {baselineMean:F1} ns -> {mean:F1} ns ({ratio:F2}x)");
+        }
+
+        Console.WriteLine(passed
+            ? $"// 所有测试的耗时都在基准的{threshold}倍以内"
+            : $"// 有测试的耗时超过了基准的{threshold}倍，需要在提交说明中给出原因或更新基准数据");
+        return passed;
+    }
+
+    /// <summary>
+    /// 每项测试的名称（类名.方法名 [参数]）和平均耗时，运行失败的测试没有结果，不会返回
+    /// </summary>
+    private static IEnumerable<(string Name, double Mean)> GetMeans(IEnumerable<Summary> summaries)
+    {
+        foreach (var report in summaries.SelectMany(s => s.Reports))
+        {
+            if (report.ResultStatistics == null) continue;
+
+            var benchmark = report.BenchmarkCase;
+            string name = $"{benchmark.Descriptor.Type.Name}.{benchmark.Descriptor.WorkloadMethod.Name} {benchmark.Parameters.DisplayInfo}".TrimEnd();
+            yield return (name, report.ResultStatistics.Mean);
+        }
+    }
+
+    private static string DescribeMachine()
+    {
+        return $"{Environment.MachineName}, {RuntimeInformation.OSDescription}, {RuntimeInformation.ProcessArchitecture}, {Environment.ProcessorCount} cores";
+    }
+}
@@ -0,0 +1,27 @@
+using System.Linq;
+using BenchmarkDotNet.Attributes;
+using Clandom.Models.BalancedRandom;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 大量学号时创建实例（包括计算初始候选池）
+/// </summary>
+[MemoryDiagnoser]
+public class ConstructionBenchmarks
+{
+    private const int Size = 500000;
+    private int[] _numbers = null!;
+
+    [GlobalSetup]
+    public void Setup()
+    {
+        _numbers = Enumerable.Range(0, Size).ToArray();
+    }
+
+    [Benchmark]
+    public int Range() => new BalancedRand(0, Size - 1, loadData: false).CandidatePoolCount;
+
+    [Benchmark]
+    public int List() => new BalancedRand(_numbers, loadData: false).CandidatePoolCount;
+}
@@ -0,0 +1,99 @@
+using System.Linq;
+using BenchmarkDotNet.Attributes;
+using Clandom.Models.BalancedRandom;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 单次抽取和候选池更新
+/// </summary>
+[MemoryDiagnoser]
+public class DrawBenchmarks
+{
+    private BalancedRand _rand = null!;
+
+    [Params(50, 2000, 20000)]
+    public int Size { get; set; }
+
+    [GlobalSetup]
+    public void Setup()
+    {
+        _rand = new BalancedRand(1, Size, loadData: false);
+        _rand.SetSeed(42);
+        
+        // 预先抽取一轮，使抽取次数不全为0
+        for (int i = 0; i < Size; i++)
+        {
+            _rand.Draw(autoSave: false);
+        }
+    }
+
+    [Benchmark]
+    public int Draw() => _rand.Draw(autoSave: false);
+
+    [Benchmark]
+    public void UpdateCandidatePool() => _rand.RefreshCandidatePool();
+}
+
+/// <summary>
+/// 2D抽取
+/// </summary>
+[MemoryDiagnoser]
+public class PlaneBenchmarks
+{
+    private const int DrawCount = 30;
+    private BalancedRandPlane _plane = null!;
+
+    [GlobalSetup]
+    public void Setup()
+    {
+        _plane = new BalancedRandPlane(30, 30, loadData: false);
+        _plane.SetSeed(42);
+    }
+
+    [Benchmark]
+    public int DrawMultiple30()
+    {
+        // 候选池不足时重置，避免 DrawMultiple 抛出异常
+        if (_plane.CandidatePoolCount < DrawCount)
+        {
+            _plane.ResetDrawCounts();
+        }
+        return _plane.DrawMultiplePositions(DrawCount, autoSave: false).Count;
+    }
+}
+
+/// <summary>
+/// 5万个学号时数组存储（范围类型）与字典存储（列表类型）的比较
+/// </summary>
+[MemoryDiagnoser]
+public class StorageBenchmarks
+{
+    private const int Size = 50000;
+    private const int DrawCount = 20;
+    private BalancedRand _rand = null!;
+
+    [Params(true, false)]
+    public bool Dense { get; set; }
+
+    [GlobalSetup]
+    public void Setup()
+    {
+        // 同样的学号，范围类型使用数组存储，列表类型使用字典存储
+        _rand = Dense
+            ? new BalancedRand(1, Size, loadData: false)
+            : new BalancedRand(Enumerable.Range(1, Size), loadData: false);
+        _rand.SetSeed(42);
+    }
+
+    [Benchmark]
+    public int DrawLoop()
+    {
+        int last = 0;
+        for (int i = 0; i < DrawCount; i++)
+        {
+            last = _rand.Draw(autoSave: false);
+        }
+        return last;
+    }
+}
@@ -0,0 +1,61 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 基准测试使用的数据文件，运行时生成，不提交到仓库
+/// </summary>
+static class Fixtures
+{
+    /// <summary>
+    /// 生成一个临时数据文件路径
+    /// </summary>
+    public static string CreateTempPath(string name)
+    {
+        return Path.Combine(Path.GetTempPath(), $"clandom_bench_{name}_{Guid.NewGuid():N}.json");
+    }
+
+    /// <summary>
+    /// 生成包含 entryCount 个范围配置的数据文件，每个配置有 membersPerEntry 个学号和随机的抽取记录
+    /// </summary>
+    public static void WriteDataFile(string path, int entryCount, int membersPerEntry, int seed = 42)
+    {
+        var random = new Random(seed);
+        var allData = new Dictionary<string, BalancedRandData>();
+        
+        for (int i = 0; i < entryCount; i++)
+        {
+            int start = 1 + i * membersPerEntry;
+            int end = start + membersPerEntry - 1;
+            var rand = new BalancedRand(start, end, loadData: false);
+            rand.SetSeed(random.Next());
+            
+            // 每个学号平均被抽中约3次
+            int draws = Math.Min(membersPerEntry * 3, 3000);
+            for (int d = 0; d < draws; d++)
+            {
+                rand.Draw(autoSave: false);
+            }
+            
+            var data = rand.ToData();
+            allData[data.Id] = data;
+        }
+        
+        BalancedRandDataManager.SaveAllData(allData, path);
+    }
+
+    /// <summary>
+    /// 删除生成的数据文件
+    /// </summary>
+    public static void Delete(string path)
+    {
+        if (File.Exists(path))
+        {
+            File.Delete(path);
+        }
+    }
+}
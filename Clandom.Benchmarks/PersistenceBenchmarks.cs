@@ -0,0 +1,58 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using BenchmarkDotNet.Attributes;
+using Clandom.Models.BalancedRandom;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 数据文件的读取和保存
+/// </summary>
+[MemoryDiagnoser]
+public class PersistenceBenchmarks
+{
+    private string _smallFile = null!;
+    private string _largeFile = null!;
+    private string _largeCompressedFile = null!;
+    private BalancedRand _rand = null!;
+
+    [GlobalSetup]
+    public void Setup()
+    {
+        // 保存时文件中共有20个配置（19个其他配置加上被保存的配置）
+        _smallFile = Fixtures.CreateTempPath("save");
+        Fixtures.WriteDataFile(_smallFile, 19, 50);
+        _rand = new BalancedRand(100001, 100050, loadData: false);
+        _rand.SetSeed(42);
+        _rand.DrawMultiple(10, autoSave: false);
+        _rand.SaveData(_smallFile);
+        
+        // 大文件：200个配置，每个2000个学号
+        _largeFile = Fixtures.CreateTempPath("load");
+        Fixtures.WriteDataFile(_largeFile, 200, 2000);
+        
+        // 同样内容的gzip压缩文件
+        _largeCompressedFile = Fixtures.CreateTempPath("load_gz");
+        File.Copy(_largeFile, _largeCompressedFile);
+        BalancedRandDataManager.ConvertCompression(_largeCompressedFile, DataCompression.Gzip());
+        Console.WriteLine($"// 数据文件大小: {new FileInfo(_largeFile).Length} 字节，gzip压缩后: {new FileInfo(_largeCompressedFile).Length} 字节");
+    }
+
+    [GlobalCleanup]
+    public void Cleanup()
+    {
+        Fixtures.Delete(_smallFile);
+        Fixtures.Delete(_largeFile);
+        Fixtures.Delete(_largeCompressedFile);
+    }
+
+    [Benchmark]
+    public void SaveData() => _rand.SaveData(_smallFile);
+
+    [Benchmark]
+    public Dictionary<string, BalancedRandData> LoadAllData() => BalancedRandDataManager.LoadAllData(_largeFile);
+
+    [Benchmark]
+    public Dictionary<string, BalancedRandData> LoadAllDataCompressed() => BalancedRandDataManager.LoadAllData(_largeCompressedFile);
+}
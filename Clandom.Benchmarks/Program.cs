@@ -0,0 +1,62 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Linq;
+using BenchmarkDotNet.Running;
+
+namespace Clandom.Benchmarks;
+
+/// <summary>
+/// 性能基准测试入口
+/// 用法: dotnet run -c Release --project Clandom.Benchmarks -- [--filter *Draw*]
+///       [--save-baseline baseline.json] [--compare baseline.json [--threshold 1.5]]
+/// 使用 --compare 时，有测试的平均耗时超过基准的 threshold 倍则返回1
+/// </summary>
+static class Program
+{
+    private const double DefaultThreshold = 1.5;
+
+    public static int Main(string[] args)
+    {
+        var benchmarkArgs = new List<string>(args);
+        string? savePath = TakeOption(benchmarkArgs, "--save-baseline");
+        string? comparePath = TakeOption(benchmarkArgs, "--compare");
+        string? thresholdText = TakeOption(benchmarkArgs, "--threshold");
+        double threshold = thresholdText == null
+            ? DefaultThreshold
+            : double.Parse(thresholdText, CultureInfo.InvariantCulture);
+
+        // 先读取基准数据，避免运行完所有测试后才发现文件不存在
+        var baseline = comparePath == null ? null : Baseline.Load(comparePath);
+
+        var summaries = BenchmarkSwitcher.FromAssembly(typeof(Program).Assembly).Run(benchmarkArgs.ToArray()).ToList();
+
+        if (savePath != null)
+        {
+            Baseline.FromSummaries(summaries).Save(savePath);
+            Console.WriteLine($"// 基准数据已保存到 {savePath}");
+        }
+
+        if (baseline != null && !baseline.Compare(summaries, threshold))
+        {
+            return 1;
+        }
+
+        return 0;
+    }
+
+    /// <summary>
+    /// 取出 "名称 值" 形式的参数（其余参数原样传给 BenchmarkDotNet），不存在时返回null
+    /// </summary>
+    private static string? TakeOption(List<string> args, string name)
+    {
+        int index = args.IndexOf(name);
+        if (index < 0) return null;
+        if (index == args.Count - 1)
+            throw new ArgumentException($"参数 {name} 缺少值");
+
+        string value = args[index + 1];
+        args.RemoveRange(index, 2);
+        return value;
+    }
+}
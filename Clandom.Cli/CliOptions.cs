@@ -0,0 +1,73 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Clandom.Cli;
+
+/// <summary>
+/// 命令行参数
+/// </summary>
+class CliOptions
+{
+    public string Command { get; private set; } = "";
+    public (int Start, int End)? Range { get; private set; }
+    public List<int>? List { get; private set; }
+    public int Count { get; private set; } = 1;
+    public string DataFile { get; private set; } = "balanced_rand_data.json";
+    public bool Json { get; private set; }
+
+    public static CliOptions Parse(string[] args)
+    {
+        if (args.Length == 0)
+            throw new ArgumentException("缺少命令");
+
+        var options = new CliOptions { Command = args[0] };
+
+        for (int i = 1; i < args.Length; i++)
+        {
+            switch (args[i])
+            {
+                case "--range":
+                    options.Range = (ParseInt(args, ++i, "--range"), ParseInt(args, ++i, "--range"));
+                    break;
+                case "--list":
+                    options.List = RequireValue(args, ++i, "--list")
+                        .Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries)
+                        .Select(v => int.TryParse(v, out var n) ? n : throw new ArgumentException($"无效的学号: {v}"))
+                        .ToList();
+                    break;
+                case "--count":
+                    options.Count = ParseInt(args, ++i, "--count");
+                    break;
+                case "--data-file":
+                    options.DataFile = RequireValue(args, ++i, "--data-file");
+                    break;
+                case "--json":
+                    options.Json = true;
+                    break;
+                default:
+                    throw new ArgumentException($"未知参数: {args[i]}");
+            }
+        }
+
+        if (options.Range.HasValue && options.List != null)
+            throw new ArgumentException("--range 和 --list 不能同时使用");
+
+        return options;
+    }
+
+    private static string RequireValue(string[] args, int index, string name)
+    {
+        if (index >= args.Length)
+            throw new ArgumentException($"{name} 缺少参数值");
+        return args[index];
+    }
+
+    private static int ParseInt(string[] args, int index, string name)
+    {
+        var value = RequireValue(args, index, name);
+        if (!int.TryParse(value, out var result))
+            throw new ArgumentException($"{name} 的参数值不是整数: {value}");
+        return result;
+    }
+}
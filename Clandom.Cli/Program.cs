@@ -0,0 +1,173 @@
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text.Json;
+using Clandom.Models.BalancedRandom;
+
+namespace Clandom.Cli;
+
+/// <summary>
+/// 命令行抽取工具
+/// </summary>
+static class Program
+{
+    private const string Usage =
+        """
+        用法:
+          clandom draw  (--range <起始> <结束> | --list <学号,学号,...>) [--count <数量>] [选项]
+          clandom stats (--range <起始> <结束> | --list <学号,学号,...>) [选项]
+          clandom reset (--range <起始> <结束> | --list <学号,学号,...>) [选项]
+
+        选项:
+          --data-file <路径>  数据文件路径（默认 balanced_rand_data.json）
+          --json              以JSON格式输出
+
+        退出代码:
+          0  成功
+          1  无法抽取（如没有可抽取的学号）
+          2  参数错误
+          3  数据文件无法读取或写入（如格式错误、校验失败、已加密或没有写入权限），此时不会保存任何数据
+        """;
+
+    private static readonly JsonSerializerOptions JsonOptions = new JsonSerializerOptions
+    {
+        WriteIndented = true
+    };
+
+    public static int Main(string[] args)
+    {
+        try
+        {
+            var options = CliOptions.Parse(args);
+            var rand = CreateInstance(options);
+
+            switch (options.Command)
+            {
+                case "draw":
+                    return RunDraw(rand, options);
+                case "stats":
+                    return RunStats(rand, options);
+                case "reset":
+                    return RunReset(rand, options);
+                default:
+                    throw new ArgumentException($"未知命令: {options.Command}");
+            }
+        }
+        catch (ArgumentException ex)
+        {
+            Console.Error.WriteLine($"错误: {ex.Message}");
+            Console.Error.WriteLine();
+            Console.Error.WriteLine(Usage);
+            return 2;
+        }
+        catch (InvalidOperationException ex)
+        {
+            Console.Error.WriteLine($"错误: {ex.Message}");
+            return 1;
+        }
+        catch (Exception ex) when (ex is IOException or InvalidDataException or JsonException or UnauthorizedAccessException)
+        {
+            // ChecksumMismatchException 也是 InvalidDataException；JsonException 为数据文件不是合法的JSON
+            Console.Error.WriteLine($"数据文件错误: {ex.Message}");
+            return 3;
+        }
+    }
+
+    /// <summary>
+    /// 根据参数创建抽取实例并从指定数据文件加载历史数据，文件无法读取或数据不合法时抛出异常
+    /// </summary>
+    private static BalancedRand CreateInstance(CliOptions options)
+    {
+        BalancedRand rand;
+        if (options.Range.HasValue)
+        {
+            rand = new BalancedRand(options.Range.Value.Start, options.Range.Value.End, loadData: false);
+        }
+        else if (options.List != null)
+        {
+            rand = new BalancedRand(options.List, loadData: false);
+        }
+        else
+        {
+            throw new ArgumentException("必须指定 --range 或 --list");
+        }
+
+        rand.LoadDataStrict(options.DataFile);
+        return rand;
+    }
+
+    private static int RunDraw(BalancedRand rand, CliOptions options)
+    {
+        var numbers = options.Count == 1
+            ? new List<int> { rand.Draw(autoSave: false) }
+            : rand.DrawMultiple(options.Count, autoSave: false);
+        Save(rand, options);
+
+        if (options.Json)
+        {
+            Console.WriteLine(JsonSerializer.Serialize(new { numbers }, JsonOptions));
+        }
+        else
+        {
+            foreach (var number in numbers)
+            {
+                Console.WriteLine(number);
+            }
+        }
+        return 0;
+    }
+
+    private static int RunStats(BalancedRand rand, CliOptions options)
+    {
+        var counts = rand.GetStatisticsList();
+        var probabilities = rand.GetProbabilityList();
+        var rows = rand
+            .Select((number, i) => new { number, count = counts[i], probability = probabilities[i] })
+            .ToList();
+
+        if (options.Json)
+        {
+            Console.WriteLine(JsonSerializer.Serialize(new
+            {
+                totalDraws = rand.GetTotalDraws(),
+                currentRound = rand.GetCurrentRound(),
+                members = rows
+            }, JsonOptions));
+        }
+        else
+        {
+            Console.WriteLine($"总抽取次数: {rand.GetTotalDraws()}  当前轮次: {rand.GetCurrentRound()}");
+            Console.WriteLine("学号\t次数\t概率");
+            foreach (var row in rows)
+            {
+                Console.WriteLine($"{row.number}\t{row.count}\t{row.probability:P2}");
+            }
+        }
+        return 0;
+    }
+
+    private static int RunReset(BalancedRand rand, CliOptions options)
+    {
+        rand.ResetDrawCounts();
+        Save(rand, options);
+
+        if (options.Json)
+        {
+            Console.WriteLine(JsonSerializer.Serialize(new { reset = rand.GetDataId() }, JsonOptions));
+        }
+        else
+        {
+            Console.WriteLine($"已重置: {rand.GetDataId()}");
+        }
+        return 0;
+    }
+
+    /// <summary>
+    /// 保存数据，失败时抛出异常（SaveData 只记录日志，脚本无法知道数据没有保存）
+    /// </summary>
+    private static void Save(BalancedRand rand, CliOptions options)
+    {
+        rand.SaveDataAsync(options.DataFile).GetAwaiter().GetResult();
+    }
+}
@@ -0,0 +1,95 @@
+using System;
+using System.Linq;
+using System.Text.Json;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class AnomalyTests
+{
+    private static BalancedRand CreateRand()
+    {
+        var rand = new BalancedRand(1, 10, loadData: false);
+        rand.SetSeed(6);
+        return rand;
+    }
+
+    [Fact]
+    public void BalancedHistory_HasNoAnomalies()
+    {
+        var rand = CreateRand();
+        for (int i = 0; i < 60; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        Assert.Empty(rand.DetectAnomalies(10));
+    }
+
+    [Fact]
+    public void RepeatedWinner_IsFlaggedAsFrequent()
+    {
+        var rand = CreateRand();
+        for (int i = 0; i < 20; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        for (int i = 0; i < 6; i++)
+        {
+            rand.DrawFromSubset(new[] { 4 }, autoSave: false);
+        }
+
+        var anomaly = Assert.Single(rand.DetectAnomalies(10), a => a.Kind == AnomalyKind.FrequentDraws);
+
+        Assert.Equal(4, anomaly.Number);
+        Assert.InRange(anomaly.Observed, 6, 10);
+        Assert.Equal(1.0, anomaly.Expected, 12);
+        Assert.True(anomaly.PValue < 0.01);
+    }
+
+    [Fact]
+    public void SkippedMember_IsFlaggedAsLongWait()
+    {
+        var rand = CreateRand();
+        for (int i = 0; i < 60; i++)
+        {
+            rand.DrawExcluding(new[] { 7 }, autoSave: false);
+        }
+
+        var anomalies = rand.DetectAnomalies(10);
+
+        var anomaly = Assert.Single(anomalies, a => a.Kind == AnomalyKind.LongWait);
+        Assert.Equal(7, anomaly.Number);
+        Assert.Equal(60, anomaly.Observed);
+        Assert.Equal(9.0, anomaly.Expected, 12);
+        Assert.Equal(Math.Pow(0.9, 60), anomaly.PValue, 12);
+        Assert.DoesNotContain(anomalies, a => a.Kind == AnomalyKind.FrequentDraws);
+    }
+
+    [Fact]
+    public void BlacklistedMember_IsNotReported()
+    {
+        var rand = CreateRand();
+        for (int i = 0; i < 60; i++)
+        {
+            rand.DrawExcluding(new[] { 7 }, autoSave: false);
+        }
+
+        rand.AddToBlacklist(7);
+
+        Assert.DoesNotContain(rand.DetectAnomalies(10), a => a.Number == 7);
+    }
+
+    [Fact]
+    public void Anomaly_SerializesWithStableFieldNames()
+    {
+        var anomaly = new DrawAnomaly { Kind = AnomalyKind.LongWait, Number = 7, Observed = 60, Expected = 9, PValue = 0.5 };
+
+        using var document = JsonDocument.Parse(anomaly.ToJson());
+
+        Assert.Equal(new[] { "kind", "number", "observed", "expected", "pValue" },
+            document.RootElement.EnumerateObject().Select(p => p.Name));
+        Assert.Equal("LongWait", document.RootElement.GetProperty("kind").GetString());
+    }
+}
@@ -0,0 +1,27 @@
+using System.IO;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class AuditLogTests
+{
+    [Fact]
+    public void SilentDrawsAndClones_DoNotWriteAuditLog()
+    {
+        using var log = new TempDataFile();
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetAuditLog(log.Path);
+
+        rand.DrawSilent();
+        Assert.False(File.Exists(log.Path));
+
+        var clone = rand.CloneWithHistory("copy");
+        Assert.Null(clone.GetAuditLog());
+        clone.Draw(autoSave: false);
+        Assert.False(File.Exists(log.Path));
+
+        rand.Draw(autoSave: false);
+        Assert.Single(File.ReadAllLines(log.Path));
+    }
+}
@@ -0,0 +1,73 @@
+using System;
+using System.Collections.Concurrent;
+using System.Threading;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class AutoReloadTests
+{
+    /// <summary>
+    /// 把 Post 的回调放入队列，由测试线程执行（模拟界面线程）
+    /// </summary>
+    private sealed class QueueSynchronizationContext : SynchronizationContext
+    {
+        private readonly BlockingCollection<(SendOrPostCallback Callback, object? State)> _queue = new();
+
+        public override void Post(SendOrPostCallback d, object? state) => _queue.Add((d, state));
+
+        public bool RunOne(TimeSpan timeout)
+        {
+            if (!_queue.TryTake(out var item, timeout)) return false;
+            item.Callback(item.State);
+            return true;
+        }
+    }
+
+    [Fact]
+    public void ExternalWrite_IsAppliedOnCapturedContext()
+    {
+        using var file = new TempDataFile();
+        var rand = new BalancedRand(1, 5, loadData: false);
+        var context = new QueueSynchronizationContext();
+        int testThread = Environment.CurrentManagedThreadId;
+        int? reloadedOn = null;
+        rand.DataReloaded += (_, _) => reloadedOn = Environment.CurrentManagedThreadId;
+
+        var previous = SynchronizationContext.Current;
+        SynchronizationContext.SetSynchronizationContext(context);
+        try
+        {
+            rand.EnableAutoReload(TimeSpan.FromMilliseconds(50), file.Path);
+        }
+        finally
+        {
+            SynchronizationContext.SetSynchronizationContext(previous);
+        }
+
+        try
+        {
+            // 另一个程序保存了同一份数据
+            var other = new BalancedRand(1, 5, loadData: false);
+            other.Draw(autoSave: false);
+            other.Draw(autoSave: false);
+            other.SaveData(file.Path);
+
+            var deadline = DateTime.UtcNow + TimeSpan.FromSeconds(10);
+            while (reloadedOn == null && DateTime.UtcNow < deadline)
+            {
+                // 回调执行之前不会在后台线程中修改实例
+                Assert.Equal(0, rand.GetTotalDraws());
+                context.RunOne(TimeSpan.FromMilliseconds(100));
+            }
+
+            Assert.Equal(testThread, reloadedOn);
+            Assert.Equal(2, rand.GetTotalDraws());
+        }
+        finally
+        {
+            rand.DisableAutoReload();
+        }
+    }
+}
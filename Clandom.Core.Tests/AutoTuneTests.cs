@@ -0,0 +1,42 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class AutoTuneTests
+{
+    private static readonly FairnessTarget Target = new() { Draws = 40, MaxProbability = 0.6 };
+    private static readonly TuneBudget Budget = new() { Steps = 3, Seed = 8 };
+
+    [Fact]
+    public void AutoTune_IsDeterministicAndDoesNotMutate()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.Draw(autoSave: false);
+        var before = rand.ToData();
+
+        var first = rand.AutoTune(Target, Budget);
+        var second = rand.AutoTune(Target, Budget);
+
+        Assert.Equal(before, rand.ToData());
+        Assert.Equal(9, first.Evaluated);
+        Assert.Equal(first.DecayFactor, second.DecayFactor);
+        Assert.Equal(first.ColdStartBoost, second.ColdStartBoost);
+        Assert.Equal(first.Simulation.Counts, second.Simulation.Counts);
+        Assert.Equal(first.Simulation.MaxGap, second.Simulation.MaxGap);
+        Assert.InRange(first.DecayFactor, Budget.DecayFactorMin, Budget.DecayFactorMax);
+        Assert.InRange(first.ColdStartBoost, Budget.ColdStartBoostMin, Budget.ColdStartBoostMax);
+    }
+
+    [Fact]
+    public void ApplyTuneResult_UpdatesParameters()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        var result = rand.AutoTune(Target, Budget);
+
+        rand.ApplyTuneResult(result);
+
+        Assert.Equal(result.DecayFactor, rand.GetDecayFactor());
+        Assert.Equal(result.ColdStartBoost, rand.GetColdStartBoost());
+    }
+}
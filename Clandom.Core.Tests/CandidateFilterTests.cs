@@ -0,0 +1,24 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class CandidateFilterTests
+{
+    [Fact]
+    public void Filter_RefillsPoolFromAllowedNumbers()
+    {
+        var rand = new BalancedRand(1, 4, loadData: false);
+        rand.SetSeed(7);
+        rand.SetCandidateFilter(n => n == 4);
+
+        for (int i = 0; i < 10; i++)
+        {
+            Assert.Equal(4, rand.Draw(autoSave: false));
+            Assert.Equal(new[] { 4 }, rand.GetCandidatePoolList());
+        }
+
+        Assert.Equal(0, rand.GetCyclesCompleted());
+        Assert.Equal(new[] { 0, 0, 0, 10 }, rand.GetStatisticsList());
+    }
+}
@@ -0,0 +1,112 @@
+using System.Collections.Generic;
+using System.IO;
+using System.Text.Json;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class ChecksumTests
+{
+    private static BalancedRand CreateDrawn(int draws)
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.SetSeed(3);
+        for (int i = 0; i < draws; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        return rand;
+    }
+
+    private static void FlipTotalDraws(string path)
+    {
+        string original = File.ReadAllText(path);
+        string corrupted = original.Replace("\"TotalDraws\": 3", "\"TotalDraws\": 8");
+        Assert.NotEqual(original, corrupted);
+        File.WriteAllText(path, corrupted);
+    }
+
+    [Fact]
+    public void CorruptedPayload_WithoutBackup_Throws()
+    {
+        using var file = new TempDataFile();
+        CreateDrawn(3).SaveData(file.Path);
+        FlipTotalDraws(file.Path);
+
+        Assert.Throws<ChecksumMismatchException>(() => BalancedRandDataManager.ReadAllData(file.Path));
+        Assert.Throws<InvalidDataException>(() =>
+            BalancedRandDataManager.LoadAllData(file.Path, ValidationMode.Strict, out _));
+    }
+
+    [Fact]
+    public void CorruptedPayload_FallsBackToBackup()
+    {
+        using var file = new TempDataFile();
+        var rand = CreateDrawn(2);
+        rand.SaveData(file.Path);
+        rand.Draw(autoSave: false);
+        rand.SaveData(file.Path);
+        FlipTotalDraws(file.Path);
+
+        var allData = BalancedRandDataManager.ReadAllData(file.Path);
+
+        Assert.Equal(2, allData[rand.GetDataId()].TotalDraws);
+    }
+
+    [Fact]
+    public void EnvelopeWithoutPayload_IsCorrupt()
+    {
+        using var file = new TempDataFile();
+        var rand = CreateDrawn(2);
+        rand.SaveData(file.Path);
+        rand.Draw(autoSave: false);
+        rand.SaveData(file.Path);
+        File.WriteAllText(file.Path, "{\"Checksum\": \"00\", \"Data\": {}}");
+
+        Assert.Equal(2, BalancedRandDataManager.ReadAllData(file.Path)[rand.GetDataId()].TotalDraws);
+
+        File.Delete(file.Path + ".bak");
+        Assert.Throws<ChecksumMismatchException>(() => BalancedRandDataManager.ReadAllData(file.Path));
+    }
+
+    [Fact]
+    public void Checksum_IsIndependentOfPropertyOrder()
+    {
+        var a = JsonDocument.Parse("{\"a\": 1, \"b\": {\"x\": [1, 2], \"y\": \"z\"}}").RootElement;
+        var b = JsonDocument.Parse("{\"b\": {\"y\": \"z\", \"x\": [1, 2]}, \"a\": 1}").RootElement;
+        var c = JsonDocument.Parse("{\"a\": 1, \"b\": {\"x\": [2, 1], \"y\": \"z\"}}").RootElement;
+
+        Assert.Equal(BalancedRandDataManager.ComputeChecksum(a), BalancedRandDataManager.ComputeChecksum(b));
+        Assert.NotEqual(BalancedRandDataManager.ComputeChecksum(a), BalancedRandDataManager.ComputeChecksum(c));
+    }
+
+    [Fact]
+    public void Checksum_IsIndependentOfDictionaryInsertionOrder()
+    {
+        var first = CreateDrawn(3).ToData();
+        var second = new BalancedRand(1, 9, loadData: false).ToData();
+        var forward = new Dictionary<string, BalancedRandData> { [first.Id] = first, [second.Id] = second };
+        var backward = new Dictionary<string, BalancedRandData> { [second.Id] = second, [first.Id] = first };
+
+        Assert.Equal(
+            BalancedRandDataManager.ComputeChecksum(JsonSerializer.SerializeToElement(forward, BalancedRandDataManager.JsonOptions)),
+            BalancedRandDataManager.ComputeChecksum(JsonSerializer.SerializeToElement(backward, BalancedRandDataManager.JsonOptions)));
+    }
+
+    [Fact]
+    public void LegacyFileWithoutEnvelope_LoadsAndIsUpgradedOnSave()
+    {
+        using var file = new TempDataFile();
+        var data = CreateDrawn(3).ToData();
+        var allData = new Dictionary<string, BalancedRandData> { [data.Id] = data };
+        File.WriteAllText(file.Path, JsonSerializer.Serialize(allData, BalancedRandDataManager.JsonOptions));
+
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(file.Path));
+
+        BalancedRandDataManager.SaveAllData(BalancedRandDataManager.ReadAllData(file.Path), file.Path);
+        using var document = JsonDocument.Parse(File.ReadAllText(file.Path));
+        Assert.True(document.RootElement.TryGetProperty("Checksum", out _));
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(file.Path));
+    }
+}
@@ -0,0 +1,61 @@
+using System.Collections.Generic;
+using System.IO;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class CompressionTests
+{
+    private static Dictionary<string, BalancedRandData> CreateLargeData()
+    {
+        var plane = new BalancedRandPlane(60, 60, loadData: false);
+        plane.SetSeed(1);
+        for (int i = 0; i < 500; i++)
+        {
+            plane.Draw(autoSave: false);
+        }
+        var data = plane.ToData();
+        return new Dictionary<string, BalancedRandData> { [data.Id] = data };
+    }
+
+    private static bool StartsWithGzipMagic(string path)
+    {
+        var bytes = File.ReadAllBytes(path);
+        return bytes.Length >= 2 && bytes[0] == 0x1f && bytes[1] == 0x8b;
+    }
+
+    [Fact]
+    public void Compressed_RoundTripsAndIsSmaller()
+    {
+        using var plain = new TempDataFile();
+        using var compressed = new TempDataFile();
+        var allData = CreateLargeData();
+
+        BalancedRandDataManager.SaveAllData(allData, plain.Path);
+        BalancedRandDataManager.SaveAllDataCompressed(allData, compressed.Path);
+
+        Assert.True(StartsWithGzipMagic(compressed.Path));
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(compressed.Path));
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(plain.Path));
+        Assert.True(new FileInfo(compressed.Path).Length * 4 < new FileInfo(plain.Path).Length,
+            $"gzip: {new FileInfo(compressed.Path).Length}, json: {new FileInfo(plain.Path).Length}");
+    }
+
+    [Fact]
+    public void ConvertCompression_MigratesInPlace()
+    {
+        using var file = new TempDataFile();
+        var allData = CreateLargeData();
+        BalancedRandDataManager.SaveAllData(allData, file.Path);
+
+        BalancedRandDataManager.ConvertCompression(file.Path, DataCompression.Gzip());
+        Assert.True(StartsWithGzipMagic(file.Path));
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(file.Path));
+
+        BalancedRandDataManager.ConvertCompression(file.Path, DataCompression.None);
+        Assert.False(StartsWithGzipMagic(file.Path));
+        Assert.Equal(allData, BalancedRandDataManager.ReadAllData(file.Path));
+        Assert.False(File.Exists(file.Path + ".tmp"));
+    }
+}
@@ -0,0 +1,60 @@
+using System.Collections.Generic;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class CoverageTests
+{
+    private static readonly (int row, int col)[] Blacklisted = { (1, 1), (2, 3), (4, 4) };
+
+    private static BalancedRandPlane CreatePlane(int seed)
+    {
+        var plane = new BalancedRandPlane(4, 4, loadData: false);
+        plane.SetSeed(seed);
+        plane.AddToBlacklistPositions(Blacklisted);
+        return plane;
+    }
+
+    [Theory]
+    [InlineData(1)]
+    [InlineData(7)]
+    [InlineData(42)]
+    public void DrawUncovered_VisitsEveryPositionOnceBeforeRepeating(int seed)
+    {
+        var plane = CreatePlane(seed);
+        int required = 16 - Blacklisted.Length;
+        var seen = new HashSet<(int row, int col)>();
+
+        for (int i = 0; i < required; i++)
+        {
+            var (row, col, fromUncovered) = plane.DrawUncovered(autoSave: false);
+
+            Assert.True(fromUncovered);
+            Assert.DoesNotContain((row, col), Blacklisted);
+            Assert.True(seen.Add((row, col)), $"位置({row}, {col})在覆盖完成前被重复抽中");
+            Assert.Equal((double)seen.Count / required, plane.GetCoverage(), 12);
+            Assert.Equal(required - seen.Count, plane.GetUncoveredPositions().Count);
+        }
+
+        Assert.Empty(plane.GetUncoveredPositions());
+        Assert.Equal(1.0, plane.GetCoverage());
+
+        var after = plane.DrawUncovered(autoSave: false);
+        Assert.False(after.FromUncovered);
+        Assert.DoesNotContain((after.row, after.col), Blacklisted);
+    }
+
+    [Fact]
+    public void UncoveredPositions_ExcludeBlacklistAndAreRowMajor()
+    {
+        var plane = CreatePlane(3);
+
+        var uncovered = plane.GetUncoveredPositions();
+
+        Assert.Equal(13, uncovered.Count);
+        Assert.Equal((1, 2), uncovered[0]);
+        Assert.Equal((4, 3), uncovered[^1]);
+        Assert.Equal(0.0, plane.GetCoverage());
+    }
+}
@@ -0,0 +1,45 @@
+using System.Collections.Generic;
+using System.IO;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class CsvImportTests
+{
+    private static BalancedRand Import(string csv, out List<string> warnings)
+    {
+        using var file = new TempDataFile();
+        File.WriteAllText(file.Path, csv);
+        return BalancedRand.FromCsv(file.Path, out warnings, loadData: false);
+    }
+
+    [Fact]
+    public void QuotedFields_KeepCommasAndEscapedQuotes()
+    {
+        var rand = Import("学号,姓名\n1,\"Li, Wei\"\r\n2,\"He said \"\"hi\"\"\"\n\"3\", Zhang San \n", out var warnings);
+
+        Assert.Empty(warnings);
+        Assert.Equal(3, rand.Count);
+        Assert.Equal("Li, Wei", rand.GetLabel(1));
+        Assert.Equal("He said \"hi\"", rand.GetLabel(2));
+        Assert.Equal("Zhang San", rand.GetLabel(3));
+    }
+
+    [Fact]
+    public void QuotedNewline_ReportsLineOfFollowingRows()
+    {
+        Import("1,\"Li\nWei\"\nabc,Wang\n2,Zhao\n", out var warnings);
+
+        Assert.Equal(new[] { "第3行: 无效的学号 \"abc\"" }, warnings);
+    }
+
+    [Fact]
+    public void UnterminatedQuote_SkipsRowWithWarning()
+    {
+        var rand = Import("1,Li\n2,\"Wang\n", out var warnings);
+
+        Assert.Equal(1, rand.Count);
+        Assert.Equal(new[] { "第2行: 引号未闭合" }, warnings);
+    }
+}
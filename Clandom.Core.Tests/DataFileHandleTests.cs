@@ -0,0 +1,46 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class DataFileHandleTests
+{
+    [Fact]
+    public void SaveData_PicksUpExternalWriteBetweenSaves()
+    {
+        using var file = new TempDataFile();
+        var handle = new DataFileHandle(file.Path);
+        var first = new BalancedRand(1, 5, loadData: false);
+        first.Draw(autoSave: false);
+        first.SaveData(handle);
+
+        // 另一个实例绕过句柄直接写入文件
+        var external = new BalancedRand(1, 8, loadData: false);
+        external.Draw(autoSave: false);
+        external.SaveData(file.Path);
+
+        first.Draw(autoSave: false);
+        first.SaveData(handle);
+
+        var allData = BalancedRandDataManager.ReadAllData(file.Path);
+        Assert.Equal(2, allData[first.GetDataId()].TotalDraws);
+        Assert.Equal(1, allData[external.GetDataId()].TotalDraws);
+    }
+
+    [Fact]
+    public void LoadData_DoesNotShareCollectionsWithCache()
+    {
+        using var file = new TempDataFile();
+        var handle = new DataFileHandle(file.Path);
+        var original = new BalancedRand(1, 5, loadData: false);
+        original.AddToBlacklist(2);
+        original.SaveData(handle);
+
+        var loaded = new BalancedRand(1, 5, loadData: false);
+        loaded.LoadData(handle);
+        loaded.AddToBlacklist(3);
+
+        Assert.True(handle.TryGetEntry(original.GetDataId(), out var cached));
+        Assert.Equal(new[] { 2 }, cached.Blacklist);
+    }
+}
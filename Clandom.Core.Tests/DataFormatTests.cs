@@ -0,0 +1,133 @@
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class DataFormatTests
+{
+    public static TheoryData<DataFormat, bool> Cases
+    {
+        get
+        {
+            var cases = new TheoryData<DataFormat, bool>();
+            foreach (var format in new[] { DataFormat.Json, DataFormat.Yaml, DataFormat.Toml })
+            {
+                cases.Add(format, false);
+                cases.Add(format, true);
+            }
+            return cases;
+        }
+    }
+
+    private static BalancedRandData CreateData(bool plane)
+    {
+        BalancedRand rand;
+        if (plane)
+        {
+            var planeRand = new BalancedRandPlane(3, 4, loadData: false);
+            planeRand.AddToBlacklistPositions((1, 1), (2, 3));
+            planeRand.AddToWhitelistPositions((3, 4));
+            rand = planeRand;
+        }
+        else
+        {
+            rand = new BalancedRand(1, 8, loadData: false);
+            rand.AddToBlacklist(2, 5);
+            rand.AddToWhitelist(3, 42);
+            rand.SetLabel(3, "张三 \"班长\"");
+            rand.SetGroup(4, 1);
+        }
+
+        rand.SetSeed(11);
+        for (int i = 0; i < 6; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        return rand.ToData();
+    }
+
+    private static string Export(BalancedRandData data, DataFormat format) => format switch
+    {
+        DataFormat.Yaml => data.ToYaml(),
+        DataFormat.Toml => data.ToToml(),
+        _ => DataFormatConverter.ToJson(data)
+    };
+
+    private static BalancedRandData Import(string source, DataFormat format) => format switch
+    {
+        DataFormat.Yaml => BalancedRandData.FromYaml(source),
+        DataFormat.Toml => BalancedRandData.FromToml(source),
+        _ => DataFormatConverter.FromJson(source)
+    };
+
+    [Theory]
+    [MemberData(nameof(Cases))]
+    public void RoundTrip_PreservesData(DataFormat format, bool plane)
+    {
+        var data = CreateData(plane);
+
+        var restored = Import(Export(data, format), format);
+
+        Assert.Equal(data, restored);
+        Assert.NotEmpty(restored.Blacklist);
+        Assert.NotEmpty(restored.Whitelist);
+        Assert.Equal(data.Rows, restored.Rows);
+        Assert.Equal(data.Cols, restored.Cols);
+    }
+
+    [Theory]
+    [MemberData(nameof(Cases))]
+    public void ExportEntryAs_ThenImportEntryFrom_RestoresEntry(DataFormat format, bool plane)
+    {
+        using var file = new TempDataFile();
+        var data = CreateData(plane);
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData> { [data.Id] = data }, file.Path);
+
+        string exported = BalancedRandDataManager.ExportEntryAs(file.Path, data.Id, format);
+        File.Delete(file.Path);
+        string id = BalancedRandDataManager.ImportEntryFrom(file.Path, exported, format);
+
+        Assert.Equal(data.Id, id);
+        Assert.Equal(data, BalancedRandDataManager.ReadAllData(file.Path)[id]);
+    }
+
+    [Theory]
+    [InlineData(DataFormat.Json)]
+    [InlineData(DataFormat.Yaml)]
+    [InlineData(DataFormat.Toml)]
+    public void UnknownField_IsRejectedAndFileIsUnchanged(DataFormat format)
+    {
+        using var file = new TempDataFile();
+        var data = CreateData(plane: false);
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData> { [data.Id] = data }, file.Path);
+        byte[] before = File.ReadAllBytes(file.Path);
+
+        string source = format switch
+        {
+            DataFormat.Yaml => InsertBeforeFirstKey(data.ToYaml(), "Unexpected: 1"),
+            DataFormat.Toml => InsertBeforeFirstKey(data.ToToml(), "Unexpected = 1"),
+            _ => "{\"Unexpected\": 1," + DataFormatConverter.ToJson(data).TrimStart().Substring(1)
+        };
+
+        var ex = Assert.Throws<InvalidDataException>(() => BalancedRandDataManager.ImportEntryFrom(file.Path, source, format));
+        Assert.Contains("Unexpected", ex.Message);
+        if (format != DataFormat.Json)
+        {
+            Assert.Contains("行", ex.Message);
+        }
+        Assert.Equal(before, File.ReadAllBytes(file.Path));
+    }
+
+    /// <summary>
+    /// 在根对象的第一个字段前插入一行（跳过文档开头的标记）
+    /// </summary>
+    private static string InsertBeforeFirstKey(string source, string line)
+    {
+        var lines = source.Split('\n').ToList();
+        lines.Insert(lines.FindIndex(l => l.Length > 0 && char.IsLetter(l[0])), line);
+        return string.Join('\n', lines);
+    }
+}
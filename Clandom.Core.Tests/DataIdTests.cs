@@ -0,0 +1,79 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Linq;
+using System.Text.Json;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class DataIdTests
+{
+    [Fact]
+    public void EquivalentFloatFormatting_ProducesSameId()
+    {
+        var a = new BalancedRand(1, 6, coldStartBoost: 2, decayFactor: 0.7, loadData: false);
+        var b = new BalancedRand(1, 6, coldStartBoost: double.Parse("2.0", CultureInfo.InvariantCulture),
+            decayFactor: double.Parse("0.70", CultureInfo.InvariantCulture), loadData: false);
+
+        Assert.Equal(a.GetDataId(), b.GetDataId());
+    }
+
+    [Fact]
+    public void Id_DoesNotDependOnCurrentCulture()
+    {
+        var previous = CultureInfo.CurrentCulture;
+        string invariantId = new BalancedRand(1, 6, decayFactor: 0.7, loadData: false).GetDataId();
+        try
+        {
+            CultureInfo.CurrentCulture = new CultureInfo("de-DE");
+
+            Assert.Equal(invariantId, new BalancedRand(1, 6, decayFactor: 0.7, loadData: false).GetDataId());
+        }
+        finally
+        {
+            CultureInfo.CurrentCulture = previous;
+        }
+    }
+
+    [Fact]
+    public void FindDuplicates_ReportsEntriesDifferingOnlyInFloatFormatting()
+    {
+        using var file = new TempDataFile();
+        var data = new BalancedRand(1, 6, coldStartBoost: 2, decayFactor: 0.7, loadData: false).ToData();
+        var parts = data.Id.Split('_');
+        parts[^2] = "2.0";
+        parts[^1] = "0,70";
+        var legacy = Copy(data);
+        legacy.Id = string.Join("_", parts);
+        var other = new BalancedRand(1, 6, decayFactor: 0.8, loadData: false).ToData();
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData>
+        {
+            [data.Id] = data,
+            [legacy.Id] = legacy,
+            [other.Id] = other,
+        }, file.Path);
+
+        var duplicates = BalancedRandDataManager.FindDuplicates(file.Path);
+
+        var group = Assert.Single(duplicates);
+        Assert.Equal(new[] { data.Id, legacy.Id }.OrderBy(id => id, StringComparer.Ordinal), group);
+    }
+
+    [Fact]
+    public void FindDuplicates_IsEmptyWithoutDuplicates()
+    {
+        using var file = new TempDataFile();
+        new BalancedRand(1, 6, loadData: false).SaveData(file.Path);
+        new BalancedRand(1, 7, loadData: false).SaveData(file.Path);
+
+        Assert.Empty(BalancedRandDataManager.FindDuplicates(file.Path));
+    }
+
+    private static BalancedRandData Copy(BalancedRandData data)
+    {
+        return JsonSerializer.Deserialize<BalancedRandData>(
+            JsonSerializer.Serialize(data, BalancedRandDataManager.JsonOptions), BalancedRandDataManager.JsonOptions)!;
+    }
+}
@@ -0,0 +1,24 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class GapBoostTests
+{
+    [Fact]
+    public void SetGapBoost_MatchesFreshRecalculation()
+    {
+        var rand = new BalancedRand(1, 8, loadData: false);
+        rand.SetSeed(9);
+        for (int i = 0; i < 40; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        rand.SetGapBoost(new GapBoost { ThresholdFraction = 0, Scale = 5, Curve = GapBoostCurve.Linear });
+        var afterSet = rand.GetProbabilityList();
+        rand.SetCandidateFilter(null);
+
+        Assert.Equal(rand.GetProbabilityList(), afterSet);
+    }
+}
@@ -0,0 +1,34 @@
+using System;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class GroupQuotaTests
+{
+    [Fact]
+    public void Draw_AllQuotasUsed_ThrowsWithoutResettingCounts()
+    {
+        var rand = new BalancedRand(1, 4, loadData: false);
+        rand.SetSeed(1);
+        for (int number = 1; number <= 4; number++)
+        {
+            rand.SetGroup(number, number <= 2 ? 1 : 2);
+        }
+        rand.SetGroupQuota(1, 1);
+        rand.SetGroupQuota(2, 1);
+
+        rand.Draw(autoSave: false);
+        rand.Draw(autoSave: false);
+        var counts = rand.GetStatisticsList();
+
+        Assert.Throws<InvalidOperationException>(() => rand.Draw(autoSave: false));
+        Assert.Equal(0, rand.GetCyclesCompleted());
+        Assert.Equal(counts, rand.GetStatisticsList());
+        Assert.Equal(2, rand.GetTotalDraws());
+
+        rand.ResetQuotas();
+        rand.Draw(autoSave: false);
+        Assert.Equal(3, rand.GetTotalDraws());
+    }
+}
@@ -0,0 +1,59 @@
+using System.Collections.Generic;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class LargeCountWeightTests
+{
+    [Theory]
+    [InlineData(500)]
+    [InlineData(2000)]
+    public void LargeCounts_KeepDecayBetweenAdjacentCounts(int baseCount)
+    {
+        using var file = new TempDataFile();
+        var data = new BalancedRand(1, 4, loadData: false).ToData();
+        // 平均值向上取整为 baseCount + 1，候选池为学号 1–3，学号4被平均值过滤
+        data.DrawCounts = new Dictionary<int, int>
+        {
+            [1] = baseCount, [2] = baseCount, [3] = baseCount + 1, [4] = baseCount + 3,
+        };
+        data.TotalDraws = 4 * baseCount + 4;
+        data.CurrentRound = data.TotalDraws;
+        // 所有学号距上次抽中的轮次相同，间隔提升不影响权重比例
+        data.LastDrawRound = new Dictionary<int, int>
+        {
+            [1] = data.CurrentRound - 4, [2] = data.CurrentRound - 4, [3] = data.CurrentRound - 4, [4] = data.CurrentRound - 4,
+        };
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData> { [data.Id] = data }, file.Path);
+
+        var rand = new BalancedRand(1, 4, loadData: false);
+        rand.LoadData(file.Path);
+        var probabilities = rand.GetProbabilityList();
+
+        Assert.All(probabilities, p => Assert.True(double.IsFinite(p)));
+        Assert.True(probabilities[0] > 0);
+        Assert.Equal(probabilities[0], probabilities[1], 12);
+        Assert.Equal(0, probabilities[3]);
+
+        // 权重 ∝ decay^n / (n + 1)，相邻次数的比例为 (1 / decay) × (n + 2) / (n + 1)
+        double expectedRatio = 1 / 0.7 * (baseCount + 2) / (baseCount + 1);
+        Assert.Equal(expectedRatio, probabilities[0] / probabilities[2], 9);
+    }
+
+    [Fact]
+    public void LargeCounts_DrawStillFavoursLessDrawnMembers()
+    {
+        var rand = new BalancedRand(1, 3, loadData: false);
+        rand.SetSeed(5);
+        for (int i = 0; i < 1500; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        var counts = rand.GetStatisticsList();
+        Assert.All(rand.GetProbabilityList(), p => Assert.True(double.IsFinite(p) && p >= 0));
+        Assert.InRange(counts.Max() - counts.Min(), 0, 5);
+    }
+}
@@ -0,0 +1,53 @@
+using System.Collections.Generic;
+using System.IO;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class LoadDataStrictTests
+{
+    [Fact]
+    public void MissingFile_LoadsNothing()
+    {
+        using var file = new TempDataFile();
+        var rand = new BalancedRand(1, 5, loadData: false);
+
+        Assert.False(rand.LoadDataStrict(file.Path));
+    }
+
+    [Fact]
+    public void SavedEntry_IsLoaded()
+    {
+        using var file = new TempDataFile();
+        var original = new BalancedRand(1, 5, loadData: false);
+        original.Draw(autoSave: false);
+        original.SaveData(file.Path);
+
+        var rand = new BalancedRand(1, 5, loadData: false);
+
+        Assert.True(rand.LoadDataStrict(file.Path));
+        Assert.Equal(1, rand.GetTotalDraws());
+    }
+
+    [Fact]
+    public void EncryptedFile_Throws()
+    {
+        using var file = new TempDataFile();
+        BalancedRandDataManager.SaveAllDataEncrypted(
+            new Dictionary<string, BalancedRandData> { ["other"] = new BalancedRand(1, 5, loadData: false).ToData() },
+            file.Path, DataEncryptionKey.Generate());
+
+        Assert.ThrowsAny<InvalidDataException>(() => new BalancedRand(1, 5, loadData: false).LoadDataStrict(file.Path));
+    }
+
+    [Fact]
+    public void CorruptedFileWithoutBackup_Throws()
+    {
+        using var file = new TempDataFile();
+        new BalancedRand(1, 5, loadData: false).SaveData(file.Path);
+        File.WriteAllText(file.Path, File.ReadAllText(file.Path).Replace("\"CurrentRound\": 0", "\"CurrentRound\": 7"));
+
+        Assert.Throws<ChecksumMismatchException>(() => new BalancedRand(1, 5, loadData: false).LoadDataStrict(file.Path));
+    }
+}
@@ -0,0 +1,116 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class LongIdTests
+{
+    private static readonly long[] BoundaryIds =
+    {
+        uint.MaxValue - 1L,
+        uint.MaxValue,
+        uint.MaxValue + 1L,
+        int.MaxValue + 1L,
+        long.MaxValue,
+    };
+
+    [Fact]
+    public void BoundaryIds_RoundTrip()
+    {
+        var rand = BalancedRand.FromLongIds(BoundaryIds, loadData: false);
+
+        for (int i = 0; i < BoundaryIds.Length; i++)
+        {
+            Assert.Equal(BoundaryIds[i], rand.GetLongId(i));
+            Assert.Equal(i, rand.GetNumberForLongId(BoundaryIds[i]));
+        }
+        Assert.Null(rand.GetNumberForLongId(uint.MaxValue + 2L));
+
+        rand.SetSeed(3);
+        for (int i = 0; i < 20; i++)
+        {
+            Assert.Contains(rand.DrawLongId(autoSave: false), BoundaryIds);
+        }
+    }
+
+    [Fact]
+    public void LoadData_ReorderedRoster_KeepsCountsPerLongId()
+    {
+        using var file = new TempDataFile();
+        var rand = BalancedRand.FromLongIds(BoundaryIds, loadData: false);
+        rand.SetSeed(5);
+        var drawn = new Dictionary<long, int>();
+        for (int i = 0; i < 12; i++)
+        {
+            long id = rand.DrawLongId(autoSave: false);
+            drawn[id] = drawn.TryGetValue(id, out var count) ? count + 1 : 1;
+        }
+        rand.AddToBlacklist(rand.GetNumberForLongId(long.MaxValue)!.Value);
+        rand.SaveData(file.Path);
+
+        var reordered = BalancedRand.FromLongIds(BoundaryIds.Reverse(), loadData: false);
+        reordered.LoadData(file.Path);
+
+        var counts = reordered.GetStatisticsList();
+        foreach (var id in BoundaryIds)
+        {
+            int number = reordered.GetNumberForLongId(id)!.Value;
+            Assert.Equal(drawn.TryGetValue(id, out var expected) ? expected : 0, counts[number]);
+        }
+        Assert.Equal(new[] { reordered.GetNumberForLongId(long.MaxValue)!.Value }, reordered.GetBlacklist());
+        Assert.Equal(12, reordered.GetTotalDraws());
+    }
+
+    [Fact]
+    public void DrawLongId_WhitelistExtraOutsideTable_ThrowsWithoutDrawing()
+    {
+        var rand = BalancedRand.FromLongIds(BoundaryIds, loadData: false);
+        rand.AddToWhitelist(BoundaryIds.Length + 10);
+
+        Assert.Throws<InvalidOperationException>(() => rand.DrawLongId(autoSave: false));
+        Assert.Equal(0, rand.GetTotalDraws());
+    }
+
+    [Fact]
+    public void LongRange_StraddlingUIntMax_RoundTrips()
+    {
+        using var file = new TempDataFile();
+        var rand = BalancedRand.FromLongRange(uint.MaxValue - 2L, uint.MaxValue + 2L, loadData: false);
+        var expected = Enumerable.Range(-2, 5).Select(offset => uint.MaxValue + (long)offset).ToList();
+
+        Assert.Equal(expected, Enumerable.Range(0, 5).Select(rand.GetLongId));
+        Assert.Equal(2, rand.GetNumberForLongId(uint.MaxValue));
+        Assert.Equal(3, rand.GetNumberForLongId(uint.MaxValue + 1L));
+
+        rand.SetSeed(9);
+        var drawn = rand.DrawMultiple(5, autoSave: false).Select(rand.GetLongId).ToList();
+        Assert.Equal(expected, drawn.OrderBy(id => id));
+        rand.SaveData(file.Path);
+
+        var loaded = BalancedRand.FromLongRange(uint.MaxValue - 2L, uint.MaxValue + 2L, loadData: false);
+        loaded.LoadData(file.Path);
+        Assert.Equal(new[] { 1, 1, 1, 1, 1 }, loaded.GetStatisticsList());
+    }
+
+    [Fact]
+    public void LongRange_NearLongMax_DoesNotOverflow()
+    {
+        var rand = BalancedRand.FromLongRange(long.MaxValue - 3, long.MaxValue, loadData: false);
+
+        Assert.Equal(long.MaxValue, rand.GetLongId(3));
+        Assert.Equal(0, rand.GetNumberForLongId(long.MaxValue - 3));
+    }
+
+    [Theory]
+    [InlineData(0L, 2147483647L)]
+    [InlineData(long.MinValue, long.MaxValue)]
+    [InlineData(-1L, 4294967295L)]
+    [InlineData(5L, 4L)]
+    public void LongRange_InvalidOrTooLarge_Throws(long start, long end)
+    {
+        Assert.Throws<ArgumentException>(() => BalancedRand.FromLongRange(start, end, loadData: false));
+    }
+}
@@ -0,0 +1,103 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class LotteryTests
+{
+    private static readonly Dictionary<int, int> Tickets = new() { [1] = 1, [2] = 2, [3] = 3, [4] = 4, [5] = 0 };
+
+    private static BalancedRand CreateLottery(int seed, bool applyDecay = false)
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetSeed(seed);
+        rand.SetTickets(Tickets);
+        rand.SetLotteryMode(true, applyDecay);
+        return rand;
+    }
+
+    [Theory]
+    [InlineData(1)]
+    [InlineData(99)]
+    public void Frequencies_AreProportionalToTickets(int seed)
+    {
+        const int draws = 20000;
+        var rand = CreateLottery(seed);
+
+        var winners = rand.DrawWinners(draws, unique: false, autoSave: false);
+
+        Assert.Equal(draws, winners.Count);
+        Assert.DoesNotContain(5, winners);
+        foreach (var (number, tickets) in Tickets.Where(kvp => kvp.Value > 0))
+        {
+            double frequency = (double)winners.Count(w => w == number) / draws;
+            Assert.InRange(frequency, tickets / 10.0 - 0.015, tickets / 10.0 + 0.015);
+        }
+    }
+
+    [Fact]
+    public void Probabilities_AreTicketShares()
+    {
+        var rand = CreateLottery(1);
+
+        var probabilities = rand.GetProbabilityList();
+
+        double[] expected = { 0.1, 0.2, 0.3, 0.4, 0 };
+        for (int i = 0; i < expected.Length; i++)
+        {
+            Assert.Equal(expected[i], probabilities[i], 12);
+        }
+    }
+
+    [Fact]
+    public void Decay_ReducesWeightOfPreviousWinners()
+    {
+        var rand = CreateLottery(4, applyDecay: true);
+
+        int winner = rand.Draw(autoSave: false);
+
+        var weights = Tickets.ToDictionary(kvp => kvp.Key, kvp => kvp.Key == winner ? kvp.Value * 0.7 : kvp.Value);
+        double total = weights.Values.Sum();
+        var probabilities = rand.GetProbabilityList();
+        for (int number = 1; number <= 5; number++)
+        {
+            Assert.Equal(weights[number] / total, probabilities[number - 1], 12);
+        }
+    }
+
+    [Fact]
+    public void UniqueWinners_AreDistinctTicketHolders()
+    {
+        var rand = CreateLottery(8);
+
+        var winners = rand.DrawWinners(4, unique: true, autoSave: false);
+
+        Assert.Equal(new[] { 1, 2, 3, 4 }, winners.OrderBy(w => w));
+    }
+
+    [Fact]
+    public void AllZeroTickets_AreRejected()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+
+        Assert.Throws<ArgumentException>(() => rand.SetTickets(new Dictionary<int, int> { [1] = 0, [2] = 0 }));
+        Assert.Throws<ArgumentException>(() => rand.SetTickets(new Dictionary<int, int> { [1] = -1 }));
+    }
+
+    [Fact]
+    public void Tickets_ArePersisted()
+    {
+        using var file = new TempDataFile();
+        var rand = CreateLottery(1);
+        rand.SaveData(file.Path);
+
+        var loaded = new BalancedRand(1, 5, loadData: false);
+        loaded.LoadData(file.Path);
+
+        Assert.True(loaded.GetLotteryMode());
+        Assert.Equal(new Dictionary<int, int> { [1] = 1, [2] = 2, [3] = 3, [4] = 4 }, loaded.GetTickets());
+    }
+}
@@ -0,0 +1,45 @@
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class MinWeightTests
+{
+    [Fact]
+    public void SetMinWeight_UpdatesProbabilitiesImmediately()
+    {
+        var rand = new BalancedRand(1, 8, loadData: false);
+        rand.SetSeed(21);
+        for (int i = 0; i < 30; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        // 最大的权重归一化为1，最小权重为1时候选池中的学号权重相同
+        rand.SetMinWeight(1.0);
+        var probabilities = rand.GetProbabilityList();
+        var nonZero = probabilities.Where(p => p > 0).ToList();
+
+        Assert.NotEmpty(nonZero);
+        Assert.All(nonZero, p => Assert.Equal(1.0 / nonZero.Count, p, 12));
+        Assert.Equal(1.0, probabilities.Sum(), 12);
+    }
+
+    [Fact]
+    public void SetMinWeight_MatchesFreshRecalculation()
+    {
+        var rand = new BalancedRand(1, 8, loadData: false);
+        rand.SetSeed(5);
+        for (int i = 0; i < 30; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        rand.SetMinWeight(0.5);
+        var afterSet = rand.GetProbabilityList();
+        rand.SetCandidateFilter(null);
+
+        Assert.Equal(rand.GetProbabilityList(), afterSet);
+    }
+}
@@ -0,0 +1,69 @@
+using System;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class PlaneIndexTests
+{
+    public static TheoryData<int, int> GridSizes => new()
+    {
+        { 1, 1 },
+        { 1, 6 },
+        { 6, 1 },
+        { 3, 5 },
+        { 7, 4 },
+    };
+
+    [Theory]
+    [MemberData(nameof(GridSizes))]
+    public void IndexOf_And_PositionOf_RoundTripOverWholeGrid(int rows, int cols)
+    {
+        var plane = new BalancedRandPlane(rows, cols, minPoolSize: 1, loadData: false);
+        int expectedIndex = 0;
+
+        for (int row = 1; row <= rows; row++)
+        {
+            for (int col = 1; col <= cols; col++)
+            {
+                int index = BalancedRandDataManager.PlaneIndexOf(rows, cols, row, col);
+
+                Assert.Equal(expectedIndex++, index);
+                Assert.Equal(index, plane.IndexOf(row, col));
+                Assert.Equal<int?>(index, plane.FlatIndex(row, col));
+                Assert.Equal((row, col), BalancedRandDataManager.PlanePositionOf(rows, cols, index));
+                Assert.Equal((row, col), plane.PositionOf(index));
+            }
+        }
+
+        Assert.Equal(rows * cols, expectedIndex);
+    }
+
+    [Theory]
+    [MemberData(nameof(GridSizes))]
+    public void PositionsOutsideGrid_AreRejected(int rows, int cols)
+    {
+        var plane = new BalancedRandPlane(rows, cols, minPoolSize: 1, loadData: false);
+        var outside = new[] { (0, 1), (1, 0), (0, 0), (rows + 1, 1), (1, cols + 1), (-1, -1), (rows + 1, cols + 1) };
+
+        foreach (var (row, col) in outside)
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => BalancedRandDataManager.PlaneIndexOf(rows, cols, row, col));
+            Assert.Throws<ArgumentOutOfRangeException>(() => plane.IndexOf(row, col));
+            Assert.Null(plane.FlatIndex(row, col));
+        }
+    }
+
+    [Theory]
+    [MemberData(nameof(GridSizes))]
+    public void IndicesOutsideGrid_AreRejected(int rows, int cols)
+    {
+        var plane = new BalancedRandPlane(rows, cols, minPoolSize: 1, loadData: false);
+
+        foreach (int index in new[] { -1, rows * cols, rows * cols + cols, int.MaxValue })
+        {
+            Assert.Throws<ArgumentOutOfRangeException>(() => BalancedRandDataManager.PlanePositionOf(rows, cols, index));
+            Assert.Throws<ArgumentOutOfRangeException>(() => plane.PositionOf(index));
+        }
+    }
+}
@@ -0,0 +1,38 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class PlanePositionListTests
+{
+    [Theory]
+    [InlineData(0, 0)]
+    [InlineData(0, 1)]
+    [InlineData(1, 0)]
+    [InlineData(1, 4)]
+    [InlineData(4, 1)]
+    [InlineData(-1, 2)]
+    public void PositionsOutsideGrid_AreNotInLists(int row, int col)
+    {
+        var plane = new BalancedRandPlane(3, 3, loadData: false);
+        // 不检查范围时 (1, 4) 会被算成 (2, 1) 的序号
+        plane.AddToWhitelistPositions((2, 1), (1, 1));
+        plane.AddToBlacklistPositions((2, 1), (3, 3));
+
+        Assert.False(plane.IsPositionInWhitelist(row, col));
+        Assert.False(plane.IsPositionInBlacklist(row, col));
+    }
+
+    [Fact]
+    public void PositionsInsideGrid_AreReported()
+    {
+        var plane = new BalancedRandPlane(3, 3, loadData: false);
+        plane.AddToWhitelistPositions((2, 1));
+        plane.AddToBlacklistPositions((3, 3));
+
+        Assert.True(plane.IsPositionInWhitelist(2, 1));
+        Assert.False(plane.IsPositionInWhitelist(1, 1));
+        Assert.True(plane.IsPositionInBlacklist(3, 3));
+        Assert.False(plane.IsPositionInBlacklist(2, 1));
+    }
+}
@@ -0,0 +1,37 @@
+using System.Collections.Generic;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class ProbabilityIntegrityTests
+{
+    [Fact]
+    public void LoadData_RecomputesStoredProbabilities()
+    {
+        using var file = new TempDataFile();
+        var original = new BalancedRand(1, 6, loadData: false);
+        original.SetSeed(17);
+        for (int i = 0; i < 9; i++)
+        {
+            original.Draw(autoSave: false);
+        }
+
+        // 手动修改过的文件：概率与抽取次数不符，且总和不为1
+        var data = original.ToData();
+        data.CurrentProbabilities = new Dictionary<int, double> { [1] = 0.9, [2] = 0.9, [3] = 0, [4] = 0, [5] = 0, [6] = 0 };
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData> { [data.Id] = data }, file.Path);
+
+        var loaded = new BalancedRand(1, 6, loadData: false);
+        loaded.LoadData(file.Path);
+
+        var expected = original.GetProbabilityList();
+        var actual = loaded.GetProbabilityList();
+        Assert.Equal(expected.Count, actual.Count);
+        for (int i = 0; i < expected.Count; i++)
+        {
+            Assert.Equal(expected[i], actual[i], 12);
+        }
+        Assert.Equal(original.GetStatisticsList(), loaded.GetStatisticsList());
+    }
+}
@@ -0,0 +1,64 @@
+using System;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class ProbabilityPercentTests
+{
+    private static BalancedRand CreateDrawn()
+    {
+        // 7个学号的概率不能被整除，直接四舍五入时总和通常不为100
+        var rand = new BalancedRand(1, 7, loadData: false);
+        rand.SetSeed(21);
+        for (int i = 0; i < 10; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        return rand;
+    }
+
+    [Theory]
+    [InlineData(0)]
+    [InlineData(1)]
+    [InlineData(2)]
+    [InlineData(3)]
+    public void Percents_SumToExactlyOneHundred(int decimals)
+    {
+        var rand = CreateDrawn();
+        double scale = Math.Pow(10, decimals);
+
+        var percents = rand.GetProbabilityPercents(decimals);
+
+        Assert.Equal(Enumerable.Range(1, 7), percents.Select(p => p.Number));
+        Assert.Equal((long)(100 * scale), percents.Sum(p => (long)Math.Round(p.Percent * scale)));
+        Assert.Equal(100.0, Math.Round(percents.Sum(p => p.Percent), decimals));
+    }
+
+    [Theory]
+    [InlineData(0)]
+    [InlineData(2)]
+    public void Percents_StayWithinOneUnitOfExactValue(int decimals)
+    {
+        var rand = CreateDrawn();
+        double unit = Math.Pow(10, -decimals);
+        var probabilities = rand.GetProbabilityList();
+        double total = probabilities.Sum();
+
+        var percents = rand.GetProbabilityPercents(decimals);
+
+        for (int i = 0; i < percents.Count; i++)
+        {
+            Assert.InRange(percents[i].Percent, probabilities[i] / total * 100 - unit, probabilities[i] / total * 100 + unit);
+        }
+    }
+
+    [Theory]
+    [InlineData(-1)]
+    [InlineData(7)]
+    public void InvalidDecimals_Throw(int decimals)
+    {
+        Assert.Throws<ArgumentException>(() => CreateDrawn().GetProbabilityPercents(decimals));
+    }
+}
@@ -0,0 +1,129 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Threading.Tasks;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class ReadOnlyTests
+{
+    private const string MissingPath = "clandom_readonly_test_never_written.json";
+
+    private static readonly Dictionary<string, Action<BalancedRand>> Mutators = new()
+    {
+        ["Draw"] = r => r.Draw(autoSave: false),
+        ["DrawSilent"] = r => r.DrawSilent(),
+        ["TryDraw"] = r => r.TryDraw(out _, autoSave: false),
+        ["Draws"] = r => r.Draws(autoSave: false).First(),
+        ["DrawMultiple"] = r => r.DrawMultiple(2, autoSave: false),
+        ["DrawMultipleWith"] = r => r.DrawMultipleWith(2, new DrawConstraints(), autoSave: false),
+        ["DrawRanked"] = r => r.DrawRanked(2, autoSave: false),
+        ["PartitionTeams"] = r => r.PartitionTeams(2, autoSave: false),
+        ["DrawWithReplacement"] = r => r.DrawWithReplacement(2, autoSave: false),
+        ["DrawUntil"] = r => r.DrawUntil(3, _ => true, autoSave: false),
+        ["SaveData"] = r => r.SaveData(MissingPath),
+        ["SaveDataAsync"] = r => r.SaveDataAsync(MissingPath).GetAwaiter().GetResult(),
+        ["SaveDataHandle"] = r => r.SaveData(new DataFileHandle(MissingPath)),
+        ["LoadData"] = r => r.LoadData(MissingPath),
+        ["LoadDataAsync"] = r => r.LoadDataAsync(MissingPath).GetAwaiter().GetResult(),
+        ["LoadDataHandle"] = r => r.LoadData(new DataFileHandle(MissingPath)),
+        ["EnableAutoReload"] = r => r.EnableAutoReload(TimeSpan.FromSeconds(1), MissingPath),
+        ["BeginBatch"] = r => r.BeginBatch(),
+        ["EndBatch"] = r => r.EndBatch(),
+        ["RemapNumbers"] = r => r.RemapNumbers(Enumerable.Range(1, 6).ToDictionary(n => n, n => n + 100)),
+        ["MergeCountsFrom"] = r => r.MergeCountsFrom(new BalancedRand(1, 6, loadData: false), MergeStrategy.Sum),
+        ["SetBlacklistDiff"] = r => r.SetBlacklistDiff(new[] { 3 }),
+        ["AddToBlacklist"] = r => r.AddToBlacklist(3),
+        ["RemoveFromBlacklist"] = r => r.RemoveFromBlacklist(2),
+        ["ClearBlacklist"] = r => r.ClearBlacklist(),
+        ["SetWhitelistDiff"] = r => r.SetWhitelistDiff(new[] { 4 }),
+        ["AddToWhitelist"] = r => r.AddToWhitelist(4),
+        ["RemoveFromWhitelist"] = r => r.RemoveFromWhitelist(1),
+        ["ClearWhitelist"] = r => r.ClearWhitelist(),
+        ["SetWhitelistOnlyMode"] = r => r.SetWhitelistOnlyMode(true),
+        ["SetLabel"] = r => r.SetLabel(1, "x"),
+        ["SetDisplayName"] = r => r.SetDisplayName("x"),
+        ["SetDescription"] = r => r.SetDescription("x"),
+        ["SetNamespace"] = r => r.SetNamespace("x"),
+        ["SetGroup"] = r => r.SetGroup(1, 2),
+        ["SetGroupQuota"] = r => r.SetGroupQuota(1, 5),
+        ["ResetQuotas"] = r => r.ResetQuotas(),
+        ["SetEmptyPoolPolicy"] = r => r.SetEmptyPoolPolicy(EmptyPoolPolicy.Error),
+        ["EnableProbabilitySnapshots"] = r => r.EnableProbabilitySnapshots(1, 10),
+        ["DisableProbabilitySnapshots"] = r => r.DisableProbabilitySnapshots(),
+        ["ClearProbabilitySnapshots"] = r => r.ClearProbabilitySnapshots(),
+        ["ClearHistory"] = r => r.ClearHistory(),
+        ["ResetDrawCounts"] = r => r.ResetDrawCounts(),
+        ["ResetSession"] = r => r.ResetSession(keepLists: false),
+        ["SetTimeZone"] = r => r.SetTimeZone(TimeZoneInfo.Local),
+        ["SetDailyLimit"] = r => r.SetDailyLimit(1),
+        ["UpdateParameters"] = r => r.UpdateParameters(minPoolSize: 2),
+        ["SetSeed"] = r => r.SetSeed(99),
+        ["SetMinWeight"] = r => r.SetMinWeight(0.5),
+        ["LockNumber"] = r => r.LockNumber(1),
+        ["UnlockNumber"] = r => r.UnlockNumber(1),
+        ["SetPreferenceOrder"] = r => r.SetPreferenceOrder(new[] { 2, 1 }),
+        ["SetMinSpacing"] = r => r.SetMinSpacing(2),
+        ["SetNoRepeat"] = r => r.SetNoRepeat(true),
+        ["SetRoundRobin"] = r => r.SetRoundRobin(true),
+        ["SetTickets"] = r => r.SetTickets(new Dictionary<int, int> { [1] = 3 }),
+        ["SetLotteryMode"] = r => r.SetLotteryMode(true),
+        ["SetTargetShares"] = r => r.SetTargetShares(new Dictionary<int, double> { [1] = 2 }),
+        ["SetCandidateFilter"] = r => r.SetCandidateFilter(n => n != 1),
+        ["SetAuditLog"] = r => r.SetAuditLog(MissingPath),
+        ["SetBalancingEnabled"] = r => r.SetBalancingEnabled(false),
+        ["SetWarmupRounds"] = r => r.SetWarmupRounds(3),
+        ["SetGapBoost"] = r => r.SetGapBoost(new GapBoost { Scale = 1 }),
+        ["SetAdaptiveTemperature"] = r => r.SetAdaptiveTemperature(0.5, 1, 10),
+        ["ClearAdaptiveTemperature"] = r => r.ClearAdaptiveTemperature(),
+    };
+
+    public static TheoryData<string> MutatorNames
+    {
+        get
+        {
+            var names = new TheoryData<string>();
+            foreach (var name in Mutators.Keys)
+            {
+                names.Add(name);
+            }
+            return names;
+        }
+    }
+
+    [Theory]
+    [MemberData(nameof(MutatorNames))]
+    public void Mutator_ThrowsAndLeavesStateUnchanged(string name)
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.SetSeed(4);
+        rand.AddToBlacklist(2);
+        rand.AddToWhitelist(1);
+        for (int i = 0; i < 5; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        rand.SetReadOnly(true);
+        var before = rand.ToData();
+
+        Assert.Throws<InvalidOperationException>(() => Mutators[name](rand));
+
+        Assert.Equal(before, rand.ToData());
+        Assert.False(rand.IsInBatch());
+        Assert.False(rand.IsAutoReloadEnabled());
+        Assert.Null(rand.GetAuditLog());
+        Assert.False(System.IO.File.Exists(MissingPath));
+    }
+
+    [Fact]
+    public void SetReadOnly_InBatch_Throws()
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.BeginBatch();
+
+        Assert.Throws<InvalidOperationException>(() => rand.SetReadOnly(true));
+        Assert.False(rand.IsReadOnly());
+    }
+}
@@ -0,0 +1,51 @@
+using System.Collections.Generic;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class RemapNumbersTests
+{
+    private static readonly Dictionary<int, int> Mapping =
+        Enumerable.Range(1, 5).ToDictionary(n => n, n => n + 100);
+
+    [Fact]
+    public void Snapshots_UseNewNumbers()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetSeed(4);
+        rand.EnableProbabilitySnapshots(1, 10);
+        for (int i = 0; i < 3; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        var before = rand.GetProbabilitySnapshots();
+
+        rand.RemapNumbers(Mapping);
+
+        var after = rand.GetProbabilitySnapshots();
+        Assert.Equal(before.Count, after.Count);
+        for (int i = 0; i < before.Count; i++)
+        {
+            Assert.Equal(before[i].Round, after[i].Round);
+            Assert.Equal(
+                before[i].Probabilities.ToDictionary(kvp => Mapping[kvp.Key], kvp => kvp.Value),
+                after[i].Probabilities);
+        }
+    }
+
+    [Fact]
+    public void LockedWeights_UseNewNumbers()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetSeed(4);
+        rand.Draw(autoSave: false);
+        rand.LockNumber(3);
+        double locked = rand.GetLockedWeights()[3];
+
+        rand.RemapNumbers(Mapping);
+
+        Assert.Equal(new Dictionary<int, double> { [103] = locked }, rand.GetLockedWeights());
+    }
+}
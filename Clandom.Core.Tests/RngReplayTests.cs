@@ -0,0 +1,55 @@
+using System.Collections.Generic;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class RngReplayTests
+{
+    private static List<int> DrawSequence(BalancedRand rand, int count)
+    {
+        return Enumerable.Range(0, count).Select(_ => rand.Draw(autoSave: false)).ToList();
+    }
+
+    [Fact]
+    public void LoadData_ResumesSeededSequence()
+    {
+        using var file = new TempDataFile();
+        var original = new BalancedRand(1, 8, loadData: false);
+        original.SetSeed(13);
+        DrawSequence(original, 5);
+        original.SaveData(file.Path);
+
+        var loaded = new BalancedRand(1, 8, loadData: false);
+        loaded.LoadData(file.Path);
+
+        Assert.Equal(DrawSequence(original, 10), DrawSequence(loaded, 10));
+    }
+
+    [Fact]
+    public void LoadData_HugeConsumedCount_ReseedsInsteadOfReplaying()
+    {
+        using var file = new TempDataFile();
+        var data = new BalancedRand(1, 8, loadData: false).ToData();
+        data.Seed = 13;
+        data.RngDrawsConsumed = 1_000_000_000_000;
+        BalancedRandDataManager.SaveAllData(new Dictionary<string, BalancedRandData> { [data.Id] = data }, file.Path);
+
+        var loaded = new BalancedRand(1, 8, loadData: false);
+        loaded.LoadData(file.Path);
+
+        var fresh = new BalancedRand(1, 8, loadData: false);
+        fresh.SetSeed(13);
+        Assert.Equal(DrawSequence(fresh, 10), DrawSequence(loaded, 10));
+    }
+
+    [Fact]
+    public void Validate_RejectsNegativeConsumedCount()
+    {
+        var data = new BalancedRand(1, 8, loadData: false).ToData();
+        data.RngDrawsConsumed = -1;
+
+        Assert.Contains(data.Validate(), e => e.Field == nameof(BalancedRandData.RngDrawsConsumed));
+    }
+}
@@ -0,0 +1,73 @@
+using System;
+using System.IO;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class RollbackTests
+{
+    private static string MissingDirectoryPath() =>
+        Path.Combine(Path.GetTempPath(), $"clandom_missing_{Guid.NewGuid():N}", "audit.log");
+
+    private static BalancedRand CreateDrawnInstance()
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.SetSeed(11);
+        rand.AddToWhitelist(99);
+        for (int number = 1; number <= 6; number++)
+        {
+            rand.SetGroup(number, 1);
+        }
+        rand.SetGroupQuota(1, 100);
+        rand.EnableProbabilitySnapshots(1, 50);
+        for (int i = 0; i < 4; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        return rand;
+    }
+
+    [Fact]
+    public void DrawRanked_FailureRestoresInMemoryState()
+    {
+        var rand = CreateDrawnInstance();
+        var reference = rand.CloneWithHistory("reference");
+        var before = rand.ToData();
+        var groupCounts = rand.GetGroupDrawCounts();
+        int snapshots = rand.GetProbabilitySnapshots().Count;
+
+        rand.SetAuditLog(MissingDirectoryPath());
+        Assert.Throws<DirectoryNotFoundException>(() => rand.DrawRanked(3, autoSave: false));
+        rand.SetAuditLog(null);
+
+        Assert.Equal(before, rand.ToData());
+        Assert.Equal(groupCounts, rand.GetGroupDrawCounts());
+        Assert.Equal(snapshots, rand.GetProbabilitySnapshots().Count);
+        Assert.False(rand.IsInBatch());
+        Assert.Equal(reference.DrawRanked(3, autoSave: false).Select(r => r.Number),
+                     rand.DrawRanked(3, autoSave: false).Select(r => r.Number));
+    }
+
+    [Fact]
+    public void DrawMultipleWith_FailureRestoresInMemoryState()
+    {
+        var rand = CreateDrawnInstance();
+        var reference = rand.CloneWithHistory("reference");
+        var before = rand.ToData();
+        var groupCounts = rand.GetGroupDrawCounts();
+        int snapshots = rand.GetProbabilitySnapshots().Count;
+        var constraints = new DrawConstraints { Include = { 2 }, Exclude = { 5 } };
+
+        rand.SetAuditLog(MissingDirectoryPath());
+        Assert.Throws<DirectoryNotFoundException>(() => rand.DrawMultipleWith(3, constraints, autoSave: false));
+        rand.SetAuditLog(null);
+
+        Assert.Equal(before, rand.ToData());
+        Assert.Equal(groupCounts, rand.GetGroupDrawCounts());
+        Assert.Equal(snapshots, rand.GetProbabilitySnapshots().Count);
+        Assert.Equal(reference.DrawMultipleWith(3, constraints, autoSave: false),
+                     rand.DrawMultipleWith(3, constraints, autoSave: false));
+    }
+}
@@ -0,0 +1,45 @@
+using System;
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class RoundRobinTests
+{
+    [Theory]
+    [InlineData(1)]
+    [InlineData(2)]
+    [InlineData(3)]
+    public void DrawMultiple_QueueRefilledMidBatch_NoRepeats(int seed)
+    {
+        var rand = new BalancedRand(1, 4, loadData: false);
+        rand.SetSeed(seed);
+        rand.SetRoundRobin(true);
+        for (int i = 0; i < 3; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        var results = rand.DrawMultiple(3, autoSave: false);
+
+        Assert.Equal(3, results.Distinct().Count());
+        Assert.Equal(6, rand.GetTotalDraws());
+    }
+
+    [Fact]
+    public void Draw_EveryoneDrawnInBatch_Throws()
+    {
+        var rand = new BalancedRand(1, 3, loadData: false);
+        rand.SetRoundRobin(true);
+        rand.Draw(autoSave: false);
+        rand.BeginBatch();
+        for (int i = 0; i < 3; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        Assert.Throws<InvalidOperationException>(() => rand.Draw(autoSave: false));
+        rand.EndBatch();
+    }
+}
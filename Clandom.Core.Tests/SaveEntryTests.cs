@@ -0,0 +1,48 @@
+using System.Collections.Generic;
+using System.IO;
+using System.Threading.Tasks;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class SaveEntryTests
+{
+    [Fact]
+    public async Task SaveData_LeavesEncryptedFileIntact()
+    {
+        using var file = new TempDataFile();
+        var key = DataEncryptionKey.Generate();
+        var other = new BalancedRand(1, 5, loadData: false);
+        BalancedRandDataManager.SaveAllDataEncrypted(
+            new Dictionary<string, BalancedRandData> { ["other"] = other.ToData() }, file.Path, key);
+        byte[] before = File.ReadAllBytes(file.Path);
+
+        var rand = new BalancedRand(1, 10, loadData: false);
+        rand.Draw(autoSave: false);
+        rand.SaveData(file.Path);
+        await Assert.ThrowsAnyAsync<InvalidDataException>(() => rand.SaveDataAsync(file.Path));
+
+        Assert.Equal(before, File.ReadAllBytes(file.Path));
+        Assert.False(File.Exists(file.Path + ".bak"));
+        Assert.Equal(new[] { "other" }, BalancedRandDataManager.LoadAllDataWithKey(file.Path, key).Keys);
+    }
+
+    [Fact]
+    public void SaveData_LeavesCorruptedFileWithoutBackupIntact()
+    {
+        using var file = new TempDataFile();
+        var other = new BalancedRand(1, 5, loadData: false);
+        other.SaveData(file.Path);
+        string original = File.ReadAllText(file.Path);
+        string tampered = original.Replace("\"CurrentRound\": 0", "\"CurrentRound\": 7");
+        Assert.NotEqual(original, tampered);
+        File.WriteAllText(file.Path, tampered);
+
+        var rand = new BalancedRand(1, 10, loadData: false);
+        rand.SaveData(file.Path);
+
+        Assert.Equal(tampered, File.ReadAllText(file.Path));
+        Assert.Throws<ChecksumMismatchException>(() => BalancedRandDataManager.ReadAllData(file.Path));
+    }
+}
@@ -0,0 +1,138 @@
+using System.Collections.Generic;
+using System.Text.Json;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+/// <summary>
+/// 锁定 GetState 序列化后的结构，前端依赖这些字段名和类型；修改时需要同步前端
+/// </summary>
+public class StateSnapshotSchemaTests
+{
+    private static readonly string[] CommonShape =
+    {
+        "$: object",
+        "$.members: array",
+        "$.members[]: object",
+        "$.members[].number: number",
+        "$.members[].position: {0}",
+        "$.members[].count: number",
+        "$.members[].probability: number",
+        "$.members[].lastRound: number",
+        "$.blacklist: array",
+        "$.blacklist[]: number",
+        "$.whitelist: array",
+        "$.whitelist[]: number",
+        "$.whitelistOnlyMode: boolean",
+        "$.config: object",
+        "$.config.minPoolSize: number",
+        "$.config.maxGapThreshold: number",
+        "$.config.coldStartBoost: number",
+        "$.config.decayFactor: number",
+        "$.currentRound: number",
+        "$.totalDraws: number",
+        "$.candidatePool: array",
+        "$.candidatePool[]: number",
+    };
+
+    private static readonly string[] PlaneShape =
+    {
+        "$.rows: number",
+        "$.cols: number",
+        "$.blacklistPositions: array",
+        "$.blacklistPositions[]: array",
+        "$.blacklistPositions[][]: number",
+        "$.whitelistPositions: array",
+        "$.whitelistPositions[]: array",
+        "$.whitelistPositions[][]: number",
+    };
+
+    [Fact]
+    public void RangeState_HasStableShape()
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.SetSeed(1);
+        rand.AddToBlacklist(2);
+        rand.AddToWhitelist(9);
+        rand.Draw(autoSave: false);
+
+        var shape = Describe(rand.GetState().ToJson());
+
+        Assert.Equal(Expected(CommonShape, "null"), shape);
+    }
+
+    [Fact]
+    public void PlaneState_HasStableShape()
+    {
+        var plane = new BalancedRandPlane(2, 3, loadData: false);
+        plane.SetSeed(1);
+        plane.AddToBlacklistPositions((1, 2));
+        plane.AddToWhitelistPositions((2, 3));
+        plane.Draw(autoSave: false);
+
+        var shape = Describe(plane.GetState().ToJson());
+
+        var expected = Expected(CommonShape, "array");
+        expected.Insert(expected.IndexOf("$.members[].count: number"), "$.members[].position[]: number");
+        expected.AddRange(PlaneShape);
+        Assert.Equal(expected, shape);
+    }
+
+    [Fact]
+    public void PlaneState_UsesOneBasedPositionPairs()
+    {
+        var plane = new BalancedRandPlane(2, 3, loadData: false);
+        plane.AddToBlacklistPositions((1, 2));
+
+        using var document = JsonDocument.Parse(plane.GetState().ToJson());
+        var root = document.RootElement;
+
+        Assert.Equal(2, root.GetProperty("rows").GetInt32());
+        Assert.Equal(3, root.GetProperty("cols").GetInt32());
+        Assert.Equal("[1,2]", root.GetProperty("blacklistPositions")[0].GetRawText());
+        Assert.Equal("[2,3]", root.GetProperty("members")[5].GetProperty("position").GetRawText());
+    }
+
+    private static List<string> Expected(string[] shape, string positionKind)
+    {
+        var expected = new List<string>();
+        foreach (var line in shape)
+        {
+            expected.Add(line.Replace("{0}", positionKind));
+        }
+        return expected;
+    }
+
+    /// <summary>
+    /// 按出现顺序列出每个字段的路径和类型，数组只描述第一个元素
+    /// </summary>
+    private static List<string> Describe(string json)
+    {
+        using var document = JsonDocument.Parse(json);
+        var lines = new List<string>();
+        Describe(document.RootElement, "$", lines);
+        return lines;
+    }
+
+    private static void Describe(JsonElement element, string path, List<string> lines)
+    {
+        string kind = element.ValueKind is JsonValueKind.True or JsonValueKind.False
+            ? "boolean"
+            : element.ValueKind.ToString().ToLowerInvariant();
+        lines.Add($"{path}: {kind}");
+
+        switch (element.ValueKind)
+        {
+            case JsonValueKind.Object:
+                foreach (var property in element.EnumerateObject())
+                {
+                    Describe(property.Value, $"{path}.{property.Name}", lines);
+                }
+                break;
+            case JsonValueKind.Array when element.GetArrayLength() > 0:
+                Describe(element[0], path + "[]", lines);
+                break;
+        }
+    }
+}
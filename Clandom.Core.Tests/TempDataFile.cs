@@ -0,0 +1,28 @@
+using System;
+using System.IO;
+
+namespace Clandom.Core.Tests;
+
+/// <summary>
+/// 测试使用的临时数据文件，释放时删除文件及其备份
+/// </summary>
+sealed class TempDataFile : IDisposable
+{
+    public string Path { get; } = System.IO.Path.Combine(System.IO.Path.GetTempPath(), $"clandom_test_{Guid.NewGuid():N}.json");
+
+    /// <summary>
+    /// 将测试数据目录中的文件复制为临时数据文件
+    /// </summary>
+    public static TempDataFile FromFixture(string relativePath)
+    {
+        var file = new TempDataFile();
+        File.Copy(System.IO.Path.Combine(AppContext.BaseDirectory, "Fixtures", relativePath), file.Path);
+        return file;
+    }
+
+    public void Dispose()
+    {
+        File.Delete(Path);
+        File.Delete(Path + ".bak");
+    }
+}
@@ -0,0 +1,54 @@
+using System.IO;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class ValidationTests
+{
+    public static TheoryData<string> InvalidFixtures => new()
+    {
+        "negative_probability",
+        "probability_above_one",
+        "negative_count",
+        "counts_exceed_total",
+        "range_start_after_end",
+        "empty_list",
+        "plane_zero_rows",
+        "unknown_type",
+    };
+
+    [Theory]
+    [MemberData(nameof(InvalidFixtures))]
+    public void SkipMode_DropsInvalidEntryAndKeepsValidOne(string fixture)
+    {
+        using var file = TempDataFile.FromFixture(Path.Combine("Validation", fixture + ".json"));
+
+        var allData = BalancedRandDataManager.LoadAllData(file.Path, ValidationMode.Skip, out var warnings);
+
+        Assert.Equal(new[] { "valid" }, allData.Keys);
+        Assert.Single(warnings);
+        Assert.StartsWith("bad:", warnings[0]);
+    }
+
+    [Theory]
+    [MemberData(nameof(InvalidFixtures))]
+    public void StrictMode_Throws(string fixture)
+    {
+        using var file = TempDataFile.FromFixture(Path.Combine("Validation", fixture + ".json"));
+
+        Assert.Throws<InvalidDataException>(() =>
+            BalancedRandDataManager.LoadAllData(file.Path, ValidationMode.Strict, out _));
+    }
+
+    [Fact]
+    public void ValidEntry_HasNoErrors()
+    {
+        using var file = TempDataFile.FromFixture(Path.Combine("Validation", "negative_count.json"));
+
+        var allData = BalancedRandDataManager.ReadAllData(file.Path);
+
+        Assert.Empty(allData["valid"].Validate());
+        Assert.NotEmpty(allData["bad"].Validate());
+    }
+}
@@ -0,0 +1,72 @@
+using System;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class WarmupTests
+{
+    private const int Trials = 3000;
+
+    /// <summary>
+    /// 在3个学号中连续抽取两次，返回第二次抽中同一学号的比例（均匀随机时约为1/3，平衡时远低于1/3）
+    /// </summary>
+    private static double RepeatRate(Action<BalancedRand> configure)
+    {
+        int repeats = 0;
+        for (int seed = 0; seed < Trials; seed++)
+        {
+            var rand = new BalancedRand(1, 3, loadData: false);
+            rand.SetSeed(seed);
+            configure(rand);
+            if (rand.Draw(autoSave: false) == rand.Draw(autoSave: false))
+                repeats++;
+        }
+        return (double)repeats / Trials;
+    }
+
+    [Fact]
+    public void BalancingDisabled_IsUniform()
+    {
+        double rate = RepeatRate(r => r.SetBalancingEnabled(false));
+
+        Assert.InRange(rate, 0.28, 0.39);
+    }
+
+    [Fact]
+    public void Balanced_SuppressesRepeats()
+    {
+        double rate = RepeatRate(_ => { });
+
+        Assert.InRange(rate, 0.0, 0.15);
+    }
+
+    [Fact]
+    public void Warmup_UniformUntilThresholdThenBalanced()
+    {
+        // 第二次抽取是第2轮：预热2轮时仍然均匀，预热1轮时已经开始平衡
+        Assert.InRange(RepeatRate(r => r.SetWarmupRounds(2)), 0.28, 0.39);
+        Assert.InRange(RepeatRate(r => r.SetWarmupRounds(1)), 0.0, 0.15);
+    }
+
+    [Fact]
+    public void BalancingDisabled_PoolProbabilitiesAreEqual()
+    {
+        var rand = new BalancedRand(1, 6, loadData: false);
+        rand.SetSeed(2);
+        for (int i = 0; i < 7; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+
+        rand.SetBalancingEnabled(false);
+
+        var pool = rand.GetCandidatePoolList();
+        var probabilities = rand.GetProbabilityList();
+        for (int number = 1; number <= 6; number++)
+        {
+            double expected = pool.Contains(number) ? 1.0 / pool.Count : 0;
+            Assert.Equal(expected, probabilities[number - 1], 12);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+using System.Linq;
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class WhitelistOnlyTests
+{
+    [Fact]
+    public void EmptyWhitelist_DrawThrowsWithoutResettingCounts()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetSeed(2);
+        for (int i = 0; i < 4; i++)
+        {
+            rand.Draw(autoSave: false);
+        }
+        var before = rand.ToData();
+
+        rand.SetWhitelistOnlyMode(true);
+
+        Assert.Throws<WhitelistOnlyButEmptyException>(() => rand.Draw(autoSave: false));
+        Assert.Throws<WhitelistOnlyButEmptyException>(() => rand.DrawMultiple(2, autoSave: false));
+        Assert.Equal(before.DrawCounts, rand.ToData().DrawCounts);
+        Assert.Equal(4, rand.ToData().TotalDraws);
+    }
+
+    [Fact]
+    public void AddingToWhitelist_MakesDrawUsableAgain()
+    {
+        var rand = new BalancedRand(1, 5, loadData: false);
+        rand.SetSeed(2);
+        rand.SetWhitelistOnlyMode(true);
+        Assert.Throws<WhitelistOnlyButEmptyException>(() => rand.Draw(autoSave: false));
+
+        rand.AddToWhitelist(3);
+
+        Assert.All(Enumerable.Range(0, 5).Select(_ => rand.Draw(autoSave: false)), number => Assert.Equal(3, number));
+    }
+}
@@ -0,0 +1,26 @@
+using Clandom.Models.BalancedRandom;
+using Xunit;
+
+namespace Clandom.Core.Tests;
+
+public class WhitelistTests
+{
+    [Fact]
+    public void RemoveFromWhitelist_DropsRecordsOfExtraNumber()
+    {
+        var rand = new BalancedRand(1, 4, loadData: false);
+        rand.AddToWhitelist(50);
+        rand.SetWhitelistOnlyMode(true);
+        Assert.Equal(50, rand.Draw(autoSave: false));
+        Assert.Equal(50, rand.Draw(autoSave: false));
+        rand.SetWhitelistOnlyMode(false);
+
+        rand.RemoveFromWhitelist(50);
+
+        Assert.Equal(new[] { 0, 0, 0, 0 }, rand.GetStatisticsList());
+        Assert.Equal(0, rand.GetAverageDrawCount());
+        var data = rand.ToData();
+        Assert.False(data.DrawCounts.ContainsKey(50));
+        Assert.False(data.LastDrawRound.ContainsKey(50));
+    }
+}
@@ -0,0 +1,143 @@
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 新增调节参数的保存位置（BalancedRandData.Advanced）
+    /// 
+    /// 约定：之后新增的调节参数都放在这里，而不是作为 BalancedRandData 的顶层字段，
+    /// 每个参数都必须有默认值，使旧文件（没有该字段或没有 Advanced）加载后行为不变。
+    /// 当前版本不认识的字段（由更新的版本写入）会保存在 ExtensionData 中，重新保存时原样写回，不会丢失。
+    /// </summary>
+    public class AdvancedConfig
+    {
+        /// <summary>
+        /// 学号 -> 目标抽取比例（相对值，未设置的学号为1）
+        /// </summary>
+        public Dictionary<int, double> TargetShares { get; set; } = new Dictionary<int, double>();
+        
+        /// <summary>
+        /// 2D抽取时不抽取与上一次抽中位置上下左右相邻的位置
+        /// </summary>
+        public bool AvoidAdjacentToLast { get; set; }
+        
+        /// <summary>
+        /// 学号 -> 锁定的权重
+        /// </summary>
+        public Dictionary<int, double> LockedWeights { get; set; } = new Dictionary<int, double>();
+        
+        /// <summary>
+        /// 每个学号每天最多被抽取的次数，为null时不限制
+        /// </summary>
+        public int? DailyLimit { get; set; }
+        
+        /// <summary>
+        /// 学号 -> 最近一次被抽中的日期及当天的抽取次数
+        /// </summary>
+        public Dictionary<int, DailyCount> DailyCounts { get; set; } = new Dictionary<int, DailyCount>();
+        
+        /// <summary>
+        /// 完成的抽取周期数（候选池耗尽后自动重置抽取次数的次数）
+        /// </summary>
+        public int CyclesCompleted { get; set; }
+        
+        /// <summary>
+        /// 偏好顺序（按偏好从高到低排列的学号），权重相同时靠前的学号权重略高
+        /// </summary>
+        public List<int> PreferenceOrder { get; set; } = new List<int>();
+        
+        /// <summary>
+        /// 抽奖模式：按彩票数量抽取
+        /// </summary>
+        public bool LotteryMode { get; set; }
+        
+        /// <summary>
+        /// 抽奖模式下是否对已中奖的学号应用权重衰减
+        /// </summary>
+        public bool LotteryDecay { get; set; }
+        
+        /// <summary>
+        /// 学号 -> 彩票数量
+        /// </summary>
+        public Dictionary<int, int> Tickets { get; set; } = new Dictionary<int, int>();
+        
+        /// <summary>
+        /// 长期未被抽中的学号的权重提升
+        /// </summary>
+        public GapBoost GapBoost { get; set; } = new GapBoost();
+        
+        /// <summary>
+        /// 每隔多少轮记录一次概率快照，0表示不记录
+        /// </summary>
+        public int ProbabilitySnapshotEvery { get; set; }
+        
+        /// <summary>
+        /// 最多保留的概率快照数量
+        /// </summary>
+        public int MaxProbabilitySnapshots { get; set; } = 100;
+        
+        /// <summary>
+        /// 是否保存概率快照
+        /// </summary>
+        public bool PersistProbabilitySnapshots { get; set; }
+        
+        /// <summary>
+        /// 概率快照（只在 PersistProbabilitySnapshots 为true时保存）
+        /// </summary>
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public List<ProbabilitySnapshot>? ProbabilitySnapshots { get; set; }
+        
+        /// <summary>
+        /// 当前版本不认识的字段
+        /// </summary>
+        [JsonExtensionData]
+        public Dictionary<string, JsonElement>? ExtensionData { get; set; }
+
+        public override bool Equals(object? obj)
+        {
+            if (obj is not AdvancedConfig other) return false;
+            if (ReferenceEquals(this, other)) return true;
+
+            return DictionaryEquals(TargetShares, other.TargetShares) &&
+                   AvoidAdjacentToLast == other.AvoidAdjacentToLast &&
+                   DictionaryEquals(LockedWeights, other.LockedWeights) &&
+                   DailyLimit == other.DailyLimit &&
+                   DictionaryEquals(DailyCounts, other.DailyCounts) &&
+                   CyclesCompleted == other.CyclesCompleted &&
+                   (PreferenceOrder ?? new List<int>()).SequenceEqual(other.PreferenceOrder ?? new List<int>()) &&
+                   LotteryMode == other.LotteryMode &&
+                   LotteryDecay == other.LotteryDecay &&
+                   DictionaryEquals(Tickets, other.Tickets) &&
+                   Equals(GapBoost ?? new GapBoost(), other.GapBoost ?? new GapBoost()) &&
+                   ProbabilitySnapshotEvery == other.ProbabilitySnapshotEvery &&
+                   MaxProbabilitySnapshots == other.MaxProbabilitySnapshots &&
+                   PersistProbabilitySnapshots == other.PersistProbabilitySnapshots &&
+                   (ProbabilitySnapshots ?? new List<ProbabilitySnapshot>()).SequenceEqual(other.ProbabilitySnapshots ?? new List<ProbabilitySnapshot>()) &&
+                   ExtensionDataEquals(ExtensionData, other.ExtensionData);
+        }
+
+        public override int GetHashCode() => 0;
+
+        private static bool DictionaryEquals<TKey, TValue>(Dictionary<TKey, TValue>? a, Dictionary<TKey, TValue>? b)
+            where TKey : notnull
+        {
+            a ??= new Dictionary<TKey, TValue>();
+            b ??= new Dictionary<TKey, TValue>();
+            if (a.Count != b.Count) return false;
+
+            return a.All(kvp => b.TryGetValue(kvp.Key, out var value) && EqualityComparer<TValue>.Default.Equals(kvp.Value, value));
+        }
+
+        private static bool ExtensionDataEquals(Dictionary<string, JsonElement>? a, Dictionary<string, JsonElement>? b)
+        {
+            a ??= new Dictionary<string, JsonElement>();
+            b ??= new Dictionary<string, JsonElement>();
+            if (a.Count != b.Count) return false;
+
+            return a.All(kvp => b.TryGetValue(kvp.Key, out var value) && kvp.Value.GetRawText() == value.GetRawText());
+        }
+    }
+}
@@ -0,0 +1,64 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 自动调参的目标
+    /// </summary>
+    public class FairnessTarget
+    {
+        /// <summary>
+        /// 模拟的抽取次数
+        /// </summary>
+        public int Draws { get; set; } = 100;
+        
+        /// <summary>
+        /// 允许的单个学号在一次抽取中被抽中的最大概率
+        /// </summary>
+        public double MaxProbability { get; set; } = 1.0;
+    }
+
+    /// <summary>
+    /// 自动调参的搜索范围
+    /// 权重衰减因子和冷启动提升系数各取 Steps 个等间距的值，共模拟 Steps × Steps 次
+    /// </summary>
+    public class TuneBudget
+    {
+        public double DecayFactorMin { get; set; } = 0.3;
+        public double DecayFactorMax { get; set; } = 1.0;
+        public double ColdStartBoostMin { get; set; } = 1.0;
+        public double ColdStartBoostMax { get; set; } = 4.0;
+        
+        /// <summary>
+        /// 每个参数的取值个数
+        /// </summary>
+        public int Steps { get; set; } = 8;
+        
+        /// <summary>
+        /// 模拟使用的随机数种子（相同的种子得到相同的结果）
+        /// </summary>
+        public int Seed { get; set; } = 42;
+    }
+
+    /// <summary>
+    /// 自动调参的结果
+    /// </summary>
+    public class TuneResult
+    {
+        public double DecayFactor { get; set; }
+        public double ColdStartBoost { get; set; }
+        
+        /// <summary>
+        /// 使用该参数模拟的结果
+        /// </summary>
+        public required SimulationResult Simulation { get; set; }
+        
+        /// <summary>
+        /// 是否满足最大概率的限制（没有参数满足时返回最大概率最低的参数）
+        /// </summary>
+        public bool MeetsTarget { get; set; }
+        
+        /// <summary>
+        /// 模拟的参数组合个数
+        /// </summary>
+        public int Evaluated { get; set; }
+    }
+}
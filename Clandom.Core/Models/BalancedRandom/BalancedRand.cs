@@ -0,0 +1,7067 @@
+using System;
+using System.Collections;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Globalization;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+using System.IO;
+using System.IO.Compression;
+using System.Security.Cryptography;
+using System.Text;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 平衡随机抽取数据存储结构
+    /// </summary>
+    public class BalancedRandData
+    {
+        public required string Id { get; set; }
+        public DateTime LastUpdated { get; set; }
+        
+        // 便于阅读的名称和描述（如"三班数学"）
+        public string? DisplayName { get; set; }
+        public string? Description { get; set; }
+        
+        // 命名空间（如按班级区分），保存的ID为 命名空间/ID；为null时属于默认命名空间
+        public string? Namespace { get; set; }
+        public required Dictionary<int, int> DrawCounts { get; set; }
+        public required Dictionary<int, int> LastDrawRound { get; set; }
+        public int CurrentRound { get; set; }
+        public int TotalDraws { get; set; }
+        public required Dictionary<int, double> CurrentProbabilities { get; set; }
+        
+        // 配置参数
+        public int MinPoolSize { get; set; }
+        public int MaxGapThreshold { get; set; }
+        public double ColdStartBoost { get; set; }
+        public double DecayFactor { get; set; }
+        public double MinWeight { get; set; } = 0.01;
+        public int MinSpacing { get; set; }
+        public bool NoRepeat { get; set; }
+        public bool BalancingEnabled { get; set; } = true;
+        public int WarmupRounds { get; set; }
+        public EmptyPoolPolicy EmptyPoolPolicy { get; set; } = EmptyPoolPolicy.Reset;
+        
+        // 轮流模式：每个学号按打乱后的顺序各抽一次，保存剩余的队列以便重启后继续
+        public bool RoundRobin { get; set; }
+        public List<int> RoundRobinQueue { get; set; } = new List<int>();
+        
+        // 自适应温度：在前 TemperatureOverDraws 次抽取中从 TemperatureStart 线性过渡到 TemperatureEnd
+        public bool AdaptiveTemperature { get; set; }
+        public double TemperatureStart { get; set; } = 1.0;
+        public double TemperatureEnd { get; set; } = 1.0;
+        public int TemperatureOverDraws { get; set; }
+        public int TemperatureProgress { get; set; }
+        
+        // 用于类型识别的字段
+        public required string Type { get; set; }
+        
+        // 用于2D类型的额外参数
+        public int Rows { get; set; }
+        public int Cols { get; set; }
+        
+        // 用于列表类型的参数
+        public List<int> Numbers { get; set; }
+        
+        // 用于范围类型的参数
+        public int NumberRangeStart { get; set; }
+        public int NumberRangeEnd { get; set; }
+        
+        // 用于等差范围类型的步长
+        public int Step { get; set; }
+        
+        // 用于长学号类型：内部学号（下标）-> 实际学号
+        public List<long> LongIds { get; set; } = new List<long>();
+        
+        // 黑名单/白名单功能
+        public HashSet<int> Blacklist { get; set; } = new HashSet<int>();
+        public HashSet<int> Whitelist { get; set; } = new HashSet<int>();
+        public bool WhitelistOnlyMode { get; set; } = false;
+        
+        // 学号 -> 显示名称（如学生姓名）
+        public Dictionary<int, string> Labels { get; set; } = new Dictionary<int, string>();
+        
+        // 学号 -> 分组ID，以及 分组ID -> 每次会话的抽取上限
+        public Dictionary<int, int> Groups { get; set; } = new Dictionary<int, int>();
+        public Dictionary<int, int> GroupQuotas { get; set; } = new Dictionary<int, int>();
+        
+        // 随机数种子及已消耗的随机数个数，用于恢复可复现的随机序列
+        public int? Seed { get; set; }
+        public long RngDrawsConsumed { get; set; }
+        
+        // 抽取历史（按抽取顺序）
+        public List<DrawRecord> History { get; set; } = new List<DrawRecord>();
+        
+        // 用于按天统计的时区，以及上次重置的时间（UTC）
+        public string TimeZoneId { get; set; } = "UTC";
+        public DateTime? LastResetAt { get; set; }
+        
+        // 之后新增的调节参数都放在 Advanced 中（见 AdvancedConfig）
+        public AdvancedConfig Advanced { get; set; } = new AdvancedConfig();
+
+        /// <summary>
+        /// 计算从当前数据到另一份数据的变化（忽略LastUpdated）
+        /// </summary>
+        /// <param name="other">变化后的数据</param>
+        /// <returns>两份数据之间的差异</returns>
+        public DataDiff Diff(BalancedRandData other)
+        {
+            var diff = new DataDiff
+            {
+                TotalDrawsDelta = other.TotalDraws - TotalDraws,
+                CurrentRoundDelta = other.CurrentRound - CurrentRound
+            };
+
+            // 抽取次数变化
+            foreach (var number in DrawCounts.Keys.Union(other.DrawCounts.Keys).OrderBy(n => n))
+            {
+                int before = DrawCounts.TryGetValue(number, out var b) ? b : 0;
+                int after = other.DrawCounts.TryGetValue(number, out var a) ? a : 0;
+                if (before != after)
+                {
+                    diff.ChangedCounts[number] = (before, after);
+                }
+            }
+
+            // 黑名单/白名单变化
+            var blacklist = Blacklist ?? new HashSet<int>();
+            var otherBlacklist = other.Blacklist ?? new HashSet<int>();
+            var whitelist = Whitelist ?? new HashSet<int>();
+            var otherWhitelist = other.Whitelist ?? new HashSet<int>();
+            diff.BlacklistAdded.AddRange(otherBlacklist.Except(blacklist).OrderBy(n => n));
+            diff.BlacklistRemoved.AddRange(blacklist.Except(otherBlacklist).OrderBy(n => n));
+            diff.WhitelistAdded.AddRange(otherWhitelist.Except(whitelist).OrderBy(n => n));
+            diff.WhitelistRemoved.AddRange(whitelist.Except(otherWhitelist).OrderBy(n => n));
+
+            // 配置参数变化
+            AddParameterChange(diff, nameof(MinPoolSize), MinPoolSize, other.MinPoolSize);
+            AddParameterChange(diff, nameof(MaxGapThreshold), MaxGapThreshold, other.MaxGapThreshold);
+            AddParameterChange(diff, nameof(ColdStartBoost), ColdStartBoost, other.ColdStartBoost);
+            AddParameterChange(diff, nameof(DecayFactor), DecayFactor, other.DecayFactor);
+            AddParameterChange(diff, nameof(MinWeight), MinWeight, other.MinWeight);
+            AddParameterChange(diff, nameof(MinSpacing), MinSpacing, other.MinSpacing);
+            AddParameterChange(diff, nameof(NoRepeat), NoRepeat, other.NoRepeat);
+            AddParameterChange(diff, nameof(BalancingEnabled), BalancingEnabled, other.BalancingEnabled);
+            AddParameterChange(diff, nameof(WarmupRounds), WarmupRounds, other.WarmupRounds);
+            AddParameterChange(diff, nameof(RoundRobin), RoundRobin, other.RoundRobin);
+            AddParameterChange(diff, nameof(AdaptiveTemperature), AdaptiveTemperature, other.AdaptiveTemperature);
+            AddParameterChange(diff, nameof(TemperatureStart), TemperatureStart, other.TemperatureStart);
+            AddParameterChange(diff, nameof(TemperatureEnd), TemperatureEnd, other.TemperatureEnd);
+            AddParameterChange(diff, nameof(TemperatureOverDraws), TemperatureOverDraws, other.TemperatureOverDraws);
+            AddParameterChange(diff, nameof(WhitelistOnlyMode), WhitelistOnlyMode, other.WhitelistOnlyMode);
+
+            return diff;
+        }
+
+        /// <summary>
+        /// 转换为YAML（字段名与数据文件中的JSON相同）
+        /// </summary>
+        public string ToYaml() => DataFormatConverter.ToYaml(this);
+
+        /// <summary>
+        /// 从YAML读取，不认识的字段或格式错误时抛出 InvalidDataException（包含所在的行列）
+        /// </summary>
+        public static BalancedRandData FromYaml(string yaml) => DataFormatConverter.FromYaml(yaml);
+
+        /// <summary>
+        /// 转换为TOML（字段名与数据文件中的JSON相同，值为null的字段不会导出）
+        /// </summary>
+        public string ToToml() => DataFormatConverter.ToToml(this);
+
+        /// <summary>
+        /// 从TOML读取，不认识的字段或格式错误时抛出 InvalidDataException（包含所在的行列）
+        /// </summary>
+        public static BalancedRandData FromToml(string toml) => DataFormatConverter.FromToml(toml);
+
+        /// <summary>
+        /// 检查数据是否合法（如反序列化后的数据）
+        /// </summary>
+        /// <returns>不合法的内容，合法时为空列表</returns>
+        public List<ValidationError> Validate()
+        {
+            var errors = new List<ValidationError>();
+            
+            if (string.IsNullOrEmpty(Id))
+                errors.Add(new ValidationError(nameof(Id), "数据ID不能为空"));
+            
+            // 类型相关的字段
+            switch (Type)
+            {
+                case "BalancedRand_Range":
+                    if (NumberRangeStart > NumberRangeEnd)
+                        errors.Add(new ValidationError(nameof(NumberRangeStart), $"起始值{NumberRangeStart}大于结束值{NumberRangeEnd}"));
+                    break;
+                case "BalancedRand_RangeStep":
+                    if (NumberRangeStart > NumberRangeEnd)
+                        errors.Add(new ValidationError(nameof(NumberRangeStart), $"起始值{NumberRangeStart}大于结束值{NumberRangeEnd}"));
+                    if (Step < 1)
+                        errors.Add(new ValidationError(nameof(Step), $"步长{Step}小于1"));
+                    break;
+                case "BalancedRand_List":
+                    if (Numbers == null || Numbers.Count == 0)
+                        errors.Add(new ValidationError(nameof(Numbers), "学号列表为空"));
+                    break;
+                case "BalancedRand_LongList":
+                    if (LongIds == null || LongIds.Count == 0)
+                        errors.Add(new ValidationError(nameof(LongIds), "学号列表为空"));
+                    break;
+                case "BalancedRandPlane":
+                    if (Rows < 1)
+                        errors.Add(new ValidationError(nameof(Rows), $"行数{Rows}小于1"));
+                    if (Cols < 1)
+                        errors.Add(new ValidationError(nameof(Cols), $"列数{Cols}小于1"));
+                    break;
+                default:
+                    errors.Add(new ValidationError(nameof(Type), $"未知的类型: {Type}"));
+                    break;
+            }
+            
+            // 抽取记录
+            if (DrawCounts == null || LastDrawRound == null || CurrentProbabilities == null)
+            {
+                errors.Add(new ValidationError(nameof(DrawCounts), "抽取记录缺失"));
+                return errors;
+            }
+            
+            foreach (var kvp in DrawCounts.Where(kvp => kvp.Value < 0))
+                errors.Add(new ValidationError(nameof(DrawCounts), $"学号{kvp.Key}的抽取次数{kvp.Value}小于0"));
+            
+            foreach (var kvp in LastDrawRound.Where(kvp => kvp.Value < -1))
+                errors.Add(new ValidationError(nameof(LastDrawRound), $"学号{kvp.Key}的最后抽中轮次{kvp.Value}不合法"));
+            
+            foreach (var kvp in CurrentProbabilities.Where(kvp => !(kvp.Value >= 0 && kvp.Value <= 1)))
+                errors.Add(new ValidationError(nameof(CurrentProbabilities), $"学号{kvp.Key}的概率{kvp.Value}不在[0, 1]范围内"));
+            
+            if (CurrentRound < 0)
+                errors.Add(new ValidationError(nameof(CurrentRound), $"当前轮次{CurrentRound}小于0"));
+            
+            if (RngDrawsConsumed < 0)
+                errors.Add(new ValidationError(nameof(RngDrawsConsumed), $"已消耗的随机数个数{RngDrawsConsumed}小于0"));
+            
+            if (TotalDraws < 0)
+                errors.Add(new ValidationError(nameof(TotalDraws), $"总抽取次数{TotalDraws}小于0"));
+            else if (DrawCounts.Values.Sum(c => (long)c) > TotalDraws)
+                errors.Add(new ValidationError(nameof(DrawCounts), $"抽取次数之和超过总抽取次数{TotalDraws}"));
+            
+            // 配置参数
+            if (MinPoolSize < 1)
+                errors.Add(new ValidationError(nameof(MinPoolSize), $"最小候选池大小{MinPoolSize}小于1"));
+            if (!double.IsFinite(ColdStartBoost))
+                errors.Add(new ValidationError(nameof(ColdStartBoost), $"冷启动提升系数{ColdStartBoost}不是有限值"));
+            if (!(DecayFactor > 0) || !double.IsFinite(DecayFactor))
+                errors.Add(new ValidationError(nameof(DecayFactor), $"权重衰减因子{DecayFactor}必须是大于0的有限值"));
+            if (!(MinWeight > 0) || !double.IsFinite(MinWeight))
+                errors.Add(new ValidationError(nameof(MinWeight), $"最小权重{MinWeight}必须是大于0的有限值"));
+            if (MinSpacing < 0)
+                errors.Add(new ValidationError(nameof(MinSpacing), $"最小间隔{MinSpacing}小于0"));
+            if (WarmupRounds < 0)
+                errors.Add(new ValidationError(nameof(WarmupRounds), $"预热轮次{WarmupRounds}小于0"));
+            
+            foreach (var kvp in (Advanced?.TargetShares ?? new Dictionary<int, double>()).Where(kvp => !(kvp.Value > 0) || !double.IsFinite(kvp.Value)))
+                errors.Add(new ValidationError(nameof(AdvancedConfig.TargetShares), $"学号{kvp.Key}的目标比例{kvp.Value}必须是大于0的有限值"));
+            foreach (var kvp in (Advanced?.LockedWeights ?? new Dictionary<int, double>()).Where(kvp => !(kvp.Value > 0) || !double.IsFinite(kvp.Value)))
+                errors.Add(new ValidationError(nameof(AdvancedConfig.LockedWeights), $"学号{kvp.Key}的锁定权重{kvp.Value}必须是大于0的有限值"));
+            if (Advanced?.DailyLimit is <= 0)
+                errors.Add(new ValidationError(nameof(AdvancedConfig.DailyLimit), $"每日抽取上限{Advanced.DailyLimit}必须大于0"));
+            if (Advanced?.CyclesCompleted < 0)
+                errors.Add(new ValidationError(nameof(AdvancedConfig.CyclesCompleted), $"完成的抽取周期数{Advanced.CyclesCompleted}小于0"));
+            if (Advanced?.PreferenceOrder is { } order && order.Distinct().Count() != order.Count)
+                errors.Add(new ValidationError(nameof(AdvancedConfig.PreferenceOrder), "偏好顺序中有重复的学号"));
+            var tickets = Advanced?.Tickets ?? new Dictionary<int, int>();
+            foreach (var kvp in tickets.Where(kvp => kvp.Value < 0))
+                errors.Add(new ValidationError(nameof(AdvancedConfig.Tickets), $"学号{kvp.Key}的彩票数量{kvp.Value}小于0"));
+            if (tickets.Count > 0 && tickets.Values.All(t => t == 0))
+                errors.Add(new ValidationError(nameof(AdvancedConfig.Tickets), "所有学号的彩票数量都为0"));
+            if (Advanced?.GapBoost is { } gapBoost && (!(gapBoost.ThresholdFraction >= 0) || double.IsInfinity(gapBoost.ThresholdFraction) || !(gapBoost.Scale >= 0) || double.IsInfinity(gapBoost.Scale)))
+                errors.Add(new ValidationError(nameof(AdvancedConfig.GapBoost), "长期未抽中提升的阈值和系数必须是不小于0的有限值"));
+            if (Advanced?.ProbabilitySnapshotEvery < 0)
+                errors.Add(new ValidationError(nameof(AdvancedConfig.ProbabilitySnapshotEvery), $"概率快照间隔{Advanced.ProbabilitySnapshotEvery}小于0"));
+            if (Advanced?.ProbabilitySnapshotEvery > 0 && Advanced.MaxProbabilitySnapshots < 1)
+                errors.Add(new ValidationError(nameof(AdvancedConfig.MaxProbabilitySnapshots), $"概率快照数量上限{Advanced.MaxProbabilitySnapshots}小于1"));
+            
+            return errors;
+        }
+
+        private static void AddParameterChange<T>(DataDiff diff, string name, T before, T after)
+        {
+            if (!EqualityComparer<T>.Default.Equals(before, after))
+            {
+                diff.ChangedParameters[name] = (before, after);
+            }
+        }
+
+        /// <summary>
+        /// 比较两份数据是否相同（忽略LastUpdated）
+        /// </summary>
+        public override bool Equals(object? obj)
+        {
+            if (obj is not BalancedRandData other) return false;
+            if (ReferenceEquals(this, other)) return true;
+
+            return Id == other.Id &&
+                   Type == other.Type &&
+                   DisplayName == other.DisplayName &&
+                   Description == other.Description &&
+                   Namespace == other.Namespace &&
+                   CurrentRound == other.CurrentRound &&
+                   TotalDraws == other.TotalDraws &&
+                   MinPoolSize == other.MinPoolSize &&
+                   MaxGapThreshold == other.MaxGapThreshold &&
+                   ColdStartBoost.Equals(other.ColdStartBoost) &&
+                   DecayFactor.Equals(other.DecayFactor) &&
+                   MinWeight.Equals(other.MinWeight) &&
+                   MinSpacing == other.MinSpacing &&
+                   NoRepeat == other.NoRepeat &&
+                   BalancingEnabled == other.BalancingEnabled &&
+                   WarmupRounds == other.WarmupRounds &&
+                   EmptyPoolPolicy == other.EmptyPoolPolicy &&
+                   RoundRobin == other.RoundRobin &&
+                   (RoundRobinQueue ?? new List<int>()).SequenceEqual(other.RoundRobinQueue ?? new List<int>()) &&
+                   AdaptiveTemperature == other.AdaptiveTemperature &&
+                   TemperatureStart.Equals(other.TemperatureStart) &&
+                   TemperatureEnd.Equals(other.TemperatureEnd) &&
+                   TemperatureOverDraws == other.TemperatureOverDraws &&
+                   TemperatureProgress == other.TemperatureProgress &&
+                   Rows == other.Rows &&
+                   Cols == other.Cols &&
+                   NumberRangeStart == other.NumberRangeStart &&
+                   NumberRangeEnd == other.NumberRangeEnd &&
+                   Step == other.Step &&
+                   (LongIds ?? new List<long>()).SequenceEqual(other.LongIds ?? new List<long>()) &&
+                   WhitelistOnlyMode == other.WhitelistOnlyMode &&
+                   DictionaryEquals(DrawCounts, other.DrawCounts) &&
+                   DictionaryEquals(LastDrawRound, other.LastDrawRound) &&
+                   DictionaryEquals(CurrentProbabilities, other.CurrentProbabilities) &&
+                   DictionaryEquals(Labels, other.Labels) &&
+                   DictionaryEquals(Groups, other.Groups) &&
+                   DictionaryEquals(GroupQuotas, other.GroupQuotas) &&
+                   Seed == other.Seed &&
+                   RngDrawsConsumed == other.RngDrawsConsumed &&
+                   TimeZoneId == other.TimeZoneId &&
+                   LastResetAt == other.LastResetAt &&
+                   (Advanced ?? new AdvancedConfig()).Equals(other.Advanced ?? new AdvancedConfig()) &&
+                   (History ?? new List<DrawRecord>()).Select(r => (r.Round, r.Number, r.Probability, r.Timestamp, r.Rank))
+                       .SequenceEqual((other.History ?? new List<DrawRecord>()).Select(r => (r.Round, r.Number, r.Probability, r.Timestamp, r.Rank))) &&
+                   (Numbers ?? new List<int>()).SequenceEqual(other.Numbers ?? new List<int>()) &&
+                   (Blacklist ?? new HashSet<int>()).SetEquals(other.Blacklist ?? new HashSet<int>()) &&
+                   (Whitelist ?? new HashSet<int>()).SetEquals(other.Whitelist ?? new HashSet<int>());
+        }
+
+        public override int GetHashCode()
+        {
+            return HashCode.Combine(Id, Type, CurrentRound, TotalDraws);
+        }
+
+        private static bool DictionaryEquals<TKey, TValue>(Dictionary<TKey, TValue>? a, Dictionary<TKey, TValue>? b)
+            where TKey : notnull
+        {
+            a ??= new Dictionary<TKey, TValue>();
+            b ??= new Dictionary<TKey, TValue>();
+            if (a.Count != b.Count) return false;
+
+            foreach (var kvp in a)
+            {
+                if (!b.TryGetValue(kvp.Key, out var value) || !EqualityComparer<TValue>.Default.Equals(kvp.Value, value))
+                    return false;
+            }
+            return true;
+        }
+    }
+    
+    /// <summary>
+    /// 平衡随机抽取数据管理器
+    /// </summary>
+    public static class BalancedRandDataManager
+    {
+        internal static readonly JsonSerializerOptions JsonOptions = new JsonSerializerOptions
+        {
+            WriteIndented = true,
+            Converters = { new JsonStringEnumConverter() }
+        };
+        
+        /// <summary>
+        /// SaveAllData 和 BalancedRand.SaveData 保存时使用的压缩方式（默认不压缩）
+        /// 读取时会自动识别文件是否经过gzip压缩，与这里的设置无关
+        /// </summary>
+        public static DataCompression Compression { get; set; } = DataCompression.None;
+        
+        // 同一进程中对数据文件的“读取-修改-写入”需要互斥，否则后台自动保存与界面线程同时保存时会丢失其中一方的修改
+        // 所有写入数据文件的方法（包括 DataFileHandle）都要在这个锁内读取和写入
+        internal static readonly object FileLock = new object();
+        
+        private const string ChecksumProperty = "Checksum";
+        private const string PayloadProperty = "Payload";
+        private const string BackupExtension = ".bak";
+        
+        // 加密文件格式：文件头 "CLANDOM-ENC" + 版本号(1字节)，随后是随机数(12字节)、认证标签(16字节)和密文
+        // 密文解密后为与未加密文件相同的带校验值的JSON
+        private static readonly byte[] EncryptedMagic = "CLANDOM-ENC"u8.ToArray();
+        private const byte EncryptionVersion = 1;
+        private const int EncryptionNonceSize = 12;
+        private const int EncryptionTagSize = 16;
+        
+        /// <summary>
+        /// 加载所有保存的数据
+        /// </summary>
+        public static Dictionary<string, BalancedRandData> LoadAllData(string filePath = "balanced_rand_data.json")
+        {
+            try
+            {
+                return ReadAllData(filePath);
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"加载数据失败: {ex.Message}");
+            }
+            
+            return new Dictionary<string, BalancedRandData>();
+        }
+        
+        /// <summary>
+        /// 加载所有保存的数据并检查每一项是否合法
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="mode">Skip: 跳过不合法的数据项（文件无法读取时返回空）；Strict: 遇到不合法的数据时抛出 InvalidDataException</param>
+        /// <param name="warnings">被跳过的数据项及原因</param>
+        public static Dictionary<string, BalancedRandData> LoadAllData(string filePath, ValidationMode mode,
+                                                                       out List<string> warnings)
+        {
+            warnings = new List<string>();
+            
+            Dictionary<string, BalancedRandData> allData;
+            try
+            {
+                allData = ReadAllData(filePath);
+            }
+            catch (Exception ex) when (mode == ValidationMode.Skip)
+            {
+                warnings.Add($"无法读取数据文件: {ex.Message}");
+                return new Dictionary<string, BalancedRandData>();
+            }
+            
+            var result = new Dictionary<string, BalancedRandData>();
+            foreach (var kvp in allData)
+            {
+                var errors = kvp.Value == null
+                    ? new List<ValidationError> { new ValidationError(nameof(BalancedRandData), "数据为空") }
+                    : kvp.Value.Validate();
+                
+                if (errors.Count == 0)
+                {
+                    result[kvp.Key] = kvp.Value!;
+                    continue;
+                }
+                
+                string message = $"{kvp.Key}: {string.Join("; ", errors)}";
+                if (mode == ValidationMode.Strict)
+                    throw new InvalidDataException($"数据不合法: {message}");
+                
+                warnings.Add(message);
+            }
+            
+            return result;
+        }
+        
+        /// <summary>
+        /// 保存所有数据
+        /// </summary>
+        public static void SaveAllData(Dictionary<string, BalancedRandData> allData, 
+                                      string filePath = "balanced_rand_data.json")
+        {
+            try
+            {
+                lock (FileLock)
+                {
+                    WriteAllData(allData, filePath);
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 将一项数据写入数据文件（读取、替换同ID的数据、写入在同一个锁内完成），失败时抛出异常
+        /// 数据文件无法读取（如已加密或校验失败且没有备份）时不会写入，避免用只包含这一项的文件覆盖原来的数据
+        /// </summary>
+        internal static void SaveEntry(BalancedRandData data, string filePath)
+        {
+            lock (FileLock)
+            {
+                var allData = ReadAllData(filePath);
+                allData[data.Id] = data;
+                WriteAllData(allData, filePath);
+            }
+        }
+        
+        /// <summary>
+        /// 一次保存多个实例的数据：只读取和写入一次数据文件，而不是每个实例各读写一次
+        /// 只读实例会被跳过
+        /// </summary>
+        /// <param name="entries">要保存的实例</param>
+        /// <param name="filePath">数据文件路径</param>
+        public static void SaveEntries(IEnumerable<BalancedRand> entries, string filePath = "balanced_rand_data.json")
+        {
+            try
+            {
+                lock (FileLock)
+                {
+                    var allData = ReadAllData(filePath);
+                    var saved = new List<(BalancedRand Instance, BalancedRandData Data)>();
+                    
+                    foreach (var entry in entries)
+                    {
+                        if (entry.IsReadOnly())
+                        {
+                            Debug.WriteLine($"跳过只读实例: {entry.ToData().Id}");
+                            continue;
+                        }
+                    
+                        var data = entry.ToData();
+                        allData[data.Id] = data;
+                        saved.Add((entry, data));
+                    }
+                    
+                    WriteAllData(allData, filePath);
+                    foreach (var (instance, data) in saved)
+                    {
+                        instance.MarkSynced(data);
+                    }
+                    
+                    Debug.WriteLine($"已保存{saved.Count}个实例的数据");
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 使用gzip压缩保存所有数据（文件名通常为 .json.gz），LoadAllData 可以直接读取
+        /// </summary>
+        public static void SaveAllDataCompressed(Dictionary<string, BalancedRandData> allData, string filePath,
+                                                 CompressionLevel level = CompressionLevel.Optimal)
+        {
+            try
+            {
+                lock (FileLock)
+                {
+                    WriteAllData(allData, filePath, DataCompression.Gzip(level));
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 将数据文件转换为指定的压缩方式
+        /// 先写入同目录下的临时文件再替换原文件，转换失败时原文件保持不变
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="target">目标压缩方式</param>
+        public static void ConvertCompression(string filePath, DataCompression target)
+        {
+            if (!File.Exists(filePath))
+                throw new FileNotFoundException("数据文件不存在", filePath);
+            
+            lock (FileLock)
+            {
+                var allData = ReadAllData(filePath);
+                string tempPath = filePath + ".tmp";
+                try
+                {
+                    WriteAllData(allData, tempPath, target);
+                    File.Move(tempPath, filePath, overwrite: true);
+                }
+                finally
+                {
+                    if (File.Exists(tempPath))
+                    {
+                        File.Delete(tempPath);
+                    }
+                }
+            }
+        }
+        
+        /// <summary>
+        /// 使用 AES-256-GCM 加密保存所有数据，只能用 LoadAllDataWithKey 读取
+        /// 原文件为加密文件时保留为备份（.bak）；原文件未加密时删除旧的备份，避免明文留在磁盘上
+        /// </summary>
+        /// <param name="allData">所有数据</param>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="key">密钥</param>
+        public static void SaveAllDataEncrypted(Dictionary<string, BalancedRandData> allData, string filePath,
+                                                DataEncryptionKey key)
+        {
+            try
+            {
+                lock (FileLock)
+                {
+                    WriteAllDataEncrypted(allData, filePath, key);
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 使用密钥读取加密的数据文件；文件未加密时按普通方式读取（便于迁移），文件不存在时返回空
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="key">密钥</param>
+        /// <exception cref="DecryptionFailedException">密钥错误或文件被篡改</exception>
+        public static Dictionary<string, BalancedRandData> LoadAllDataWithKey(string filePath, DataEncryptionKey key)
+        {
+            ArgumentNullException.ThrowIfNull(key);
+            
+            if (!File.Exists(filePath))
+                return new Dictionary<string, BalancedRandData>();
+            
+            byte[] bytes = File.ReadAllBytes(filePath);
+            if (!IsEncrypted(bytes))
+                return ReadAllData(filePath);
+            
+            return ReadEncryptedData(bytes, filePath, key);
+        }
+        
+        /// <summary>
+        /// 检查数据文件是否已加密
+        /// </summary>
+        public static bool IsEncryptedFile(string filePath)
+        {
+            if (!File.Exists(filePath)) return false;
+            
+            using var file = File.OpenRead(filePath);
+            return IsEncrypted(file);
+        }
+        
+        /// <summary>
+        /// 读取所有数据，失败时抛出异常
+        /// 文件以gzip文件头开始时先解压；文件带有校验值时先校验，校验失败且存在备份文件（.bak）时改为读取备份，
+        /// 没有备份时抛出 ChecksumMismatchException。没有校验值的旧格式文件直接读取，下次保存时会加上校验值
+        /// </summary>
+        internal static Dictionary<string, BalancedRandData> ReadAllData(string filePath)
+        {
+            if (!File.Exists(filePath))
+                return new Dictionary<string, BalancedRandData>();
+            
+            try
+            {
+                return ReadDataFile(filePath);
+            }
+            catch (ChecksumMismatchException) when (File.Exists(filePath + BackupExtension))
+            {
+                Debug.WriteLine($"数据文件校验失败，改为读取备份文件: {filePath}{BackupExtension}");
+                return ReadDataFile(filePath + BackupExtension);
+            }
+        }
+        
+        /// <summary>
+        /// 读取并校验一个数据文件
+        /// </summary>
+        private static Dictionary<string, BalancedRandData> ReadDataFile(string filePath)
+        {
+            using var file = File.OpenRead(filePath);
+            if (IsEncrypted(file))
+                throw new InvalidDataException($"数据文件已加密，需要使用 LoadAllDataWithKey 读取: {filePath}");
+            
+            Stream stream = IsGzip(file) ? new GZipStream(file, CompressionMode.Decompress) : file;
+            using (stream)
+            {
+                return ReadEnvelope(stream, filePath);
+            }
+        }
+        
+        /// <summary>
+        /// 解析带校验值的JSON并校验（没有校验值的旧格式直接解析）
+        /// </summary>
+        private static Dictionary<string, BalancedRandData> ReadEnvelope(Stream stream, string filePath)
+        {
+            using (var document = JsonDocument.Parse(stream))
+            {
+                var root = document.RootElement;
+                var payload = root;
+                if (root.ValueKind == JsonValueKind.Object &&
+                    root.TryGetProperty(ChecksumProperty, out var checksum))
+                {
+                    // 有校验值但没有数据内容（或校验值不是字符串）时视为文件损坏（可以读取备份），而不是把整个文件当作旧格式解析
+                    if (checksum.ValueKind != JsonValueKind.String || !root.TryGetProperty(PayloadProperty, out payload))
+                        throw new ChecksumMismatchException(filePath, checksum.ToString(), string.Empty);
+                    
+                    string expected = checksum.GetString() ?? string.Empty;
+                    string actual = ComputeChecksum(payload);
+                    if (!string.Equals(expected, actual, StringComparison.OrdinalIgnoreCase))
+                        throw new ChecksumMismatchException(filePath, expected, actual);
+                }
+                
+                return payload.Deserialize<Dictionary<string, BalancedRandData>>(JsonOptions) 
+                    ?? new Dictionary<string, BalancedRandData>();
+            }
+        }
+        
+        /// <summary>
+        /// 写入所有数据，失败时抛出异常
+        /// </summary>
+        /// <param name="compression">压缩方式，为null时使用 Compression</param>
+        internal static void WriteAllData(Dictionary<string, BalancedRandData> allData, string filePath,
+                                          DataCompression? compression = null)
+        {
+            compression ??= Compression;
+            
+            // 文件格式：{ "Checksum": "<数据内容的SHA-256>", "Payload": { ID -> 数据 } }
+            var payload = JsonSerializer.SerializeToElement(allData, JsonOptions);
+            string checksum = ComputeChecksum(payload);
+            
+            // 覆盖前保留上一个版本作为备份，文件损坏时读取备份
+            if (File.Exists(filePath))
+            {
+                File.Copy(filePath, filePath + BackupExtension, overwrite: true);
+            }
+            
+            using var file = File.Create(filePath);
+            Stream stream = compression.IsGzip ? new GZipStream(file, compression.Level) : file;
+            using (stream)
+            {
+                WriteEnvelope(stream, payload, checksum);
+            }
+        }
+        
+        /// <summary>
+        /// 写入带校验值的JSON
+        /// </summary>
+        private static void WriteEnvelope(Stream stream, JsonElement payload, string checksum)
+        {
+            using var writer = new Utf8JsonWriter(stream, new JsonWriterOptions { Indented = true });
+            writer.WriteStartObject();
+            writer.WriteString(ChecksumProperty, checksum);
+            writer.WritePropertyName(PayloadProperty);
+            payload.WriteTo(writer);
+            writer.WriteEndObject();
+        }
+        
+        /// <summary>
+        /// 加密写入所有数据，失败时抛出异常
+        /// </summary>
+        internal static void WriteAllDataEncrypted(Dictionary<string, BalancedRandData> allData, string filePath,
+                                                   DataEncryptionKey key)
+        {
+            ArgumentNullException.ThrowIfNull(key);
+            
+            var payload = JsonSerializer.SerializeToElement(allData, JsonOptions);
+            string checksum = ComputeChecksum(payload);
+            
+            byte[] plaintext;
+            using (var buffer = new MemoryStream())
+            {
+                WriteEnvelope(buffer, payload, checksum);
+                plaintext = buffer.ToArray();
+            }
+            
+            byte[] header = [..EncryptedMagic, EncryptionVersion];
+            byte[] nonce = RandomNumberGenerator.GetBytes(EncryptionNonceSize);
+            byte[] tag = new byte[EncryptionTagSize];
+            byte[] ciphertext = new byte[plaintext.Length];
+            using (var aes = new AesGcm(key.AsSpan(), EncryptionTagSize))
+            {
+                // 文件头作为附加数据参与认证，修改版本号也会导致解密失败
+                aes.Encrypt(nonce, plaintext, ciphertext, tag, header);
+            }
+            
+            if (File.Exists(filePath))
+            {
+                if (IsEncryptedFile(filePath))
+                {
+                    File.Copy(filePath, filePath + BackupExtension, overwrite: true);
+                }
+                else if (File.Exists(filePath + BackupExtension))
+                {
+                    File.Delete(filePath + BackupExtension);
+                }
+            }
+            
+            using var file = File.Create(filePath);
+            file.Write(header);
+            file.Write(nonce);
+            file.Write(tag);
+            file.Write(ciphertext);
+        }
+        
+        /// <summary>
+        /// 解密并读取加密的数据文件内容
+        /// </summary>
+        private static Dictionary<string, BalancedRandData> ReadEncryptedData(byte[] bytes, string filePath,
+                                                                              DataEncryptionKey key)
+        {
+            int headerSize = EncryptedMagic.Length + 1;
+            byte version = bytes[EncryptedMagic.Length];
+            if (version != EncryptionVersion)
+                throw new InvalidDataException($"不支持的加密数据文件版本{version}: {filePath}");
+            if (bytes.Length < headerSize + EncryptionNonceSize + EncryptionTagSize)
+                throw new DecryptionFailedException(filePath);
+            
+            var header = bytes.AsSpan(0, headerSize);
+            var nonce = bytes.AsSpan(headerSize, EncryptionNonceSize);
+            var tag = bytes.AsSpan(headerSize + EncryptionNonceSize, EncryptionTagSize);
+            var ciphertext = bytes.AsSpan(headerSize + EncryptionNonceSize + EncryptionTagSize);
+            byte[] plaintext = new byte[ciphertext.Length];
+            try
+            {
+                using var aes = new AesGcm(key.AsSpan(), EncryptionTagSize);
+                aes.Decrypt(nonce, ciphertext, tag, plaintext, header);
+            }
+            catch (CryptographicException ex)
+            {
+                throw new DecryptionFailedException(filePath, ex);
+            }
+            
+            using var stream = new MemoryStream(plaintext);
+            return ReadEnvelope(stream, filePath);
+        }
+        
+        /// <summary>
+        /// 计算数据内容的校验值（规范化后的JSON的SHA-256，十六进制小写）
+        /// 规范化时对象的属性按名称排序，因此与字典中的顺序无关
+        /// </summary>
+        internal static string ComputeChecksum(JsonElement payload)
+        {
+            using var buffer = new MemoryStream();
+            using (var writer = new Utf8JsonWriter(buffer))
+            {
+                WriteCanonical(payload, writer);
+            }
+            return Convert.ToHexString(SHA256.HashData(buffer.ToArray())).ToLowerInvariant();
+        }
+        
+        /// <summary>
+        /// 以规范形式写入JSON：对象属性按名称（序数）排序，没有缩进
+        /// </summary>
+        private static void WriteCanonical(JsonElement element, Utf8JsonWriter writer)
+        {
+            switch (element.ValueKind)
+            {
+                case JsonValueKind.Object:
+                    writer.WriteStartObject();
+                    foreach (var property in element.EnumerateObject().OrderBy(p => p.Name, StringComparer.Ordinal))
+                    {
+                        writer.WritePropertyName(property.Name);
+                        WriteCanonical(property.Value, writer);
+                    }
+                    writer.WriteEndObject();
+                    break;
+                case JsonValueKind.Array:
+                    writer.WriteStartArray();
+                    foreach (var item in element.EnumerateArray())
+                    {
+                        WriteCanonical(item, writer);
+                    }
+                    writer.WriteEndArray();
+                    break;
+                default:
+                    element.WriteTo(writer);
+                    break;
+            }
+        }
+        
+        /// <summary>
+        /// 检查流是否以加密文件头开始，检查后流的位置恢复到开头
+        /// </summary>
+        private static bool IsEncrypted(Stream stream)
+        {
+            var buffer = new byte[EncryptedMagic.Length + 1];
+            int read = stream.ReadAtLeast(buffer, buffer.Length, throwOnEndOfStream: false);
+            stream.Position = 0;
+            return IsEncrypted(buffer.AsSpan(0, read));
+        }
+        
+        /// <summary>
+        /// 检查内容是否以加密文件头（包括版本号）开始
+        /// </summary>
+        private static bool IsEncrypted(ReadOnlySpan<byte> bytes)
+        {
+            return bytes.Length > EncryptedMagic.Length && bytes.StartsWith(EncryptedMagic);
+        }
+        
+        /// <summary>
+        /// 检查流是否以gzip文件头（1F 8B）开始，检查后流的位置恢复到开头
+        /// </summary>
+        private static bool IsGzip(Stream stream)
+        {
+            int b1 = stream.ReadByte();
+            int b2 = stream.ReadByte();
+            stream.Position = 0;
+            return b1 == 0x1F && b2 == 0x8B;
+        }
+        
+        /// <summary>
+        /// 根据参数生成唯一ID
+        /// 数值按固定区域格式（InvariantCulture）转换为文本，使相同的配置在任何系统区域设置下都得到相同的ID
+        /// （如小数点为逗号的区域中 0.7 曾被写为 "0,7"）
+        /// </summary>
+        public static string GenerateId(string type, params object[] parameters)
+        {
+            string paramString = string.Join("_", parameters.Select(p => p switch
+            {
+                null => "null",
+                IFormattable formattable => formattable.ToString(null, CultureInfo.InvariantCulture),
+                _ => p.ToString() ?? "null"
+            }));
+            return $"{type}_{paramString}";
+        }
+        
+        /// <summary>
+        /// 统一ID中小数参数的格式，用于判断两个ID是否只有小数格式不同（如 "0.70" 与 "0.7"、"2.0" 与 "2"、"0,7" 与 "0.7"）
+        /// 所有构造函数生成的ID都以冷启动提升系数和衰减因子这两个小数参数结尾，只处理最后两段
+        /// </summary>
+        internal static string NormalizeId(string id)
+        {
+            var parts = id.Split('_');
+            for (int i = Math.Max(parts.Length - 2, 0); i < parts.Length; i++)
+            {
+                if (double.TryParse(parts[i].Replace(',', '.'), NumberStyles.Float, CultureInfo.InvariantCulture, out var value))
+                {
+                    parts[i] = value.ToString(CultureInfo.InvariantCulture);
+                }
+            }
+            
+            return string.Join("_", parts);
+        }
+        
+        /// <summary>
+        /// 查找只有小数格式不同的重复配置（如在不同区域设置的系统上保存的同一配置），以便手动合并
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <returns>每组重复配置的ID（每组至少两个，按ID排序）</returns>
+        public static List<List<string>> FindDuplicates(string filePath = "balanced_rand_data.json")
+        {
+            return LoadAllData(filePath).Values
+                .GroupBy(data => (data.Type, Id: NormalizeId(data.Id)))
+                .Where(g => g.Count() > 1)
+                .Select(g => g.Select(data => data.Id).OrderBy(id => id, StringComparer.Ordinal).ToList())
+                .OrderBy(ids => ids[0], StringComparer.Ordinal)
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 列出所有保存的配置
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <returns>配置列表，包含ID、类型、显示名称和描述</returns>
+        public static List<(string Id, string Type, string? DisplayName, string? Description)> ListConfigs(
+            string filePath = "balanced_rand_data.json")
+        {
+            return LoadAllData(filePath).Values
+                .OrderBy(data => data.DisplayName ?? data.Id)
+                .Select(data => (data.Id, data.Type, data.DisplayName, data.Description))
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 将数据文件中的一项配置导出为指定格式的文本（便于手动编辑）
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="id">配置ID</param>
+        /// <param name="format">导出格式</param>
+        public static string ExportEntryAs(string filePath, string id, DataFormat format)
+        {
+            var allData = ReadAllData(filePath);
+            if (!allData.TryGetValue(id, out var data))
+                throw new KeyNotFoundException($"未找到数据: {id}");
+            
+            return format switch
+            {
+                DataFormat.Yaml => data.ToYaml(),
+                DataFormat.Toml => data.ToToml(),
+                _ => DataFormatConverter.ToJson(data)
+            };
+        }
+        
+        /// <summary>
+        /// 从指定格式的文本导入一项配置并保存到数据文件中（ID相同的配置会被替换）
+        /// 不认识的字段、格式错误或数据不合法时抛出 InvalidDataException，数据文件不会被修改
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="source">配置内容</param>
+        /// <param name="format">配置内容的格式</param>
+        /// <returns>导入的配置ID</returns>
+        public static string ImportEntryFrom(string filePath, string source, DataFormat format)
+        {
+            var data = format switch
+            {
+                DataFormat.Yaml => BalancedRandData.FromYaml(source),
+                DataFormat.Toml => BalancedRandData.FromToml(source),
+                _ => DataFormatConverter.FromJson(source)
+            };
+            
+            var errors = data.Validate();
+            if (errors.Count > 0)
+                throw new InvalidDataException($"数据不合法: {string.Join("; ", errors)}");
+            
+            lock (FileLock)
+            {
+                var allData = ReadAllData(filePath);
+                allData[data.Id] = data;
+                WriteAllData(allData, filePath);
+            }
+            return data.Id;
+        }
+        
+        /// <summary>
+        /// 将数据文件中的一项配置单独导出为JSON文件（如把一个班的数据复制到另一台电脑）
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="id">配置ID</param>
+        /// <param name="outPath">导出的文件路径</param>
+        public static void ExportProfile(string filePath, string id, string outPath)
+        {
+            var allData = ReadAllData(filePath);
+            if (!allData.TryGetValue(id, out var data))
+                throw new KeyNotFoundException($"未找到数据: {id}");
+            
+            File.WriteAllText(outPath, JsonSerializer.Serialize(data, JsonOptions));
+        }
+        
+        /// <summary>
+        /// 将 ExportProfile 导出的配置导入到数据文件中
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="inPath">导出的配置文件路径</param>
+        /// <param name="overwrite">数据文件中已有相同ID的配置时是否覆盖，为false时抛出 InvalidOperationException</param>
+        /// <returns>导入的配置ID</returns>
+        public static string ImportProfile(string filePath, string inPath, bool overwrite)
+        {
+            var data = JsonSerializer.Deserialize<BalancedRandData>(File.ReadAllText(inPath), JsonOptions)
+                ?? throw new InvalidDataException($"配置文件为空: {inPath}");
+            
+            var errors = data.Validate();
+            if (errors.Count > 0)
+                throw new InvalidDataException($"数据不合法: {string.Join("; ", errors)}");
+            
+            lock (FileLock)
+            {
+                var allData = ReadAllData(filePath);
+                if (allData.ContainsKey(data.Id) && !overwrite)
+                    throw new InvalidOperationException($"数据文件中已有相同ID的配置: {data.Id}");
+                
+                allData[data.Id] = data;
+                WriteAllData(allData, filePath);
+            }
+            return data.Id;
+        }
+        
+        /// <summary>
+        /// 查找匹配的数据
+        /// </summary>
+        public static BalancedRandData FindMatchingData(Dictionary<string, BalancedRandData> allData, string type, params object[] parameters)
+        {
+            string targetId = GenerateId(type, parameters);
+            
+            // 精确匹配
+            if (allData.ContainsKey(targetId))
+            {
+                return allData[targetId];
+            }
+            
+            // 模糊匹配：查找相同类型和相似参数的数据
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == type)
+                {
+                    // 根据类型进行不同的匹配逻辑
+                    if (type == "BalancedRandPlane")
+                    {
+                        // 对于2D类型，检查行列是否匹配
+                        // 这里可以根据需要实现更复杂的匹配逻辑
+                    }
+                }
+            }
+            
+            return null;
+        }
+        
+        /// <summary>
+        /// 为ID加上命名空间前缀（命名空间为null时不变）
+        /// </summary>
+        internal static string QualifyId(string? ns, string id) => ns == null ? id : $"{ns}/{id}";
+        
+        /// <summary>
+        /// 去掉ID的命名空间前缀
+        /// </summary>
+        internal static string UnqualifyId(string? ns, string id)
+        {
+            return ns != null && id.StartsWith(ns + "/", StringComparison.Ordinal) ? id.Substring(ns.Length + 1) : id;
+        }
+        
+        /// <summary>
+        /// 数据是否属于指定的命名空间；ns 为null时不过滤
+        /// </summary>
+        private static bool InNamespace(BalancedRandData data, string? ns) => ns == null || data.Namespace == ns;
+        
+        /// <summary>
+        /// 获取数据文件中的所有命名空间（不包括默认命名空间）
+        /// </summary>
+        public static List<string> ListNamespaces(string filePath = "balanced_rand_data.json")
+        {
+            return LoadAllData(filePath).Values
+                .Select(data => data.Namespace)
+                .OfType<string>()
+                .Distinct()
+                .OrderBy(ns => ns, StringComparer.Ordinal)
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 获取命名空间中所有配置的ID
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">命名空间，为null时为默认命名空间（没有设置命名空间的配置，包括旧版本保存的配置）</param>
+        public static List<string> ListEntriesIn(string filePath, string? ns)
+        {
+            return LoadAllData(filePath)
+                .Where(kvp => kvp.Value.Namespace == ns)
+                .Select(kvp => kvp.Key)
+                .OrderBy(id => id, StringComparer.Ordinal)
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 删除命名空间中的所有配置
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">命名空间（不能删除默认命名空间）</param>
+        /// <returns>删除的配置数量</returns>
+        public static int DeleteNamespace(string filePath, string ns)
+        {
+            if (string.IsNullOrEmpty(ns))
+                throw new ArgumentException("命名空间不能为空");
+            
+            lock (FileLock)
+            {
+                var allData = ReadAllData(filePath);
+                var ids = allData.Where(kvp => kvp.Value.Namespace == ns).Select(kvp => kvp.Key).ToList();
+                if (ids.Count == 0) return 0;
+                
+                foreach (var id in ids)
+                {
+                    allData.Remove(id);
+                }
+                WriteAllData(allData, filePath);
+                return ids.Count;
+            }
+        }
+        
+        /// <summary>
+        /// 获取所有学号范围数据
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只返回该命名空间中的数据，为null时不过滤</param>
+        /// <returns>二维列表，每个子列表表示一个学号范围 [start, end]</returns>
+        public static List<List<int>> GetAllIdData(string filePath = "balanced_rand_data.json", string? ns = null)
+        {
+            var allData = LoadAllData(filePath);
+            var result = new List<List<int>>();
+            
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRand_Range" && InNamespace(data, ns))
+                {
+                    // 学号范围数据
+                    result.Add(new List<int> { data.NumberRangeStart, data.NumberRangeEnd });
+                }
+            }
+            
+            return result;
+        }
+        
+        /// <summary>
+        /// 获取所有2D行列配置
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只返回该命名空间中的数据，为null时不过滤</param>
+        /// <returns>二维列表，每个子列表表示一个2D配置 [rows, cols]</returns>
+        public static List<List<int>> GetAllPlaneData(string filePath = "balanced_rand_data.json", string? ns = null)
+        {
+            var allData = LoadAllData(filePath);
+            var result = new List<List<int>>();
+            
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRandPlane" && InNamespace(data, ns))
+                {
+                    // 2D行列数据
+                    result.Add(new List<int> { data.Rows, data.Cols });
+                }
+            }
+            
+            return result;
+        }
+        
+        /// <summary>
+        /// 从指定学号范围中读取权重列表
+        /// </summary>
+        /// <param name="range">学号范围，如 [1, 50]</param>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只在该命名空间中查找，为null时不过滤（相同配置存在于多个命名空间时返回第一个）</param>
+        /// <returns>权重列表，按学号顺序排列</returns>
+        public static List<double> GetWeightsByIdRange(List<int> range, string filePath = "balanced_rand_data.json", 
+                                                       string? ns = null)
+        {
+            if (range == null || range.Count != 2)
+                throw new ArgumentException("学号范围参数必须包含两个元素 [start, end]");
+            
+            var allData = LoadAllData(filePath);
+            
+            // 查找匹配的学号范围数据
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRand_Range" && 
+                    data.NumberRangeStart == range[0] && 
+                    data.NumberRangeEnd == range[1] &&
+                    InNamespace(data, ns))
+                {
+                    return GetNumberRangeWeightList(data);
+                }
+            }
+            
+            // 未找到匹配的数据
+            throw new KeyNotFoundException($"未找到匹配的学号范围数据: [{range[0]}, {range[1]}]");
+        }
+        
+        /// <summary>
+        /// 从指定2D配置中读取权重列表
+        /// </summary>
+        /// <param name="range">2D配置，如 [3, 4] 表示3行4列</param>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只在该命名空间中查找，为null时不过滤（相同配置存在于多个命名空间时返回第一个）</param>
+        /// <returns>权重列表，按位置顺序排列（行优先）</returns>
+        public static Dictionary<List<int>,double> GetWeightsByPlaneRange(List<int> range, string filePath = "balanced_rand_data.json", 
+                                                                          string? ns = null)
+        {
+            if (range == null || range.Count != 2)
+                throw new ArgumentException("Plane配置参数必须包含两个元素 [rows, cols]");
+            
+            var allData = LoadAllData(filePath);
+            
+            // 查找匹配的2D配置数据
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRandPlane" && 
+                    data.Rows == range[0] && 
+                    data.Cols == range[1] &&
+                    InNamespace(data, ns))
+                {
+                    return GetPlaneConfigWeightList(data);
+                }
+            }
+            
+            // 未找到匹配的数据
+            throw new KeyNotFoundException($"未找到匹配的Plane配置数据: [{range[0]}, {range[1]}]");
+        }
+        
+        /// <summary>
+        /// 从学号范围数据中提取权重列表（按学号顺序）
+        /// </summary>
+        private static List<double> GetNumberRangeWeightList(BalancedRandData data)
+        {
+            var weights = new List<double>();
+            
+            if (data.Type != "BalancedRand_Range")
+                throw new ArgumentException("数据类型必须是BalancedRand_Range");
+            
+            // 对于学号范围，按学号顺序提取权重
+            for (int i = data.NumberRangeStart; i <= data.NumberRangeEnd; i++)
+            {
+                if (data.CurrentProbabilities.ContainsKey(i))
+                {
+                    weights.Add(data.CurrentProbabilities[i]);
+                }
+                else
+                {
+                    weights.Add(0); // 未找到的学号权重设为0
+                }
+            }
+            
+            return weights;
+        }
+        
+        /// <summary>
+        /// 从2D配置数据中提取权重列表（按位置顺序，行优先）
+        /// </summary>
+        internal static Dictionary<List<int>,double> GetPlaneConfigWeightList(BalancedRandData data)
+        {
+            // {[1,1]:0.2,[2,1]:0.3}
+            if (data.Type != "BalancedRandPlane")
+                throw new ArgumentException("数据类型必须是BalancedRandPlane");
+            
+            return ToPlaneDictionary(data.CurrentProbabilities, data.Rows, data.Cols);
+        }
+        
+        /// <summary>
+        /// 将按位置索引存储的数据转换为以 [列, 行]（从0开始）为键的字典（行优先）
+        /// </summary>
+        private static Dictionary<List<int>, T> ToPlaneDictionary<T>(Dictionary<int, T> values, int rows, int cols)
+        {
+            var result = new Dictionary<List<int>, T>();
+            
+            int totalPositions = rows * cols;
+            for (int i = 0; i < totalPositions; i++)
+            {
+                if (values.TryGetValue(i, out var value))
+                {
+                    var (row, col) = PlanePositionOf(rows, cols, i);
+                    result.Add([col - 1, row - 1], value);
+                }
+            }
+            
+            return result;
+        }
+        
+        /// <summary>
+        /// 将2D网格中的行列（从1开始）转换为内部序号（行优先，从0开始）
+        /// 所有行列与序号之间的转换都应使用该方法和 PlanePositionOf，避免各处的边界处理不一致
+        /// </summary>
+        /// <param name="rows">行数</param>
+        /// <param name="cols">列数</param>
+        /// <param name="row">行号（1-based）</param>
+        /// <param name="col">列号（1-based）</param>
+        /// <exception cref="ArgumentOutOfRangeException">位置超出网格</exception>
+        public static int PlaneIndexOf(int rows, int cols, int row, int col)
+        {
+            if (row < 1 || row > rows || col < 1 || col > cols)
+                throw new ArgumentOutOfRangeException(nameof(row), $"位置({row}, {col})超出了{rows}行{cols}列的范围");
+            
+            return (row - 1) * cols + (col - 1);
+        }
+        
+        /// <summary>
+        /// 将2D网格中的内部序号（行优先，从0开始）转换为行列（从1开始）
+        /// </summary>
+        /// <param name="rows">行数</param>
+        /// <param name="cols">列数</param>
+        /// <param name="index">内部序号</param>
+        /// <exception cref="ArgumentOutOfRangeException">序号超出网格</exception>
+        public static (int row, int col) PlanePositionOf(int rows, int cols, int index)
+        {
+            if (index < 0 || index >= rows * cols)
+                throw new ArgumentOutOfRangeException(nameof(index), $"序号{index}超出了{rows}行{cols}列的范围");
+            
+            return (index / cols + 1, index % cols + 1);
+        }
+        
+        /// <summary>
+        /// 从指定学号范围中读取抽取次数列表
+        /// </summary>
+        /// <param name="range">学号范围，如 [1, 50]</param>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只在该命名空间中查找，为null时不过滤（相同配置存在于多个命名空间时返回第一个）</param>
+        /// <returns>抽取次数列表，按学号顺序排列</returns>
+        public static List<int> GetDrawCountsByIdRange(List<int> range, string filePath = "balanced_rand_data.json", 
+                                                       string? ns = null)
+        {
+            if (range == null || range.Count != 2)
+                throw new ArgumentException("学号范围参数必须包含两个元素 [start, end]");
+            
+            var allData = LoadAllData(filePath);
+            
+            // 查找匹配的学号范围数据
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRand_Range" && 
+                    data.NumberRangeStart == range[0] && 
+                    data.NumberRangeEnd == range[1] &&
+                    InNamespace(data, ns))
+                {
+                    return GetNumberRangeDrawCounts(data);
+                }
+            }
+            
+            // 未找到匹配的数据
+            throw new KeyNotFoundException($"未找到匹配的学号范围数据: [{range[0]}, {range[1]}]");
+        }
+        
+        /// <summary>
+        /// 从指定2D配置中读取抽取次数列表
+        /// </summary>
+        /// <param name="range">2D配置，如 [3, 4] 表示3行4列</param>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="ns">只在该命名空间中查找，为null时不过滤（相同配置存在于多个命名空间时返回第一个）</param>
+        /// <returns>抽取次数列表，按位置顺序排列（行优先）</returns>
+        public static Dictionary<List<int>,int> GetDrawCountsByPlaneRange(List<int> range, string filePath = "balanced_rand_data.json", 
+                                                                          string? ns = null)
+        {
+            if (range == null || range.Count != 2)
+                throw new ArgumentException("Plane配置参数必须包含两个元素 [rows, cols]");
+            
+            var allData = LoadAllData(filePath);
+            
+            // 查找匹配的2D配置数据
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRandPlane" && 
+                    data.Rows == range[0] && 
+                    data.Cols == range[1] &&
+                    InNamespace(data, ns))
+                {
+                    return GetPlaneConfigDrawCounts(data);
+                }
+            }
+            
+            // 未找到匹配的数据
+            throw new KeyNotFoundException($"未找到匹配的Plane配置数据: [{range[0]}, {range[1]}]");
+        }
+        
+        /// <summary>
+        /// 汇总数据文件中所有配置的抽取次数（学号 -> 所有配置中的抽取次数之和）
+        /// 不同配置中相同的学号视为同一个人，次数相加（如同一个名单的多个配置）；
+        /// 学号不重叠的配置之间互不影响，结果中包含所有配置的学号。
+        /// 长学号类型中保存的是内部编号，不是实际学号，因此不参与汇总；
+        /// 2D数据中的学号是位置的下标（从0开始，行优先），与学号没有对应关系，默认不参与汇总
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <param name="includePlanes">是否把2D数据的位置下标也作为学号汇总</param>
+        public static Dictionary<int, int> AggregateCounts(string filePath = "balanced_rand_data.json", 
+                                                           bool includePlanes = false)
+        {
+            var allData = LoadAllData(filePath);
+            var result = new Dictionary<int, int>();
+            
+            foreach (var data in allData.Values)
+            {
+                if (data.Type == "BalancedRand_LongList") continue;
+                if (data.Type == "BalancedRandPlane" && !includePlanes) continue;
+                
+                foreach (var kvp in data.DrawCounts)
+                {
+                    result[kvp.Key] = result.TryGetValue(kvp.Key, out var count) ? count + kvp.Value : kvp.Value;
+                }
+            }
+            
+            return result;
+        }
+        
+        /// <summary>
+        /// 从学号范围数据中提取抽取次数列表（按学号顺序）
+        /// </summary>
+        private static List<int> GetNumberRangeDrawCounts(BalancedRandData data)
+        {
+            var drawCounts = new List<int>();
+            
+            if (data.Type != "BalancedRand_Range")
+                throw new ArgumentException("数据类型必须是BalancedRand_Range");
+            
+            // 对于学号范围，按学号顺序提取抽取次数
+            for (int i = data.NumberRangeStart; i <= data.NumberRangeEnd; i++)
+            {
+                if (data.DrawCounts.ContainsKey(i))
+                {
+                    drawCounts.Add(data.DrawCounts[i]);
+                }
+                else
+                {
+                    drawCounts.Add(0); // 未找到的学号抽取次数设为0
+                }
+            }
+            
+            return drawCounts;
+        }
+        
+        /// <summary>
+        /// 从2D配置数据中提取抽取次数列表（按位置顺序，行优先）
+        /// </summary>
+        internal static Dictionary<List<int>,int> GetPlaneConfigDrawCounts(BalancedRandData data)
+        {
+            if (data.Type != "BalancedRandPlane")
+                throw new ArgumentException("数据类型必须是BalancedRandPlane");
+            
+            return ToPlaneDictionary(data.DrawCounts, data.Rows, data.Cols);
+        }
+    }
+
+    /// <summary>
+    /// 平衡随机抽取类，提供智能动态权重算法和平均值差值保护机制
+    /// </summary>
+    public class BalancedRand : IEnumerable<int>
+    {
+        // 内部数据结构
+        // 连续范围使用数组存储，列表类型使用字典存储
+        private NumberMap<int> _drawCounts;  // 学号 -> 抽取次数
+        private NumberMap<int> _lastDrawRound;  // 学号 -> 最后被抽中的轮次
+        private List<int> _allNumbers;  // 所有学号
+        private HashSet<int> _allNumbersSet;  // 所有学号（用于快速判断成员）
+        private List<int>? _candidatePool;  // 当前候选池
+        private List<int>? _activeNumbers;  // 活跃学号缓存（原始学号加白名单额外学号，按学号排序）
+        private HashSet<int>? _batchDrawn;  // 批量抽取模式下本批已抽中的学号（null表示不在批量模式）
+        private Random _random;
+        private int? _seed;  // 随机数种子（null表示不可复现）
+        private long _rngDrawsConsumed;  // 已消耗的随机数个数
+        private const long MaxRngReplay = 10_000_000;  // 恢复随机数序列时最多跳过的个数
+        
+        // 配置参数
+        private int _currentRound;  // 当前抽取轮次
+        private int _minPoolSize;  // 最小候选池大小
+        private int _maxGapThreshold;  // 最大差距阈值
+        private double _coldStartBoost;  // 冷启动提升系数
+        private double _decayFactor;  // 权重衰减因子
+        private double _minWeight = 0.01;  // 最小权重
+        private int _minSpacing;  // 同一学号两次被抽中之间至少间隔的抽取次数
+        private bool _noRepeat;  // 是否禁止连续两次抽中同一学号
+        private bool _balancingEnabled = true;  // 是否启用平衡权重
+        private int _warmupRounds;  // 预热轮次（不超过该轮次时使用相同的权重）
+        private GapBoost _gapBoost = new GapBoost();  // 长期未被抽中的学号的权重提升
+        private EmptyPoolPolicy _emptyPoolPolicy = EmptyPoolPolicy.Reset;  // 候选池为空时的处理方式
+        private bool _roundRobin;  // 轮流模式
+        private bool _lotteryMode;  // 抽奖模式：按彩票数量抽取
+        private bool _lotteryDecay;  // 抽奖模式下是否对已中奖的学号应用权重衰减
+        private Dictionary<int, int> _tickets = new Dictionary<int, int>();  // 学号 -> 彩票数量
+        private List<int> _roundRobinQueue = new List<int>();  // 轮流模式中本轮剩余的学号（按抽取顺序）
+        private bool _readOnly;  // 只读模式（该设置不保存）
+        
+        // 自适应温度（权重取 温度 次幂，0为均匀随机，1为正常平衡）
+        private bool _adaptiveTemperature;
+        private double _temperatureStart = 1.0;
+        private double _temperatureEnd = 1.0;
+        private int _temperatureOverDraws;
+        private int _temperatureProgress;  // 设置自适应温度以来的抽取次数
+        
+        // 统计信息
+        private int _totalDraws;
+        private int _cyclesCompleted;  // 候选池耗尽后自动重置抽取次数的次数（即所有人都被抽过一遍的次数）
+        private NumberMap<double> _currentProbabilities;
+        private List<DrawRecord> _history = new List<DrawRecord>();  // 抽取历史
+        private TimeZoneInfo _timeZone = TimeZoneInfo.Utc;  // 按天统计时使用的时区
+        private DateTime? _lastResetAt;  // 上次重置的时间（UTC）
+        private Func<DateTime> _clock = () => DateTime.UtcNow;  // 获取当前时间（UTC），可替换以便测试
+        private int? _dailyLimit;  // 每个学号每天最多被抽取的次数
+        private Dictionary<int, DailyCount> _dailyCounts = new Dictionary<int, DailyCount>();  // 学号 -> 最近一次被抽中的日期及当天的抽取次数
+        private DateOnly? _candidatePoolDate;  // 计算候选池时的日期（设置了每日上限时使用）
+        
+        // 概率快照（用于绘制公平性随时间变化的图表）
+        private int _snapshotEvery;  // 每隔多少轮记录一次，0表示不记录
+        private int _maxSnapshots;  // 最多保留的快照数量，超过时移除最早的
+        private bool _persistSnapshots;  // 是否保存快照
+        private List<ProbabilitySnapshot> _snapshots = new List<ProbabilitySnapshot>();
+        
+        // 自动重新加载
+        private BalancedRandData? _lastSyncedData;  // 最近一次加载或保存的数据
+        private FileSystemWatcher? _watcher;
+        private Timer? _reloadTimer;
+        private TimeSpan _reloadDebounce;
+        private string? _watchedFilePath;
+        private SynchronizationContext? _reloadContext;  // 启用自动重新加载时的同步上下文（如界面线程）
+        
+        private Func<int, bool>? _candidateFilter;  // 自定义候选过滤条件（不保存）
+        private string? _auditLogPath;  // 审计日志文件路径（不保存）
+        private bool _silentDraw;  // 正在进行 DrawSilent（不写审计日志）
+        private Dictionary<string, JsonElement>? _unknownAdvancedConfig;  // 更新的版本写入的、当前版本不认识的调节参数
+        
+        // 目标抽取比例（相对值，未设置的学号为1；为空时所有学号平均）
+        private Dictionary<int, double> _targetShares = new Dictionary<int, double>();
+        private double? _targetShareTotal;  // 活跃学号的目标比例之和（缓存）
+        private Dictionary<int, double> _lockedWeights = new Dictionary<int, double>();  // 锁定的学号 -> 锁定的权重
+        private Dictionary<int, int> _preferenceRanks = new Dictionary<int, int>();  // 偏好顺序：学号 -> 在顺序中的位置
+        
+        // 偏好顺序的最大权重加成（相对值），远小于平衡产生的权重差异，只用于区分权重完全相同的学号
+        private const double PreferenceBonus = 1e-9;
+        
+        // 数据标识和类型
+        private string _dataId;
+        private string? _namespace;  // 命名空间，数据ID带有 命名空间/ 前缀
+        private string _type;
+        
+        // 构造函数参数
+        private int _numberRangeStart;
+        private int _numberRangeEnd;
+        private int _step;
+        private List<long>? _longIds;  // 长学号类型：内部学号（下标）-> 实际学号
+        private Dictionary<long, int>? _longIdIndex;  // 实际学号 -> 内部学号
+        private List<int> _numbersList;
+        
+        // 黑名单/白名单功能
+        private HashSet<int> _blacklist = new HashSet<int>();
+        private HashSet<int> _whitelist = new HashSet<int>();
+        private bool _whitelistOnlyMode = false;
+        
+        // 学号 -> 显示名称
+        private Dictionary<int, string> _labels = new Dictionary<int, string>();
+        
+        // 分组配额
+        private Dictionary<int, int> _groups = new Dictionary<int, int>();  // 学号 -> 分组ID
+        private Dictionary<int, int> _groupQuotas = new Dictionary<int, int>();  // 分组ID -> 每次会话的抽取上限
+        private Dictionary<int, int> _groupDrawCounts = new Dictionary<int, int>();  // 分组ID -> 本次会话已抽取次数（不保存）
+        
+        // 配置的显示名称和描述
+        private string? _displayName;
+        private string? _description;
+
+        /// <summary>
+        /// 构造函数
+        /// </summary>
+        /// <param name="numberRangeStart">学号起始值</param>
+        /// <param name="numberRangeEnd">学号结束值</param>
+        /// <param name="minPoolSize">最小候选池大小（默认3）</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值（默认5）</param>
+        /// <param name="coldStartBoost">冷启动提升系数（默认2.0）</param>
+        /// <param name="decayFactor">权重衰减因子（默认0.7）</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public BalancedRand(int numberRangeStart, int numberRangeEnd, 
+                           int minPoolSize = 3, int maxGapThreshold = 5,
+                           double coldStartBoost = 2.0, double decayFactor = 0.7,
+                           bool loadData = true)
+        {
+            if (numberRangeStart > numberRangeEnd)
+                throw new ArgumentException("起始值不能大于结束值");
+            
+            if (minPoolSize < 1)
+                throw new ArgumentException("最小候选池大小必须大于0");
+                
+            _allNumbers = Enumerable.Range(numberRangeStart, numberRangeEnd - numberRangeStart + 1).ToList();
+            _allNumbersSet = new HashSet<int>(_allNumbers);
+            _drawCounts = new NumberMap<int>(numberRangeStart, _allNumbers.Count);
+            _lastDrawRound = new NumberMap<int>(numberRangeStart, _allNumbers.Count);
+            foreach (var number in _allNumbers)
+            {
+                _drawCounts[number] = 0;
+                _lastDrawRound[number] = -1; // -1表示从未被抽中
+            }
+            _random = new Random(Guid.NewGuid().GetHashCode());
+            _currentRound = 0;
+            _minPoolSize = minPoolSize;
+            _maxGapThreshold = maxGapThreshold;
+            _coldStartBoost = coldStartBoost;
+            _decayFactor = decayFactor;
+            _totalDraws = 0;
+            _currentProbabilities = new NumberMap<double>(numberRangeStart, _allNumbers.Count);
+            
+            // 保存构造函数参数
+            _numberRangeStart = numberRangeStart;
+            _numberRangeEnd = numberRangeEnd;
+            _type = "BalancedRand_Range";
+            
+            // 生成数据ID
+            _dataId = BalancedRandDataManager.GenerateId(_type, 
+                numberRangeStart, numberRangeEnd, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor);
+            
+            // 初始化候选池
+            UpdateCandidatePool();
+            
+            // 加载历史数据
+            if (loadData)
+            {
+                LoadData();
+            }
+            
+            WarnIfMinPoolSizeTooLarge();
+        }
+
+        /// <summary>
+        /// 构造函数（通过列表指定学号）
+        /// </summary>
+        /// <param name="numbers">学号列表</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public BalancedRand(IEnumerable<int> numbers,
+                           int minPoolSize = 3, int maxGapThreshold = 5,
+                           double coldStartBoost = 2.0, double decayFactor = 0.7,
+                           bool loadData = true)
+            : this(numbers, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, loadData, warnMinPoolSize: true)
+        {
+        }
+
+        /// <summary>
+        /// 通过列表指定学号的构造函数，工厂方法修改类型和数据ID并加载数据后再检查最小候选池大小（warnMinPoolSize 为 false）
+        /// </summary>
+        private BalancedRand(IEnumerable<int> numbers, int minPoolSize, int maxGapThreshold,
+                             double coldStartBoost, double decayFactor, bool loadData, bool warnMinPoolSize)
+        {
+            var enumerable = numbers as int[] ?? numbers.ToArray();
+            if (numbers == null || !enumerable.Any())
+                throw new ArgumentException("学号列表不能为空");
+                
+            _allNumbers = enumerable.Distinct().ToList();
+            _allNumbersSet = new HashSet<int>(_allNumbers);
+            _drawCounts = new NumberMap<int>(_allNumbers.Count);
+            _lastDrawRound = new NumberMap<int>(_allNumbers.Count);
+            foreach (var number in _allNumbers)
+            {
+                _drawCounts[number] = 0;
+                _lastDrawRound[number] = -1;
+            }
+            _random = new Random(Guid.NewGuid().GetHashCode());
+            _currentRound = 0;
+            _minPoolSize = minPoolSize;
+            _maxGapThreshold = maxGapThreshold;
+            _coldStartBoost = coldStartBoost;
+            _decayFactor = decayFactor;
+            _totalDraws = 0;
+            _currentProbabilities = new NumberMap<double>(_allNumbers.Count);
+            
+            // 保存构造函数参数
+            _numbersList = new List<int>(_allNumbers);
+            _type = "BalancedRand_List";
+            
+            // 生成数据ID
+            _dataId = BalancedRandDataManager.GenerateId(_type, 
+                string.Join(",", _allNumbers.OrderBy(n => n).Take(10)), // 取前10个学号作为标识
+                minPoolSize, maxGapThreshold, coldStartBoost, decayFactor);
+            
+            UpdateCandidatePool();
+            
+            // 加载历史数据
+            if (loadData)
+            {
+                LoadData();
+            }
+            
+            if (warnMinPoolSize)
+            {
+                WarnIfMinPoolSizeTooLarge();
+            }
+        }
+
+        /// <summary>
+        /// 从CSV名单创建实例（每行格式为 学号,姓名，姓名列可选）
+        /// 按 RFC 4180 解析：字段可以用双引号包围，引号内可以包含逗号、换行和转义的双引号（""）
+        /// </summary>
+        /// <param name="path">CSV文件路径</param>
+        /// <param name="warnings">被跳过的无效行的说明</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public static BalancedRand FromCsv(string path, out List<string> warnings,
+                                           int minPoolSize = 3, int maxGapThreshold = 5,
+                                           double coldStartBoost = 2.0, double decayFactor = 0.7,
+                                           bool loadData = true)
+        {
+            warnings = new List<string>();
+            var numbers = new List<int>();
+            var seen = new HashSet<int>();
+            var labels = new Dictionary<int, string>();
+            bool isFirstRow = true;
+            
+            foreach (var (line, columns, error) in ParseCsvRecords(File.ReadAllText(path)))
+            {
+                if (error == null && columns.All(string.IsNullOrEmpty))
+                    continue;
+                
+                bool isHeader = isFirstRow;
+                isFirstRow = false;
+                
+                if (error != null)
+                {
+                    warnings.Add($"第{line}行: {error}");
+                    continue;
+                }
+                
+                if (!int.TryParse(columns[0], out var number))
+                {
+                    // 第一行无法解析时视为表头
+                    if (!isHeader)
+                        warnings.Add($"第{line}行: 无效的学号 \"{columns[0]}\"");
+                    continue;
+                }
+                
+                if (!seen.Add(number))
+                {
+                    warnings.Add($"第{line}行: 重复的学号 {number}");
+                    continue;
+                }
+                
+                numbers.Add(number);
+                if (columns.Length > 1 && !string.IsNullOrEmpty(columns[1]))
+                {
+                    labels[number] = columns[1];
+                }
+            }
+            
+            if (!numbers.Any())
+                throw new ArgumentException($"CSV文件中没有有效的学号: {path}");
+            
+            var result = new BalancedRand(numbers, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, loadData,
+                warnMinPoolSize: false);
+            
+            // 名单文件中的姓名优先于已保存的姓名
+            foreach (var kvp in labels)
+            {
+                result._labels[kvp.Key] = kvp.Value;
+            }
+            
+            result.WarnIfMinPoolSizeTooLarge();
+            return result;
+        }
+
+        /// <summary>
+        /// 按 RFC 4180 将CSV文本拆分为记录，字段去除首尾空白
+        /// 引号外的双引号按普通字符处理；文件结束时引号仍未闭合，则最后一条记录带有错误说明
+        /// </summary>
+        /// <returns>每条记录开始的行号（从1开始）、字段和错误说明（正常时为null）</returns>
+        private static IEnumerable<(int Line, List<string> Fields, string? Error)> ParseCsvRecords(string text)
+        {
+            var fields = new List<string>();
+            var field = new StringBuilder();
+            bool inQuotes = false;
+            int line = 1;
+            int recordLine = 1;
+            
+            for (int i = 0; i < text.Length; i++)
+            {
+                char c = text[i];
+                if (inQuotes)
+                {
+                    if (c == '"')
+                    {
+                        if (i + 1 < text.Length && text[i + 1] == '"')
+                        {
+                            field.Append('"');
+                            i++;
+                        }
+                        else
+                        {
+                            inQuotes = false;
+                        }
+                    }
+                    else
+                    {
+                        if (c == '\n')
+                            line++;
+                        field.Append(c);
+                    }
+                    continue;
+                }
+                
+                switch (c)
+                {
+                    case '"' when string.IsNullOrWhiteSpace(field.ToString()):
+                        // 字段开头的引号（允许前面有空白）
+                        field.Clear();
+                        inQuotes = true;
+                        break;
+                    case ',':
+                        fields.Add(field.ToString().Trim());
+                        field.Clear();
+                        break;
+                    case '\r':
+                        break;
+                    case '\n':
+                        fields.Add(field.ToString().Trim());
+                        field.Clear();
+                        yield return (recordLine, fields, null);
+                        fields = new List<string>();
+                        line++;
+                        recordLine = line;
+                        break;
+                    default:
+                        field.Append(c);
+                        break;
+                }
+            }
+            
+            if (inQuotes)
+            {
+                yield return (recordLine, fields, "引号未闭合");
+            }
+            else if (fields.Count > 0 || field.Length > 0)
+            {
+                fields.Add(field.ToString().Trim());
+                yield return (recordLine, fields, null);
+            }
+        }
+
+        /// <summary>
+        /// 创建等差范围的实例（如 10, 20, 30, ...），学号为 start, start+step, ... 直到不超过 end
+        /// </summary>
+        /// <param name="start">起始学号</param>
+        /// <param name="end">结束值（包含在内，如果恰好落在序列上）</param>
+        /// <param name="step">步长，必须大于等于1</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public static BalancedRand FromRangeStep(int start, int end, int step,
+                                                 int minPoolSize = 3, int maxGapThreshold = 5,
+                                                 double coldStartBoost = 2.0, double decayFactor = 0.7,
+                                                 bool loadData = true)
+        {
+            if (step < 1)
+                throw new ArgumentException("步长必须大于等于1");
+            if (start > end)
+                throw new ArgumentException("起始值不能大于结束值");
+            
+            var numbers = new List<int>();
+            for (long n = start; n <= end; n += step)
+            {
+                numbers.Add((int)n);
+            }
+            
+            var result = new BalancedRand(numbers, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, false,
+                warnMinPoolSize: false);
+            result._type = "BalancedRand_RangeStep";
+            result._numberRangeStart = start;
+            result._numberRangeEnd = end;
+            result._step = step;
+            result._dataId = BalancedRandDataManager.GenerateId(result._type,
+                start, end, step, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor);
+            
+            if (loadData)
+            {
+                result.LoadData();
+            }
+            
+            result.WarnIfMinPoolSizeTooLarge();
+            return result;
+        }
+
+        /// <summary>
+        /// 创建使用长学号（超出int范围，如10位学号）的实例
+        /// 内部按列表顺序使用 0, 1, 2, ... 作为学号，抽取、统计和名单操作都使用内部学号，
+        /// 可通过 DrawLongId、GetLongId 和 GetNumberForLongId 与实际学号相互转换。
+        /// 加载数据时按保存的长学号列表转换为当前的内部学号，名单顺序变化不会打乱每个人的抽取记录
+        /// </summary>
+        /// <param name="ids">实际学号列表</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public static BalancedRand FromLongIds(IEnumerable<long> ids,
+                                               int minPoolSize = 3, int maxGapThreshold = 5,
+                                               double coldStartBoost = 2.0, double decayFactor = 0.7,
+                                               bool loadData = true)
+        {
+            var longIds = ids?.Distinct().ToList() ?? new List<long>();
+            if (!longIds.Any())
+                throw new ArgumentException("学号列表不能为空");
+            
+            var result = new BalancedRand(Enumerable.Range(0, longIds.Count), 
+                minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, false, warnMinPoolSize: false);
+            result._type = "BalancedRand_LongList";
+            result._longIds = longIds;
+            result._longIdIndex = longIds
+                .Select((id, index) => (id, index))
+                .ToDictionary(x => x.id, x => x.index);
+            result._dataId = BalancedRandDataManager.GenerateId(result._type,
+                string.Join(",", longIds.OrderBy(n => n).Take(10)), longIds.Count,
+                minPoolSize, maxGapThreshold, coldStartBoost, decayFactor);
+            
+            if (loadData)
+            {
+                result.LoadData();
+            }
+            
+            result.WarnIfMinPoolSizeTooLarge();
+            return result;
+        }
+
+        /// <summary>
+        /// 创建使用连续长学号范围（如 2024000001 到 2024000050）的实例，与按相同顺序传入 FromLongIds 的列表相同
+        /// </summary>
+        /// <param name="start">起始学号</param>
+        /// <param name="end">结束学号（包含在内）</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        /// <exception cref="ArgumentException">起始值大于结束值，或学号数量超过 int.MaxValue</exception>
+        public static BalancedRand FromLongRange(long start, long end,
+                                                 int minPoolSize = 3, int maxGapThreshold = 5,
+                                                 double coldStartBoost = 2.0, double decayFactor = 0.7,
+                                                 bool loadData = true)
+        {
+            if (start > end)
+                throw new ArgumentException("起始值不能大于结束值");
+            
+            // end - start 可能超出long的范围（如 long.MinValue 到 long.MaxValue），按无符号数计算差值
+            ulong span = unchecked((ulong)(end - start));
+            if (span >= int.MaxValue)
+                throw new ArgumentException($"学号数量不能超过{int.MaxValue}个");
+            
+            int count = (int)span + 1;
+            var ids = new List<long>(count);
+            for (int i = 0; i < count; i++)
+            {
+                ids.Add(start + i);
+            }
+            
+            return FromLongIds(ids, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, loadData);
+        }
+
+        /// <summary>
+        /// 抽取一次并返回实际学号（仅用于 FromLongIds 创建的实例）
+        /// </summary>
+        /// <exception cref="InvalidOperationException">白名单中有不在长学号列表中的额外学号（无法转换为实际学号，抽取前检查，不会记录抽取）</exception>
+        public long DrawLongId(bool autoSave = true)
+        {
+            if (_longIds == null)
+                throw new InvalidOperationException("当前实例不是长学号类型");
+            
+            var outOfTable = _whitelist.Where(n => n < 0 || n >= _longIds.Count).OrderBy(n => n).ToList();
+            if (outOfTable.Count > 0)
+                throw new InvalidOperationException($"白名单中的学号没有对应的长学号: {string.Join(", ", outOfTable.Take(10))}");
+            
+            return _longIds[Draw(autoSave)];
+        }
+
+        /// <summary>
+        /// 获取内部学号对应的实际学号（仅用于 FromLongIds 创建的实例）
+        /// </summary>
+        public long GetLongId(int number)
+        {
+            if (_longIds == null)
+                throw new InvalidOperationException("当前实例不是长学号类型");
+            if (number < 0 || number >= _longIds.Count)
+                throw new ArgumentOutOfRangeException(nameof(number), $"学号不存在: {number}");
+            
+            return _longIds[number];
+        }
+
+        /// <summary>
+        /// 获取实际学号对应的内部学号，不存在时返回null（仅用于 FromLongIds 创建的实例）
+        /// </summary>
+        public int? GetNumberForLongId(long id)
+        {
+            if (_longIdIndex == null)
+                throw new InvalidOperationException("当前实例不是长学号类型");
+            
+            return _longIdIndex.TryGetValue(id, out var number) ? number : null;
+        }
+
+        /// <summary>
+        /// 长学号类型：保存的数据按保存时的长学号列表编号，列表顺序变化（如名单重新排序）后按实际学号转换为当前的内部学号
+        /// 不在当前列表中的实际学号的数据会被丢弃；不在保存时列表中的额外学号与当前的内部学号不冲突时保留
+        /// </summary>
+        private BalancedRandData RemapSavedLongIds(BalancedRandData savedData)
+        {
+            if (_longIds == null || _longIdIndex == null || savedData.LongIds == null || savedData.LongIds.Count == 0 ||
+                savedData.LongIds.SequenceEqual(_longIds))
+                return savedData;
+            
+            var savedIds = savedData.LongIds;
+            var longIdIndex = _longIdIndex;
+            int count = _longIds.Count;
+            int? Map(int number)
+            {
+                if (number >= 0 && number < savedIds.Count)
+                    return longIdIndex.TryGetValue(savedIds[number], out var current) ? current : null;
+                return number < 0 || number >= count ? number : null;
+            }
+            
+            Dictionary<int, TValue> MapKeys<TValue>(Dictionary<int, TValue>? source)
+            {
+                var result = new Dictionary<int, TValue>();
+                foreach (var kvp in source ?? new Dictionary<int, TValue>())
+                {
+                    if (Map(kvp.Key) is int number)
+                        result[number] = kvp.Value;
+                }
+                return result;
+            }
+            
+            List<int> MapList(IEnumerable<int>? source) => (source ?? Enumerable.Empty<int>())
+                .Select(Map)
+                .OfType<int>()
+                .ToList();
+            
+            // 先复制一份，不修改传入的数据
+            var data = JsonSerializer.Deserialize<BalancedRandData>(
+                JsonSerializer.Serialize(savedData, BalancedRandDataManager.JsonOptions), BalancedRandDataManager.JsonOptions)!;
+            data.LongIds = new List<long>(_longIds);
+            data.DrawCounts = MapKeys(data.DrawCounts);
+            data.LastDrawRound = MapKeys(data.LastDrawRound);
+            data.CurrentProbabilities = MapKeys(data.CurrentProbabilities);
+            data.Blacklist = MapList(data.Blacklist).ToHashSet();
+            data.Whitelist = MapList(data.Whitelist).ToHashSet();
+            data.Labels = MapKeys(data.Labels);
+            data.Groups = MapKeys(data.Groups);
+            data.RoundRobinQueue = MapList(data.RoundRobinQueue);
+            data.History = (data.History ?? new List<DrawRecord>())
+                .Where(r => Map(r.Number).HasValue)
+                .Select(r => new DrawRecord { Round = r.Round, Number = Map(r.Number)!.Value, Probability = r.Probability, Timestamp = r.Timestamp, Rank = r.Rank })
+                .ToList();
+            if (data.Advanced != null)
+            {
+                data.Advanced.TargetShares = MapKeys(data.Advanced.TargetShares);
+                data.Advanced.LockedWeights = MapKeys(data.Advanced.LockedWeights);
+                data.Advanced.DailyCounts = MapKeys(data.Advanced.DailyCounts);
+                data.Advanced.PreferenceOrder = MapList(data.Advanced.PreferenceOrder);
+                data.Advanced.Tickets = MapKeys(data.Advanced.Tickets);
+                foreach (var snapshot in data.Advanced.ProbabilitySnapshots ?? new List<ProbabilitySnapshot>())
+                {
+                    snapshot.Probabilities = MapKeys(snapshot.Probabilities);
+                }
+            }
+            
+            Debug.WriteLine($"长学号列表顺序已变化，已按实际学号转换保存的数据: {_dataId}");
+            return data;
+        }
+
+        /// <summary>
+        /// 获取等差范围的步长（其他类型为0）
+        /// </summary>
+        public int GetStep() => _step;
+
+        /// <summary>
+        /// 使用指定参数和种子模拟抽取（不读取也不保存数据文件）
+        /// </summary>
+        /// <param name="config">调节参数</param>
+        /// <param name="numbers">学号列表</param>
+        /// <param name="draws">抽取次数</param>
+        /// <param name="seed">随机数种子</param>
+        /// <returns>每个学号的抽取次数及其方差和熵</returns>
+        public static SimulationResult Simulate(BalancedRandConfig config, IEnumerable<int> numbers, int draws, int seed)
+        {
+            if (draws < 0)
+                throw new ArgumentException("抽取次数不能小于0");
+            
+            var rand = new BalancedRand(numbers, config.MinPoolSize, config.MaxGapThreshold,
+                config.ColdStartBoost, config.DecayFactor, false);
+            rand.SetMinWeight(config.MinWeight);
+            rand.SetMinSpacing(config.MinSpacing);
+            rand.SetNoRepeat(config.NoRepeat);
+            rand.SetBalancingEnabled(config.BalancingEnabled);
+            rand.SetWarmupRounds(config.WarmupRounds);
+            rand.SetSeed(seed);
+            
+            double maxProbability = 0;
+            for (int i = 0; i < draws; i++)
+            {
+                rand.DrawSilent();
+                maxProbability = Math.Max(maxProbability, rand._history[^1].Probability);
+            }
+            
+            var counts = rand._allNumbers.ToDictionary(n => n, n => rand._drawCounts[n]);
+            double mean = counts.Values.Average();
+            double variance = counts.Values.Average(c => (c - mean) * (c - mean));
+            
+            double entropy = 0;
+            if (draws > 0)
+            {
+                foreach (var count in counts.Values.Where(c => c > 0))
+                {
+                    double p = (double)count / draws;
+                    entropy -= p * Math.Log2(p);
+                }
+            }
+            
+            return new SimulationResult
+            {
+                Counts = counts,
+                Variance = variance,
+                Entropy = entropy,
+                MaxGap = counts.Values.Max() - counts.Values.Min(),
+                MaxProbability = maxProbability
+            };
+        }
+
+        /// <summary>
+        /// 使用相同的学号、抽取次数和种子分别模拟两组参数，比较哪一组抽取更平均
+        /// </summary>
+        /// <param name="configA">参数A</param>
+        /// <param name="configB">参数B</param>
+        /// <param name="numbers">学号列表</param>
+        /// <param name="draws">抽取次数</param>
+        /// <param name="seed">随机数种子</param>
+        /// <returns>比较结果</returns>
+        public static ComparisonReport CompareConfigs(BalancedRandConfig configA, BalancedRandConfig configB,
+                                                      IEnumerable<int> numbers, int draws, int seed)
+        {
+            var numberList = numbers.ToList();
+            return new ComparisonReport
+            {
+                ResultA = Simulate(configA, numberList, draws, seed),
+                ResultB = Simulate(configB, numberList, draws, seed)
+            };
+        }
+
+        /// <summary>
+        /// 通过模拟搜索权重衰减因子和冷启动提升系数（不修改当前实例）
+        /// 使用当前的学号和其他参数，对每组参数用相同的种子模拟抽取，
+        /// 在满足最大概率限制的参数中选择抽取次数差距最小的（差距相同时选择方差较小的）
+        /// </summary>
+        /// <param name="target">调参目标</param>
+        /// <param name="budget">搜索范围</param>
+        /// <returns>最优参数，可通过 ApplyTuneResult 应用</returns>
+        public TuneResult AutoTune(FairnessTarget target, TuneBudget budget)
+        {
+            if (budget.Steps < 1)
+                throw new ArgumentException("取值个数必须大于0");
+            if (!(budget.DecayFactorMin > 0) || budget.DecayFactorMax > 1.0 || budget.DecayFactorMin > budget.DecayFactorMax)
+                throw new ArgumentException("权重衰减因子的范围必须在(0, 1]之内");
+            if (budget.ColdStartBoostMin < 1.0 || budget.ColdStartBoostMin > budget.ColdStartBoostMax)
+                throw new ArgumentException("冷启动提升系数的范围必须不小于1");
+            
+            var numbers = GetActiveNumbers().ToList();
+            TuneResult? best = null;
+            int evaluated = 0;
+            
+            for (int i = 0; i < budget.Steps; i++)
+            {
+                double decayFactor = Interpolate(budget.DecayFactorMin, budget.DecayFactorMax, i, budget.Steps);
+                for (int j = 0; j < budget.Steps; j++)
+                {
+                    double coldStartBoost = Interpolate(budget.ColdStartBoostMin, budget.ColdStartBoostMax, j, budget.Steps);
+                    
+                    var config = GetConfig();
+                    config.DecayFactor = decayFactor;
+                    config.ColdStartBoost = coldStartBoost;
+                    var simulation = Simulate(config, numbers, target.Draws, budget.Seed);
+                    evaluated++;
+                    
+                    var candidate = new TuneResult
+                    {
+                        DecayFactor = decayFactor,
+                        ColdStartBoost = coldStartBoost,
+                        Simulation = simulation,
+                        MeetsTarget = simulation.MaxProbability <= target.MaxProbability
+                    };
+                    
+                    if (best == null || IsBetterTuneResult(candidate, best))
+                    {
+                        best = candidate;
+                    }
+                }
+            }
+            
+            best!.Evaluated = evaluated;
+            return best;
+        }
+
+        /// <summary>
+        /// 应用自动调参的结果
+        /// </summary>
+        public void ApplyTuneResult(TuneResult result)
+        {
+            UpdateParameters(coldStartBoost: result.ColdStartBoost, decayFactor: result.DecayFactor);
+            UpdateProbabilities();
+        }
+
+        private static double Interpolate(double min, double max, int index, int steps)
+        {
+            return steps == 1 ? min : min + (max - min) * index / (steps - 1);
+        }
+
+        private static bool IsBetterTuneResult(TuneResult candidate, TuneResult best)
+        {
+            if (candidate.MeetsTarget != best.MeetsTarget)
+                return candidate.MeetsTarget;
+            
+            // 都不满足限制时选择最大概率最低的
+            if (!candidate.MeetsTarget && candidate.Simulation.MaxProbability != best.Simulation.MaxProbability)
+                return candidate.Simulation.MaxProbability < best.Simulation.MaxProbability;
+            
+            if (candidate.Simulation.MaxGap != best.Simulation.MaxGap)
+                return candidate.Simulation.MaxGap < best.Simulation.MaxGap;
+            
+            return candidate.Simulation.Variance < best.Simulation.Variance;
+        }
+
+        /// <summary>
+        /// 从文件加载数据
+        /// </summary>
+        public virtual void LoadData(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            try
+            {
+                var allData = BalancedRandDataManager.LoadAllData(filePath, ValidationMode.Skip, out var warnings);
+                foreach (var warning in warnings)
+                {
+                    Debug.WriteLine($"跳过不合法的数据: {warning}");
+                }
+                
+                if (allData.TryGetValue(_dataId, out var savedData))
+                {
+                    ApplySavedData(savedData);
+                    Debug.WriteLine($"已加载数据: {_dataId}");
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"加载数据失败: {ex.Message}");
+            }
+        }
+
+        /// <summary>
+        /// 应用保存的数据
+        /// </summary>
+        protected virtual void ApplySavedData(BalancedRandData savedData)
+        {
+            savedData = RemapSavedLongIds(savedData);
+            _lastSyncedData = savedData;
+            
+            // 只加载当前范围内的数据
+            foreach (var kvp in savedData.DrawCounts)
+            {
+                if (_drawCounts.ContainsKey(kvp.Key))
+                {
+                    _drawCounts[kvp.Key] = kvp.Value;
+                }
+            }
+            
+            foreach (var kvp in savedData.LastDrawRound)
+            {
+                if (_lastDrawRound.ContainsKey(kvp.Key))
+                {
+                    _lastDrawRound[kvp.Key] = kvp.Value;
+                }
+            }
+            
+            _currentRound = savedData.CurrentRound;
+            _totalDraws = savedData.TotalDraws;
+            
+            // 更新配置参数（如果不同）
+            _minPoolSize = savedData.MinPoolSize;
+            _maxGapThreshold = savedData.MaxGapThreshold;
+            _coldStartBoost = savedData.ColdStartBoost;
+            _decayFactor = savedData.DecayFactor;
+            _minWeight = savedData.MinWeight > 0 ? savedData.MinWeight : 0.01;
+            _minSpacing = Math.Max(savedData.MinSpacing, 0);
+            _noRepeat = savedData.NoRepeat;
+            _balancingEnabled = savedData.BalancingEnabled;
+            _warmupRounds = Math.Max(savedData.WarmupRounds, 0);
+            _emptyPoolPolicy = savedData.EmptyPoolPolicy;
+            _roundRobin = savedData.RoundRobin;
+            _roundRobinQueue = new List<int>(savedData.RoundRobinQueue ?? new List<int>());
+            _adaptiveTemperature = savedData.AdaptiveTemperature;
+            _temperatureStart = savedData.TemperatureStart;
+            _temperatureEnd = savedData.TemperatureEnd;
+            _temperatureOverDraws = Math.Max(savedData.TemperatureOverDraws, 0);
+            _temperatureProgress = Math.Max(savedData.TemperatureProgress, 0);
+            
+            // 加载黑名单/白名单（复制集合，保存的数据可能被 DataFileHandle 缓存或由调用方继续使用）
+            _blacklist = new HashSet<int>(savedData.Blacklist ?? new HashSet<int>());
+            _whitelist = new HashSet<int>(savedData.Whitelist ?? new HashSet<int>());
+            InvalidateActiveNumbers();
+            _whitelistOnlyMode = savedData.WhitelistOnlyMode;
+            _labels = new Dictionary<int, string>(savedData.Labels ?? new Dictionary<int, string>());
+            _groups = new Dictionary<int, int>(savedData.Groups ?? new Dictionary<int, int>());
+            _groupQuotas = new Dictionary<int, int>(savedData.GroupQuotas ?? new Dictionary<int, int>());
+            _history = (savedData.History ?? new List<DrawRecord>())
+                .Select(r => new DrawRecord { Round = r.Round, Number = r.Number, Probability = r.Probability, Timestamp = r.Timestamp, Rank = r.Rank })
+                .ToList();
+            _lastResetAt = savedData.LastResetAt;
+            var advanced = savedData.Advanced ?? new AdvancedConfig();
+            _unknownAdvancedConfig = advanced.ExtensionData != null
+                ? new Dictionary<string, JsonElement>(advanced.ExtensionData)
+                : null;
+            _targetShares = (advanced.TargetShares ?? new Dictionary<int, double>())
+                .Where(kvp => kvp.Value > 0 && double.IsFinite(kvp.Value))
+                .ToDictionary(kvp => kvp.Key, kvp => kvp.Value);
+            _targetShareTotal = null;
+            _lockedWeights = (advanced.LockedWeights ?? new Dictionary<int, double>())
+                .Where(kvp => kvp.Value > 0 && double.IsFinite(kvp.Value))
+                .ToDictionary(kvp => kvp.Key, kvp => kvp.Value);
+            _dailyLimit = advanced.DailyLimit > 0 ? advanced.DailyLimit : null;
+            _dailyCounts = (advanced.DailyCounts ?? new Dictionary<int, DailyCount>())
+                .Where(kvp => kvp.Value != null)
+                .ToDictionary(kvp => kvp.Key, kvp => new DailyCount { Date = kvp.Value.Date, Count = kvp.Value.Count });
+            _cyclesCompleted = Math.Max(0, advanced.CyclesCompleted);
+            _preferenceRanks = ToPreferenceRanks(advanced.PreferenceOrder ?? new List<int>());
+            _lotteryMode = advanced.LotteryMode;
+            _lotteryDecay = advanced.LotteryDecay;
+            _tickets = (advanced.Tickets ?? new Dictionary<int, int>())
+                .Where(kvp => kvp.Value > 0)
+                .ToDictionary(kvp => kvp.Key, kvp => kvp.Value);
+            _snapshotEvery = Math.Max(0, advanced.ProbabilitySnapshotEvery);
+            _gapBoost = advanced.GapBoost?.Clone() ?? new GapBoost();
+            _maxSnapshots = Math.Max(1, advanced.MaxProbabilitySnapshots);
+            _persistSnapshots = advanced.PersistProbabilitySnapshots;
+            _snapshots = CopySnapshots(advanced.ProbabilitySnapshots ?? new List<ProbabilitySnapshot>());
+            try
+            {
+                _timeZone = string.IsNullOrEmpty(savedData.TimeZoneId) 
+                    ? TimeZoneInfo.Utc 
+                    : TimeZoneInfo.FindSystemTimeZoneById(savedData.TimeZoneId);
+            }
+            catch (Exception ex) when (ex is TimeZoneNotFoundException or InvalidTimeZoneException)
+            {
+                Debug.WriteLine($"未知的时区 {savedData.TimeZoneId}，使用UTC");
+                _timeZone = TimeZoneInfo.Utc;
+            }
+            _displayName = savedData.DisplayName;
+            _description = savedData.Description;
+            
+            // 恢复随机数序列：重新设置种子并跳过已消耗的随机数
+            if (savedData.Seed.HasValue)
+            {
+                _seed = savedData.Seed;
+                ReplayRandom(savedData.Seed.Value, savedData.RngDrawsConsumed);
+            }
+            
+            // 验证黑名单和白名单的合法性
+            ValidateBlacklist();
+            ValidateWhitelist();
+            
+            // 更新候选池
+            UpdateCandidatePool();
+            
+            // 保存的概率仅供参考，根据加载的抽取记录重新计算
+            UpdateProbabilities();
+            if (savedData.CurrentProbabilities != null)
+            {
+                int mismatched = _currentProbabilities.Count(kvp =>
+                    savedData.CurrentProbabilities.TryGetValue(kvp.Key, out var saved) &&
+                    !(Math.Abs(saved - kvp.Value) <= 1e-9));
+                if (mismatched > 0)
+                {
+                    Debug.WriteLine($"保存的概率与重新计算的结果不一致（{mismatched}个学号），已使用重新计算的概率: {_dataId}");
+                }
+            }
+        }
+
+        /// <summary>
+        /// 保存数据到文件
+        /// </summary>
+        public virtual void SaveData(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            try
+            {
+                var data = ToData();
+                BalancedRandDataManager.SaveEntry(data, filePath);
+                MarkSynced(data);
+                
+                Debug.WriteLine($"已保存数据: {data.Id}");
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+
+        /// <summary>
+        /// 在后台线程保存数据到文件（用于后台自动保存，避免阻塞界面线程）
+        /// 实例本身不是线程安全的：要保存的数据在调用时（调用线程上）生成，之后的抽取不会影响本次保存；
+        /// 文件的读取和写入在后台线程进行，与同一进程中的其他保存操作互斥。
+        /// 与 SaveData 不同，写入失败时返回的任务会以该异常结束，而不是只记录日志
+        /// </summary>
+        public async Task SaveDataAsync(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            var data = ToData();
+            await Task.Run(() => BalancedRandDataManager.SaveEntry(data, filePath));
+            MarkSynced(data);
+            Debug.WriteLine($"已保存数据: {data.Id}");
+        }
+
+        /// <summary>
+        /// 在后台线程读取数据文件，然后在调用线程上应用到当前实例
+        /// 实例本身不是线程安全的，等待完成前不要在其他线程上使用该实例
+        /// </summary>
+        /// <returns>是否找到并加载了保存的数据</returns>
+        public async Task<bool> LoadDataAsync(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            var (allData, warnings) = await Task.Run(() =>
+            {
+                lock (BalancedRandDataManager.FileLock)
+                {
+                    var data = BalancedRandDataManager.LoadAllData(filePath, ValidationMode.Skip, out var skipped);
+                    return (data, skipped);
+                }
+            });
+            foreach (var warning in warnings)
+            {
+                Debug.WriteLine($"跳过不合法的数据: {warning}");
+            }
+            
+            foreach (var id in GetStorageIds())
+            {
+                if (allData.TryGetValue(id, out var savedData))
+                {
+                    ApplySavedData(savedData);
+                    Debug.WriteLine($"已加载数据: {id}");
+                    return true;
+                }
+            }
+            
+            return false;
+        }
+
+        /// <summary>
+        /// 从文件加载数据，与 LoadData 不同，文件无法读取（如已加密或校验失败且没有备份）或包含不合法的数据时抛出异常，
+        /// 而不是只记录日志（用于命令行等需要明确报告错误的场景）
+        /// </summary>
+        /// <returns>是否找到并加载了保存的数据</returns>
+        /// <exception cref="InvalidDataException">数据文件格式错误、校验失败或包含不合法的数据</exception>
+        /// <exception cref="IOException">数据文件无法读取</exception>
+        public bool LoadDataStrict(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            var allData = BalancedRandDataManager.LoadAllData(filePath, ValidationMode.Strict, out _);
+            foreach (var id in GetStorageIds())
+            {
+                if (allData.TryGetValue(id, out var savedData))
+                {
+                    ApplySavedData(savedData);
+                    Debug.WriteLine($"已加载数据: {id}");
+                    return true;
+                }
+            }
+            
+            return false;
+        }
+
+        /// <summary>
+        /// 通过共享的数据文件句柄加载数据（文件未被修改时不会重新解析）
+        /// </summary>
+        public void LoadData(DataFileHandle handle)
+        {
+            EnsureWritable();
+            
+            try
+            {
+                foreach (var id in GetStorageIds())
+                {
+                    if (handle.TryGetEntry(id, out var savedData))
+                    {
+                        ApplySavedData(savedData);
+                        Debug.WriteLine($"已加载数据: {id}");
+                        return;
+                    }
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"加载数据失败: {ex.Message}");
+            }
+        }
+
+        /// <summary>
+        /// 通过共享的数据文件句柄保存数据（文件未被修改时不会重新解析）
+        /// </summary>
+        public void SaveData(DataFileHandle handle)
+        {
+            EnsureWritable();
+            
+            try
+            {
+                var data = ToData();
+                handle.SaveEntry(data);
+                MarkSynced(data);
+                Debug.WriteLine($"已保存数据: {data.Id}");
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存数据失败: {ex.Message}");
+            }
+        }
+
+        /// <summary>
+        /// 加载数据时依次尝试的数据ID
+        /// </summary>
+        protected virtual IEnumerable<string> GetStorageIds()
+        {
+            yield return _dataId;
+        }
+
+        /// <summary>
+        /// 将当前状态转换为可保存的数据
+        /// </summary>
+        public virtual BalancedRandData ToData()
+        {
+            var data = new BalancedRandData
+            {
+                Id = _dataId,
+                LastUpdated = DateTime.Now,
+                DisplayName = _displayName,
+                Description = _description,
+                Namespace = _namespace,
+                DrawCounts = _drawCounts.ToDictionary(),
+                LastDrawRound = _lastDrawRound.ToDictionary(),
+                CurrentRound = _currentRound,
+                TotalDraws = _totalDraws,
+                CurrentProbabilities = _currentProbabilities.ToDictionary(),
+                MinPoolSize = _minPoolSize,
+                MaxGapThreshold = _maxGapThreshold,
+                ColdStartBoost = _coldStartBoost,
+                DecayFactor = _decayFactor,
+                MinWeight = _minWeight,
+                MinSpacing = _minSpacing,
+                NoRepeat = _noRepeat,
+                BalancingEnabled = _balancingEnabled,
+                WarmupRounds = _warmupRounds,
+                EmptyPoolPolicy = _emptyPoolPolicy,
+                RoundRobin = _roundRobin,
+                RoundRobinQueue = new List<int>(_roundRobinQueue),
+                AdaptiveTemperature = _adaptiveTemperature,
+                TemperatureStart = _temperatureStart,
+                TemperatureEnd = _temperatureEnd,
+                TemperatureOverDraws = _temperatureOverDraws,
+                TemperatureProgress = _temperatureProgress,
+                Type = _type,
+                Blacklist = new HashSet<int>(_blacklist),
+                Whitelist = new HashSet<int>(_whitelist),
+                WhitelistOnlyMode = _whitelistOnlyMode,
+                Labels = new Dictionary<int, string>(_labels),
+                Groups = new Dictionary<int, int>(_groups),
+                GroupQuotas = new Dictionary<int, int>(_groupQuotas),
+                Seed = _seed,
+                RngDrawsConsumed = _rngDrawsConsumed,
+                History = new List<DrawRecord>(_history),
+                TimeZoneId = _timeZone.Id,
+                LastResetAt = _lastResetAt,
+                Advanced = new AdvancedConfig
+                {
+                    TargetShares = new Dictionary<int, double>(_targetShares),
+                    LockedWeights = new Dictionary<int, double>(_lockedWeights),
+                    DailyLimit = _dailyLimit,
+                    DailyCounts = _dailyCounts.ToDictionary(
+                        kvp => kvp.Key, 
+                        kvp => new DailyCount { Date = kvp.Value.Date, Count = kvp.Value.Count }),
+                    CyclesCompleted = _cyclesCompleted,
+                    PreferenceOrder = GetPreferenceOrder(),
+                    LotteryMode = _lotteryMode,
+                    LotteryDecay = _lotteryDecay,
+                    Tickets = new Dictionary<int, int>(_tickets),
+                    GapBoost = _gapBoost.Clone(),
+                    ProbabilitySnapshotEvery = _snapshotEvery,
+                    MaxProbabilitySnapshots = _maxSnapshots,
+                    PersistProbabilitySnapshots = _persistSnapshots,
+                    ProbabilitySnapshots = _persistSnapshots ? CopySnapshots(_snapshots) : null,
+                    ExtensionData = _unknownAdvancedConfig != null 
+                        ? new Dictionary<string, JsonElement>(_unknownAdvancedConfig) 
+                        : null
+                }
+            };
+            
+            // 根据类型添加额外参数
+            if (_type == "BalancedRand_Range")
+            {
+                data.NumberRangeStart = _numberRangeStart;
+                data.NumberRangeEnd = _numberRangeEnd;
+            }
+            else if (_type == "BalancedRand_RangeStep")
+            {
+                data.NumberRangeStart = _numberRangeStart;
+                data.NumberRangeEnd = _numberRangeEnd;
+                data.Step = _step;
+            }
+            else if (_type == "BalancedRand_LongList" && _longIds != null)
+            {
+                data.LongIds = new List<long>(_longIds);
+            }
+            else if (_type == "BalancedRand_List" && _numbersList != null)
+            {
+                data.Numbers = new List<int>(_numbersList);
+            }
+            
+            return data;
+        }
+
+        /// <summary>
+        /// 计算自上次保存以来的变化
+        /// </summary>
+        /// <param name="filePath">数据文件路径</param>
+        /// <returns>已保存数据到当前状态的差异</returns>
+        public DataDiff DiffAgainstSaved(string filePath = "balanced_rand_data.json")
+        {
+            var current = ToData();
+            var allData = BalancedRandDataManager.LoadAllData(filePath);
+            
+            if (!allData.TryGetValue(current.Id, out var savedData))
+                throw new KeyNotFoundException($"未找到已保存的数据: {current.Id}");
+            
+            return savedData.Diff(current);
+        }
+
+        #region 只读模式
+
+        /// <summary>
+        /// 设置只读模式（如查看已归档的配置）
+        /// 只读模式下抽取、修改名单或参数、重置、保存、加载（LoadData、LoadDataAsync）、批量模式（BeginBatch、EndBatch）
+        /// 和启用自动重新加载都会抛出 InvalidOperationException，且不会改变任何状态；已启用的自动重新加载会忽略文件的修改。
+        /// 查询、预览和复制不受影响。批量模式中不能切换到只读模式。该设置不会被保存
+        /// </summary>
+        public void SetReadOnly(bool readOnly)
+        {
+            if (readOnly && _batchDrawn != null)
+                throw new InvalidOperationException("批量抽取时不能切换到只读模式");
+            
+            _readOnly = readOnly;
+        }
+
+        /// <summary>
+        /// 是否处于只读模式
+        /// </summary>
+        public bool IsReadOnly() => _readOnly;
+
+        /// <summary>
+        /// 只读模式下抛出异常
+        /// </summary>
+        protected void EnsureWritable()
+        {
+            if (_readOnly)
+                throw new InvalidOperationException($"配置 {_dataId} 处于只读模式，不能修改");
+        }
+
+        #endregion
+
+        #region 自动重新加载
+
+        /// <summary>
+        /// 数据文件中本实例的数据被其他程序修改，并且已经重新加载后触发
+        /// 在调用 EnableAutoReload 的线程的同步上下文中触发；调用时没有同步上下文则在后台线程中触发
+        /// </summary>
+        public event EventHandler? DataReloaded;
+
+        /// <summary>
+        /// 数据文件中本实例的数据被其他程序修改，但本实例也有未保存的修改时触发，此时不会重新加载
+        /// 可以调用 LoadData 使用文件中的数据，或调用 SaveData 用本实例的数据覆盖文件。
+        /// 与 DataReloaded 相同，在调用 EnableAutoReload 时的同步上下文中触发
+        /// </summary>
+        public event EventHandler<DataConflictEventArgs>? ConflictDetected;
+
+        /// <summary>
+        /// 启用自动重新加载：监视数据文件，其他程序修改了本实例的数据时自动重新加载
+        /// 本实例自己保存引起的修改和其他配置的修改会被忽略。
+        /// 文件在后台线程中读取，比较和应用数据则发送到调用本方法时的同步上下文（如界面线程）中进行，不会与该线程上的抽取同时发生；
+        /// 调用时没有同步上下文（如控制台程序）则在后台线程中应用，期间不要同时在其他线程中抽取
+        /// </summary>
+        /// <param name="debounce">文件最后一次变化后等待的时间，避免在其他程序写入过程中读取</param>
+        /// <param name="filePath">数据文件路径</param>
+        public void EnableAutoReload(TimeSpan debounce, string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            if (debounce < TimeSpan.Zero)
+                throw new ArgumentException("等待时间不能小于0");
+            
+            DisableAutoReload();
+            
+            string fullPath = Path.GetFullPath(filePath);
+            _watchedFilePath = fullPath;
+            _reloadDebounce = debounce;
+            _reloadContext = SynchronizationContext.Current;
+            _reloadTimer = new Timer(_ => ReloadIfChanged(), null, Timeout.Infinite, Timeout.Infinite);
+            
+            _watcher = new FileSystemWatcher(Path.GetDirectoryName(fullPath)!, Path.GetFileName(fullPath))
+            {
+                NotifyFilter = NotifyFilters.LastWrite | NotifyFilters.Size | NotifyFilters.FileName
+            };
+            _watcher.Changed += OnDataFileChanged;
+            _watcher.Created += OnDataFileChanged;
+            _watcher.Renamed += OnDataFileChanged;
+            _watcher.EnableRaisingEvents = true;
+        }
+
+        /// <summary>
+        /// 停止自动重新加载
+        /// </summary>
+        public void DisableAutoReload()
+        {
+            _watcher?.Dispose();
+            _watcher = null;
+            _reloadTimer?.Dispose();
+            _reloadTimer = null;
+            _watchedFilePath = null;
+            _reloadContext = null;
+        }
+
+        /// <summary>
+        /// 是否启用了自动重新加载
+        /// </summary>
+        public bool IsAutoReloadEnabled() => _watcher != null;
+
+        /// <summary>
+        /// 记录最近一次保存的数据，用于区分本实例的修改和其他程序的修改
+        /// </summary>
+        protected internal void MarkSynced(BalancedRandData data)
+        {
+            _lastSyncedData = data;
+        }
+
+        private void OnDataFileChanged(object sender, FileSystemEventArgs e)
+        {
+            // 每次变化都重新开始计时，文件停止变化后才读取
+            _reloadTimer?.Change(_reloadDebounce, Timeout.InfiniteTimeSpan);
+        }
+
+        /// <summary>
+        /// 在后台线程中读取数据文件中本实例的数据，再到启用时的同步上下文中比较和应用
+        /// </summary>
+        private void ReloadIfChanged()
+        {
+            string? filePath = _watchedFilePath;
+            if (filePath == null) return;
+            
+            BalancedRandData? external;
+            try
+            {
+                var allData = BalancedRandDataManager.LoadAllData(filePath, ValidationMode.Skip, out _);
+                external = GetStorageIds()
+                    .Select(id => allData.TryGetValue(id, out var data) ? data : null)
+                    .FirstOrDefault(data => data != null);
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"重新加载数据失败: {ex.Message}");
+                return;
+            }
+            if (external == null) return;
+            
+            var context = _reloadContext;
+            if (context != null)
+            {
+                context.Post(_ => ApplyExternalChange(filePath, external), null);
+            }
+            else
+            {
+                ApplyExternalChange(filePath, external);
+            }
+        }
+
+        /// <summary>
+        /// 文件中的数据与最近一次加载或保存的数据不同时重新加载或报告冲突
+        /// </summary>
+        private void ApplyExternalChange(string filePath, BalancedRandData external)
+        {
+            // 等待期间停止了自动重新加载或改为监视其他文件
+            if (_watchedFilePath != filePath) return;
+            
+            // 启用后切换到了只读模式：不修改状态，也不报告冲突
+            if (_readOnly)
+            {
+                Debug.WriteLine($"配置处于只读模式，忽略数据文件的修改: {external.Id}");
+                return;
+            }
+            
+            try
+            {
+                // 本实例保存的数据，或者只有其他配置被修改
+                if (_lastSyncedData != null && _lastSyncedData.Equals(external)) return;
+                
+                var local = ToData();
+                if (local.Equals(external))
+                {
+                    _lastSyncedData = external;
+                    return;
+                }
+                
+                if (_lastSyncedData != null && !local.Equals(_lastSyncedData))
+                {
+                    Debug.WriteLine($"数据文件被修改，但本实例有未保存的修改: {external.Id}");
+                    ConflictDetected?.Invoke(this, new DataConflictEventArgs(local, external));
+                    return;
+                }
+                
+                ApplySavedData(external);
+                Debug.WriteLine($"数据文件被修改，已重新加载: {external.Id}");
+                DataReloaded?.Invoke(this, EventArgs.Empty);
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"重新加载数据失败: {ex.Message}");
+            }
+        }
+
+        #endregion
+
+        #region 复制
+
+        /// <summary>
+        /// 复制成员、配置参数、黑名单/白名单等设置，但不复制抽取记录（如用于练习模式）
+        /// 副本的数据ID加上 suffix 后缀，与原实例互不影响，可以保存在同一个数据文件中
+        /// </summary>
+        /// <param name="suffix">数据ID后缀</param>
+        public BalancedRand CloneFresh(string suffix)
+        {
+            var clone = CloneCore(suffix);
+            clone.ClearDrawState();
+            if (clone._seed.HasValue)
+            {
+                clone.SetSeed(clone._seed);
+            }
+            return clone;
+        }
+
+        /// <summary>
+        /// 完整复制当前实例（包括抽取记录）
+        /// 副本的数据ID加上 suffix 后缀，与原实例互不影响，可以保存在同一个数据文件中
+        /// </summary>
+        /// <param name="suffix">数据ID后缀</param>
+        public BalancedRand CloneWithHistory(string suffix)
+        {
+            return CloneCore(suffix);
+        }
+
+        /// <summary>
+        /// 深复制所有状态并修改数据ID
+        /// </summary>
+        private BalancedRand CloneCore(string suffix)
+        {
+            if (string.IsNullOrEmpty(suffix))
+                throw new ArgumentException("数据ID后缀不能为空");
+            
+            var clone = (BalancedRand)MemberwiseClone();
+            clone._drawCounts = _drawCounts.Clone();
+            clone._lastDrawRound = _lastDrawRound.Clone();
+            clone._currentProbabilities = _currentProbabilities.Clone();
+            clone._allNumbers = new List<int>(_allNumbers);
+            clone._allNumbersSet = new HashSet<int>(_allNumbersSet);
+            clone._candidatePool = _candidatePool != null ? new List<int>(_candidatePool) : null;
+            clone._activeNumbers = null;
+            clone._batchDrawn = null;
+            clone._readOnly = false;
+            clone._lastSyncedData = null;
+            clone._watcher = null;
+            clone._reloadTimer = null;
+            clone._watchedFilePath = null;
+            clone._reloadContext = null;
+            clone._auditLogPath = null;
+            clone.DataReloaded = null;
+            clone.ConflictDetected = null;
+            clone._numbersList = _numbersList != null ? new List<int>(_numbersList) : null!;
+            clone._blacklist = new HashSet<int>(_blacklist);
+            clone._whitelist = new HashSet<int>(_whitelist);
+            clone._labels = new Dictionary<int, string>(_labels);
+            clone._groups = new Dictionary<int, int>(_groups);
+            clone._groupQuotas = new Dictionary<int, int>(_groupQuotas);
+            clone._groupDrawCounts = new Dictionary<int, int>(_groupDrawCounts);
+            clone._history = new List<DrawRecord>(_history);
+            clone._roundRobinQueue = new List<int>(_roundRobinQueue);
+            clone._targetShares = new Dictionary<int, double>(_targetShares);
+            clone._lockedWeights = new Dictionary<int, double>(_lockedWeights);
+            clone._preferenceRanks = new Dictionary<int, int>(_preferenceRanks);
+            clone._tickets = new Dictionary<int, int>(_tickets);
+            clone._snapshots = CopySnapshots(_snapshots);
+            clone._gapBoost = _gapBoost.Clone();
+            clone._dailyCounts = _dailyCounts.ToDictionary(
+                kvp => kvp.Key, 
+                kvp => new DailyCount { Date = kvp.Value.Date, Count = kvp.Value.Count });
+            clone._unknownAdvancedConfig = _unknownAdvancedConfig != null 
+                ? new Dictionary<string, JsonElement>(_unknownAdvancedConfig) 
+                : null;
+            clone._longIds = _longIds != null ? new List<long>(_longIds) : null;
+            clone._longIdIndex = _longIdIndex != null ? new Dictionary<long, int>(_longIdIndex) : null;
+            
+            // Random 无法复制内部状态：有种子时重新设置种子并跳过相同个数的随机数
+            clone._random = new Random(Guid.NewGuid().GetHashCode());
+            clone._rngDrawsConsumed = 0;
+            if (_seed.HasValue)
+            {
+                clone.ReplayRandom(_seed.Value, _rngDrawsConsumed);
+            }
+            
+            clone.ApplyIdSuffix(suffix);
+            return clone;
+        }
+
+        /// <summary>
+        /// 抽取会修改的内存状态，多次抽取中途失败时用于恢复
+        /// 直接复制内存中的状态，而不是经过 ToData/ApplySavedData：不保存的状态（分组抽取次数、未保存的概率快照等）
+        /// 和最近一次同步的数据都会原样恢复
+        /// </summary>
+        private sealed class DrawState
+        {
+            public required NumberMap<int> DrawCounts { get; init; }
+            public required NumberMap<int> LastDrawRound { get; init; }
+            public required NumberMap<double> CurrentProbabilities { get; init; }
+            public required List<int>? CandidatePool { get; init; }
+            public required DateOnly? CandidatePoolDate { get; init; }
+            public required int CurrentRound { get; init; }
+            public required int TotalDraws { get; init; }
+            public required int CyclesCompleted { get; init; }
+            public required DateTime? LastResetAt { get; init; }
+            public required List<DrawRecord> History { get; init; }
+            public required Dictionary<int, int> GroupDrawCounts { get; init; }
+            public required Dictionary<int, DailyCount> DailyCounts { get; init; }
+            public required List<ProbabilitySnapshot> Snapshots { get; init; }
+            public required int TemperatureProgress { get; init; }
+            public required List<int> RoundRobinQueue { get; init; }
+            public required long RngDrawsConsumed { get; init; }
+            public required BalancedRandData? LastSyncedData { get; init; }
+        }
+
+        /// <summary>
+        /// 复制抽取会修改的内存状态
+        /// </summary>
+        private DrawState CaptureDrawState()
+        {
+            return new DrawState
+            {
+                DrawCounts = _drawCounts.Clone(),
+                LastDrawRound = _lastDrawRound.Clone(),
+                CurrentProbabilities = _currentProbabilities.Clone(),
+                CandidatePool = _candidatePool != null ? new List<int>(_candidatePool) : null,
+                CandidatePoolDate = _candidatePoolDate,
+                CurrentRound = _currentRound,
+                TotalDraws = _totalDraws,
+                CyclesCompleted = _cyclesCompleted,
+                LastResetAt = _lastResetAt,
+                History = new List<DrawRecord>(_history),
+                GroupDrawCounts = new Dictionary<int, int>(_groupDrawCounts),
+                DailyCounts = new Dictionary<int, DailyCount>(_dailyCounts),
+                Snapshots = new List<ProbabilitySnapshot>(_snapshots),
+                TemperatureProgress = _temperatureProgress,
+                RoundRobinQueue = new List<int>(_roundRobinQueue),
+                RngDrawsConsumed = _rngDrawsConsumed,
+                LastSyncedData = _lastSyncedData
+            };
+        }
+
+        /// <summary>
+        /// 恢复 CaptureDrawState 复制的状态
+        /// 有种子时重新设置种子并跳过相同个数的随机数，使之后的抽取结果与没有进行失败的抽取时相同
+        /// </summary>
+        private void RestoreDrawState(DrawState state)
+        {
+            _drawCounts = state.DrawCounts;
+            _lastDrawRound = state.LastDrawRound;
+            _currentProbabilities = state.CurrentProbabilities;
+            _candidatePool = state.CandidatePool;
+            _candidatePoolDate = state.CandidatePoolDate;
+            _currentRound = state.CurrentRound;
+            _totalDraws = state.TotalDraws;
+            _cyclesCompleted = state.CyclesCompleted;
+            _lastResetAt = state.LastResetAt;
+            _history = state.History;
+            _groupDrawCounts = state.GroupDrawCounts;
+            _dailyCounts = state.DailyCounts;
+            _snapshots = state.Snapshots;
+            _temperatureProgress = state.TemperatureProgress;
+            _roundRobinQueue = state.RoundRobinQueue;
+            _lastSyncedData = state.LastSyncedData;
+            InvalidateActiveNumbers();
+            
+            if (_seed.HasValue)
+            {
+                ReplayRandom(_seed.Value, state.RngDrawsConsumed);
+            }
+            else
+            {
+                _rngDrawsConsumed = state.RngDrawsConsumed;
+            }
+        }
+
+        /// <summary>
+        /// 为复制出的实例的数据ID添加后缀
+        /// </summary>
+        protected virtual void ApplyIdSuffix(string suffix)
+        {
+            _dataId += suffix;
+        }
+
+        /// <summary>
+        /// 将数据ID从旧的命名空间移动到新的命名空间
+        /// </summary>
+        protected virtual void ApplyNamespace(string? oldNamespace, string? newNamespace)
+        {
+            _dataId = BalancedRandDataManager.QualifyId(newNamespace, BalancedRandDataManager.UnqualifyId(oldNamespace, _dataId));
+        }
+
+        /// <summary>
+        /// 清空所有抽取记录
+        /// </summary>
+        private void ClearDrawState()
+        {
+            _drawCounts.Clear();
+            _lastDrawRound.Clear();
+            foreach (var number in _allNumbers)
+            {
+                _drawCounts[number] = 0;
+                _lastDrawRound[number] = -1;
+            }
+            _currentProbabilities.Clear();
+            _currentRound = 0;
+            _totalDraws = 0;
+            _history.Clear();
+            _groupDrawCounts.Clear();
+            _dailyCounts.Clear();
+            _cyclesCompleted = 0;
+            _snapshots.Clear();
+            _temperatureProgress = 0;
+            _roundRobinQueue.Clear();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 按映射重新编号所有学号，并保留每个人的抽取记录（如新学年学号由1–30变为101–130）
+        /// 抽取次数、最后抽中轮次、黑名单/白名单、锁定的权重、显示名称、分组、目标比例、彩票、偏好顺序、抽取历史和概率快照都会改用新学号；
+        /// 黑名单中不在映射里的学号会被移除。重新编号后类型变为学号列表，数据ID默认按新的学号列表生成，
+        /// 原ID下保存的数据不会被删除。不支持2D抽取和长学号类型，批量模式下不能使用
+        /// </summary>
+        /// <param name="mapping">旧学号 -> 新学号，必须包含所有学号（包括白名单中的额外学号），新学号不能重复</param>
+        /// <param name="newDataId">新的数据ID（不含命名空间），为null时自动生成</param>
+        public void RemapNumbers(Dictionary<int, int> mapping, string? newDataId = null)
+        {
+            EnsureWritable();
+            
+            if (this is BalancedRandPlane || _type == "BalancedRand_LongList")
+                throw new InvalidOperationException("2D抽取和长学号类型不支持重新编号");
+            if (_batchDrawn != null)
+                throw new InvalidOperationException("批量抽取时不能重新编号");
+            
+            var missing = GetActiveNumbers().Where(n => !mapping.ContainsKey(n)).ToList();
+            if (missing.Count > 0)
+                throw new ArgumentException($"映射中缺少学号: {string.Join(", ", missing.Take(10))}");
+            
+            var collisions = mapping.GroupBy(kvp => kvp.Value).Where(g => g.Count() > 1).Select(g => g.Key).ToList();
+            if (collisions.Count > 0)
+                throw new ArgumentException($"多个学号映射到同一个新学号: {string.Join(", ", collisions.Take(10))}");
+            
+            // 只保留映射中存在的学号
+            Dictionary<int, TValue> MapKeys<TValue>(IEnumerable<KeyValuePair<int, TValue>> source) => source
+                .Where(kvp => mapping.ContainsKey(kvp.Key))
+                .ToDictionary(kvp => mapping[kvp.Key], kvp => kvp.Value);
+            
+            var drawCounts = new NumberMap<int>(_drawCounts.Count);
+            foreach (var kvp in MapKeys(_drawCounts))
+                drawCounts[kvp.Key] = kvp.Value;
+            var lastDrawRound = new NumberMap<int>(_lastDrawRound.Count);
+            foreach (var kvp in MapKeys(_lastDrawRound))
+                lastDrawRound[kvp.Key] = kvp.Value;
+            
+            _allNumbers = _allNumbers.Select(n => mapping[n]).ToList();
+            _allNumbersSet = new HashSet<int>(_allNumbers);
+            _numbersList = new List<int>(_allNumbers);
+            _drawCounts = drawCounts;
+            _lastDrawRound = lastDrawRound;
+            _currentProbabilities = new NumberMap<double>(_allNumbers.Count);
+            _blacklist = _blacklist.Where(mapping.ContainsKey).Select(n => mapping[n]).ToHashSet();
+            _whitelist = _whitelist.Select(n => mapping[n]).ToHashSet();
+            _lockedWeights = MapKeys(_lockedWeights);
+            _labels = MapKeys(_labels);
+            _groups = MapKeys(_groups);
+            _targetShares = MapKeys(_targetShares);
+            _tickets = MapKeys(_tickets);
+            _dailyCounts = MapKeys(_dailyCounts);
+            _preferenceRanks = MapKeys(_preferenceRanks);
+            _roundRobinQueue = _roundRobinQueue.Where(mapping.ContainsKey).Select(n => mapping[n]).ToList();
+            _history = _history
+                .Where(r => mapping.ContainsKey(r.Number))
+                .Select(r => new DrawRecord { Round = r.Round, Number = mapping[r.Number], Probability = r.Probability, Timestamp = r.Timestamp, Rank = r.Rank })
+                .ToList();
+            _snapshots = _snapshots
+                .Select(snapshot => new ProbabilitySnapshot
+                {
+                    Round = snapshot.Round,
+                    Probabilities = MapKeys(snapshot.Probabilities),
+                    MaxGap = snapshot.MaxGap
+                })
+                .ToList();
+            
+            _type = "BalancedRand_List";
+            string id = newDataId ?? BalancedRandDataManager.GenerateId(_type, 
+                string.Join(",", _allNumbers.OrderBy(n => n).Take(10)),
+                _minPoolSize, _maxGapThreshold, _coldStartBoost, _decayFactor);
+            _dataId = BalancedRandDataManager.QualifyId(_namespace, id);
+            
+            InvalidateActiveNumbers();
+            UpdateCandidatePool();
+            UpdateProbabilities();
+            Debug.WriteLine($"已重新编号{mapping.Count}个学号，新的数据ID: {_dataId}");
+        }
+
+        /// <summary>
+        /// 将另一个实例的抽取次数合并到当前实例（如代课老师使用了另一个实例抽取）
+        /// 抽取次数按 strategy 合并，最后抽中轮次、当前轮次和每日抽取记录取较晚的一方；
+        /// 只合并当前实例中存在的学号，其余学号会被跳过。抽取历史不会合并
+        /// </summary>
+        /// <param name="other">要合并的实例（不会被修改）</param>
+        /// <param name="strategy">抽取次数的合并方式</param>
+        /// <returns>被跳过的学号（当前实例中不存在）</returns>
+        public List<int> MergeCountsFrom(BalancedRand other, MergeStrategy strategy)
+        {
+            EnsureWritable();
+            
+            if (ReferenceEquals(other, this))
+                throw new ArgumentException("不能与自身合并");
+            if (_batchDrawn != null)
+                throw new InvalidOperationException("批量抽取时不能合并抽取次数");
+            
+            var active = GetActiveNumbers().ToHashSet();
+            var skipped = new List<int>();
+            
+            foreach (var number in other.GetActiveNumbers())
+            {
+                if (!active.Contains(number))
+                {
+                    skipped.Add(number);
+                    continue;
+                }
+                
+                int current = _drawCounts.TryGetValue(number, out var c) ? c : 0;
+                int theirs = other._drawCounts.TryGetValue(number, out var t) ? t : 0;
+                int merged = strategy == MergeStrategy.Sum ? current + theirs : Math.Max(current, theirs);
+                _drawCounts[number] = merged;
+                _totalDraws += merged - current;
+                
+                int currentLast = _lastDrawRound.TryGetValue(number, out var r) ? r : -1;
+                int theirLast = other._lastDrawRound.TryGetValue(number, out var tr) ? tr : -1;
+                _lastDrawRound[number] = Math.Max(currentLast, theirLast);
+                
+                if (other._dailyCounts.TryGetValue(number, out var theirDaily))
+                {
+                    if (!_dailyCounts.TryGetValue(number, out var daily) || daily.Date < theirDaily.Date)
+                    {
+                        _dailyCounts[number] = new DailyCount { Date = theirDaily.Date, Count = theirDaily.Count };
+                    }
+                    else if (daily.Date == theirDaily.Date)
+                    {
+                        daily.Count = strategy == MergeStrategy.Sum ? daily.Count + theirDaily.Count : Math.Max(daily.Count, theirDaily.Count);
+                    }
+                }
+            }
+            
+            _currentRound = Math.Max(_currentRound, other._currentRound);
+            
+            if (skipped.Count > 0)
+            {
+                Debug.WriteLine($"合并时跳过了当前实例中不存在的学号: {string.Join(", ", skipped.Take(10))}");
+            }
+            
+            UpdateCandidatePool();
+            UpdateProbabilities();
+            return skipped;
+        }
+
+        #endregion
+
+        #region 黑名单/白名单功能
+
+        /// <summary>
+        /// 设置黑名单（禁止抽取的学号）
+        /// </summary>
+        /// <param name="numbers">要加入黑名单的学号</param>
+        public void SetBlacklist(IEnumerable<int> numbers)
+        {
+            SetBlacklistDiff(numbers);
+        }
+
+        /// <summary>
+        /// 设置黑名单并返回变化情况
+        /// </summary>
+        /// <param name="numbers">要加入黑名单的学号</param>
+        /// <returns>新增、移除以及因不在学号范围内而被拒绝的学号</returns>
+        public ListDiff SetBlacklistDiff(IEnumerable<int> numbers)
+        {
+            EnsureWritable();
+            
+            var previous = new HashSet<int>(_blacklist);
+            var rejected = new HashSet<int>();
+            
+            _blacklist.Clear();
+            foreach (var number in numbers)
+            {
+                if (_allNumbersSet.Contains(number))
+                {
+                    _blacklist.Add(number);
+                }
+                else
+                {
+                    rejected.Add(number);
+                }
+            }
+            ValidateBlacklist();
+            UpdateCandidatePool();
+            WarnIfMinPoolSizeTooLarge();
+            
+            return ListDiff.Create(previous, _blacklist, rejected);
+        }
+
+        /// <summary>
+        /// 添加学号到黑名单
+        /// </summary>
+        /// <param name="numbers">要添加到黑名单的学号</param>
+        public void AddToBlacklist(params int[] numbers)
+        {
+            EnsureWritable();
+            
+            foreach (var number in numbers)
+            {
+                if (_allNumbersSet.Contains(number) && !_blacklist.Contains(number))
+                {
+                    _blacklist.Add(number);
+                }
+            }
+            ValidateBlacklist();
+            UpdateCandidatePool();
+            WarnIfMinPoolSizeTooLarge();
+        }
+
+        /// <summary>
+        /// 从黑名单中移除学号
+        /// </summary>
+        /// <param name="numbers">要从黑名单中移除的学号</param>
+        public void RemoveFromBlacklist(params int[] numbers)
+        {
+            EnsureWritable();
+            
+            foreach (var number in numbers)
+            {
+                _blacklist.Remove(number);
+            }
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 清除所有黑名单
+        /// </summary>
+        public void ClearBlacklist()
+        {
+            EnsureWritable();
+            
+            _blacklist.Clear();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 获取当前黑名单
+        /// </summary>
+        /// <returns>黑名单学号列表</returns>
+        public List<int> GetBlacklist()
+        {
+            return _blacklist.ToList();
+        }
+
+        /// <summary>
+        /// 检查学号是否在黑名单中
+        /// </summary>
+        public bool IsInBlacklist(int number)
+        {
+            return _blacklist.Contains(number);
+        }
+
+        /// <summary>
+        /// 设置白名单（额外可抽取的学号，可以超出原始范围）
+        /// 超出原始范围的白名单学号在从未被抽中时享受冷启动提升，之后与普通成员同等对待
+        /// </summary>
+        /// <param name="numbers">要加入白名单的学号</param>
+        public void SetWhitelist(IEnumerable<int> numbers)
+        {
+            SetWhitelistDiff(numbers);
+        }
+
+        /// <summary>
+        /// 设置白名单并返回变化情况
+        /// </summary>
+        /// <param name="numbers">要加入白名单的学号</param>
+        /// <returns>新增和移除的学号（白名单不会拒绝任何学号）</returns>
+        public ListDiff SetWhitelistDiff(IEnumerable<int> numbers)
+        {
+            EnsureWritable();
+            
+            var previous = new HashSet<int>(_whitelist);
+            
+            _whitelist.Clear();
+            foreach (var number in numbers)
+            {
+                _whitelist.Add(number);
+            }
+            ValidateWhitelist();
+            RemoveOrphanedRecords();
+            InvalidateActiveNumbers();
+            UpdateCandidatePool();
+            
+            return ListDiff.Create(previous, _whitelist, new HashSet<int>());
+        }
+
+        /// <summary>
+        /// 添加学号到白名单
+        /// </summary>
+        /// <param name="numbers">要添加到白名单的学号</param>
+        public void AddToWhitelist(params int[] numbers)
+        {
+            EnsureWritable();
+            
+            foreach (var number in numbers)
+            {
+                if (!_whitelist.Contains(number))
+                {
+                    _whitelist.Add(number);
+                }
+            }
+            ValidateWhitelist();
+            InvalidateActiveNumbers();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 从白名单中移除学号
+        /// </summary>
+        /// <param name="numbers">要从白名单中移除的学号</param>
+        public void RemoveFromWhitelist(params int[] numbers)
+        {
+            EnsureWritable();
+            
+            foreach (var number in numbers)
+            {
+                _whitelist.Remove(number);
+            }
+            RemoveOrphanedRecords();
+            InvalidateActiveNumbers();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 清除所有白名单
+        /// </summary>
+        public void ClearWhitelist()
+        {
+            EnsureWritable();
+            
+            _whitelist.Clear();
+            RemoveOrphanedRecords();
+            InvalidateActiveNumbers();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 获取当前白名单
+        /// </summary>
+        /// <returns>白名单学号列表</returns>
+        public List<int> GetWhitelist()
+        {
+            return _whitelist.ToList();
+        }
+
+        /// <summary>
+        /// 检查学号是否在白名单中
+        /// </summary>
+        public bool IsInWhitelist(int number)
+        {
+            return _whitelist.Contains(number);
+        }
+
+        /// <summary>
+        /// 设置白名单模式
+        /// 白名单为空时也可以开启（如先开启再添加学号），但在添加白名单学号前 Draw 会抛出 WhitelistOnlyButEmptyException
+        /// </summary>
+        /// <param name="whitelistOnly">true: 只从白名单中抽取; false: 正常模式，白名单作为额外候选</param>
+        public void SetWhitelistOnlyMode(bool whitelistOnly)
+        {
+            EnsureWritable();
+            
+            _whitelistOnlyMode = whitelistOnly;
+            if (whitelistOnly && _whitelist.Count == 0)
+            {
+                Debug.WriteLine($"开启了白名单模式但白名单为空，添加白名单学号前无法抽取: {_dataId}");
+            }
+            UpdateCandidatePool();
+            WarnIfMinPoolSizeTooLarge();
+        }
+
+        /// <summary>
+        /// 获取当前是否处于白名单模式
+        /// </summary>
+        public bool GetWhitelistOnlyMode()
+        {
+            return _whitelistOnlyMode;
+        }
+
+        /// <summary>
+        /// 验证黑名单的合法性
+        /// </summary>
+        private void ValidateBlacklist()
+        {
+            // 移除不在_allNumbers中的黑名单项
+            _blacklist.RemoveWhere(number => !_allNumbersSet.Contains(number));
+        }
+
+        /// <summary>
+        /// 验证白名单的合法性
+        /// </summary>
+        private void ValidateWhitelist()
+        {
+            // 白名单不需要验证，可以包含不在_allNumbers中的学号
+        }
+
+        #endregion
+
+        #region 显示名称
+
+        /// <summary>
+        /// 设置学号的显示名称
+        /// </summary>
+        /// <param name="number">学号</param>
+        /// <param name="label">显示名称，为null时移除</param>
+        public void SetLabel(int number, string? label)
+        {
+            EnsureWritable();
+            
+            if (label == null)
+            {
+                _labels.Remove(number);
+            }
+            else
+            {
+                _labels[number] = label;
+            }
+        }
+
+        /// <summary>
+        /// 获取学号的显示名称，没有设置时返回null
+        /// </summary>
+        public string? GetLabel(int number)
+        {
+            return _labels.TryGetValue(number, out var label) ? label : null;
+        }
+
+        /// <summary>
+        /// 获取所有显示名称
+        /// </summary>
+        public Dictionary<int, string> GetLabels()
+        {
+            return new Dictionary<int, string>(_labels);
+        }
+
+        /// <summary>
+        /// 设置配置的显示名称（如"三班数学"）
+        /// </summary>
+        public void SetDisplayName(string? displayName)
+        {
+            EnsureWritable();
+            
+            _displayName = displayName;
+        }
+
+        /// <summary>
+        /// 获取配置的显示名称
+        /// </summary>
+        public string? GetDisplayName() => _displayName;
+
+        /// <summary>
+        /// 设置配置的描述
+        /// </summary>
+        public void SetDescription(string? description)
+        {
+            EnsureWritable();
+            
+            _description = description;
+        }
+
+        /// <summary>
+        /// 获取配置的描述
+        /// </summary>
+        public string? GetDescription() => _description;
+
+        /// <summary>
+        /// 设置命名空间（如按班级区分），保存时的数据ID变为 命名空间/ID，为null时回到默认命名空间
+        /// 只改变数据ID，不会加载新ID下已保存的数据，需要时可再调用 LoadData
+        /// </summary>
+        /// <param name="ns">命名空间，不能为空字符串或包含 /</param>
+        public void SetNamespace(string? ns)
+        {
+            EnsureWritable();
+            
+            if (ns != null && (ns.Length == 0 || ns.Contains('/')))
+                throw new ArgumentException("命名空间不能为空字符串或包含 /");
+            
+            ApplyNamespace(_namespace, ns);
+            _namespace = ns;
+        }
+
+        /// <summary>
+        /// 获取命名空间，默认命名空间时返回null
+        /// </summary>
+        public string? GetNamespace() => _namespace;
+
+        #endregion
+
+        #region 分组配额
+
+        /// <summary>
+        /// 设置学号所属的分组
+        /// </summary>
+        /// <param name="number">学号</param>
+        /// <param name="groupId">分组ID，为null时移出分组</param>
+        public void SetGroup(int number, int? groupId)
+        {
+            EnsureWritable();
+            
+            if (groupId.HasValue)
+            {
+                _groups[number] = groupId.Value;
+            }
+            else
+            {
+                _groups.Remove(number);
+            }
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 获取学号所属的分组，未分组时返回null
+        /// </summary>
+        public int? GetGroup(int number)
+        {
+            return _groups.TryGetValue(number, out var groupId) ? groupId : null;
+        }
+
+        /// <summary>
+        /// 获取所有学号的分组
+        /// </summary>
+        public Dictionary<int, int> GetGroups()
+        {
+            return new Dictionary<int, int>(_groups);
+        }
+
+        /// <summary>
+        /// 设置分组在本次会话中的抽取上限
+        /// 分组被抽中的次数达到上限后，其成员不会再进入候选池（候选池不足时也不会被补充进来），
+        /// 直到调用 ResetQuotas
+        /// </summary>
+        /// <param name="groupId">分组ID</param>
+        /// <param name="max">抽取上限，为null时取消限制</param>
+        public void SetGroupQuota(int groupId, int? max)
+        {
+            EnsureWritable();
+            
+            if (max.HasValue)
+            {
+                if (max.Value < 0)
+                    throw new ArgumentException("分组抽取上限不能小于0");
+                _groupQuotas[groupId] = max.Value;
+            }
+            else
+            {
+                _groupQuotas.Remove(groupId);
+            }
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 获取所有分组的抽取上限
+        /// </summary>
+        public Dictionary<int, int> GetGroupQuotas()
+        {
+            return new Dictionary<int, int>(_groupQuotas);
+        }
+
+        /// <summary>
+        /// 获取本次会话中每个分组被抽中的次数
+        /// </summary>
+        public Dictionary<int, int> GetGroupDrawCounts()
+        {
+            return new Dictionary<int, int>(_groupDrawCounts);
+        }
+
+        /// <summary>
+        /// 清空本次会话的分组抽取次数，开始新的会话
+        /// </summary>
+        public void ResetQuotas()
+        {
+            EnsureWritable();
+            
+            _groupDrawCounts.Clear();
+            UpdateCandidatePool();
+        }
+
+        #endregion
+
+        #region 集合接口
+
+        /// <summary>
+        /// 活跃学号数量（原始学号加上白名单中的额外学号）
+        /// </summary>
+        public int Count => GetActiveNumbers().Count;
+
+        /// <summary>
+        /// 是否没有任何活跃学号
+        /// </summary>
+        public bool IsEmpty => Count == 0;
+
+        /// <summary>
+        /// 按学号顺序枚举所有活跃学号
+        /// </summary>
+        public IEnumerator<int> GetEnumerator()
+        {
+            return GetActiveNumbers().GetEnumerator();
+        }
+
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        #endregion
+
+        /// <summary>
+        /// 获取数据ID
+        /// </summary>
+        public string GetDataId() => _dataId;
+
+        /// <summary>
+        /// 获取类型
+        /// </summary>
+        public string GetTypeName() => _type;
+
+        /// <summary>
+        /// 获取最后抽取轮次列表（按学号顺序）
+        /// </summary>
+        public List<int> GetLastDrawRounds()
+        {
+            return _allNumbers.OrderBy(n => n).Select(n => _lastDrawRound[n]).ToList();
+        }
+
+        /// <summary>
+        /// 获取当前轮次
+        /// </summary>
+        public int GetCurrentRound() => _currentRound;
+
+        /// <summary>
+        /// 获取总抽取次数
+        /// </summary>
+        public int GetTotalDraws() => _totalDraws;
+
+        /// <summary>
+        /// 获取完成的抽取周期数：候选池耗尽后自动重置抽取次数的次数，即"所有人都被抽过一遍"的次数
+        /// 手动调用 ResetDrawCounts 不计入；ResetSession 会将其清零
+        /// </summary>
+        public int GetCyclesCompleted() => _cyclesCompleted;
+
+        /// <summary>
+        /// 获取最小候选池大小
+        /// </summary>
+        public int GetMinPoolSize() => _minPoolSize;
+
+        /// <summary>
+        /// 获取实际使用的最小候选池大小
+        /// 可抽取的学号（不在黑名单中；白名单模式下为白名单中的学号）少于设置的最小候选池大小时，
+        /// 按可抽取的学号数计算，此时补充候选池会加入所有可抽取的学号
+        /// </summary>
+        public int GetEffectiveMinPoolSize() => Math.Min(_minPoolSize, GetDrawableCount());
+
+        /// <summary>
+        /// 获取最大差距阈值
+        /// </summary>
+        public int GetMaxGapThreshold() => _maxGapThreshold;
+
+        /// <summary>
+        /// 获取冷启动提升系数
+        /// </summary>
+        public double GetColdStartBoost() => _coldStartBoost;
+
+        /// <summary>
+        /// 获取衰减因子
+        /// </summary>
+        public double GetDecayFactor() => _decayFactor;
+
+        /// <summary>
+        /// 获取最小权重
+        /// </summary>
+        public double GetMinWeight() => _minWeight;
+
+        /// <summary>
+        /// 获取当前的调节参数
+        /// </summary>
+        public BalancedRandConfig GetConfig()
+        {
+            return new BalancedRandConfig
+            {
+                MinPoolSize = _minPoolSize,
+                MaxGapThreshold = _maxGapThreshold,
+                ColdStartBoost = _coldStartBoost,
+                DecayFactor = _decayFactor,
+                MinWeight = _minWeight,
+                MinSpacing = _minSpacing,
+                NoRepeat = _noRepeat,
+                BalancingEnabled = _balancingEnabled,
+                WarmupRounds = _warmupRounds
+            };
+        }
+
+        /// <summary>
+        /// 获取最小间隔
+        /// </summary>
+        public int GetMinSpacing() => _minSpacing;
+
+        /// <summary>
+        /// 抽取一个学号
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>抽取到的学号</returns>
+        public int Draw(bool autoSave = true)
+        {
+            return DrawCore(autoSave, null);
+        }
+
+        /// <summary>
+        /// 抽取一个学号，只更新抽取状态，没有任何副作用
+        /// 无论实例如何设置都不会保存数据或读写文件，也不会输出日志，用于模拟等批量计算
+        /// </summary>
+        /// <returns>抽取到的学号</returns>
+        public int DrawSilent()
+        {
+            _silentDraw = true;
+            try
+            {
+                return DrawCore(false, null);
+            }
+            finally
+            {
+                _silentDraw = false;
+            }
+        }
+
+        /// <summary>
+        /// 尝试抽取一次，候选池为空时返回false（不会重置抽取次数，也不会抛出异常）
+        /// 可用于“抽到没有可抽的学号为止”的循环
+        /// </summary>
+        /// <param name="number">抽到的学号</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>是否抽到了学号</returns>
+        public bool TryDraw(out int number, bool autoSave = true)
+        {
+            EnsureWritable();
+            
+            if (_candidatePool == null || _candidatePool.Count == 0)
+            {
+                number = 0;
+                return false;
+            }
+            
+            number = DrawCore(autoSave, null);
+            return true;
+        }
+
+        /// <summary>
+        /// 依次抽取的序列（用于“持续抽取”的界面，可与 Take、Where 等组合）
+        /// 每次取下一个元素时才调用 Draw，因此枚举会修改当前实例（抽取次数、历史等），并且不能重复枚举得到相同的结果。
+        /// 按默认的候选池为空处理方式（重置）时序列不会结束，需要用 Take 等限制数量；
+        /// 设置为 EmptyPoolPolicy.Error、达到每日上限或仅白名单模式下白名单为空时，Draw 抛出的异常会在取下一个元素时抛出
+        /// </summary>
+        /// <param name="autoSave">每次抽取后是否自动保存数据（默认true）</param>
+        public IEnumerable<int> Draws(bool autoSave = true)
+        {
+            while (true)
+            {
+                yield return Draw(autoSave);
+            }
+        }
+
+        /// <summary>
+        /// 设置候选池为空时 Draw 的处理方式（默认重置所有抽取次数）
+        /// </summary>
+        public void SetEmptyPoolPolicy(EmptyPoolPolicy policy)
+        {
+            EnsureWritable();
+            
+            _emptyPoolPolicy = policy;
+        }
+
+        /// <summary>
+        /// 获取候选池为空时 Draw 的处理方式
+        /// </summary>
+        public EmptyPoolPolicy GetEmptyPoolPolicy() => _emptyPoolPolicy;
+
+        /// <summary>
+        /// 抽取一次，并返回抽取前的候选池、权重和概率
+        /// 与先调用 Draw 再查询概率不同，返回的是做出选择时的状态，而不是抽取后的状态
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>抽取结果</returns>
+        public DrawOutcome DrawVerbose(bool autoSave = true)
+        {
+            var outcome = new DrawOutcome();
+            DrawCore(autoSave, outcome);
+            return outcome;
+        }
+
+        /// <summary>
+        /// 抽取一次，返回可直接序列化的结果（学号、2D位置、概率、轮次和时间）
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        public DrawResult DrawStructured(bool autoSave = true)
+        {
+            DrawCore(autoSave, null);
+            
+            var record = _history[^1];
+            var position = GetPositionOf(record.Number);
+            return new DrawResult
+            {
+                Number = record.Number,
+                Position = position.HasValue ? new[] { position.Value.row, position.Value.col } : null,
+                Probability = record.Probability,
+                Round = record.Round,
+                Timestamp = DateTime.SpecifyKind(record.Timestamp, DateTimeKind.Utc)
+            };
+        }
+
+        /// <summary>
+        /// 抽取一次，返回 DrawStructured 的结果序列化后的JSON（单行）
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        public string DrawJson(bool autoSave = true) => DrawStructured(autoSave).ToJson();
+
+        /// <summary>
+        /// 学号对应的2D位置（行列从1开始），不是2D抽取时返回null
+        /// </summary>
+        protected virtual (int row, int col)? GetPositionOf(int number) => null;
+
+        /// <summary>
+        /// 2D抽取的行数和列数，不是2D抽取时返回null
+        /// </summary>
+        protected virtual (int rows, int cols)? GetGridSize() => null;
+
+        /// <summary>
+        /// 一次获取界面需要的完整状态（学号列表及每个学号的抽取次数、概率和最后抽中轮次，黑名单、白名单、
+        /// 构造参数、当前轮次、总抽取次数和候选池），2D抽取时还包括行列数和每个学号的位置
+        /// </summary>
+        public StateSnapshot GetState()
+        {
+            int[]? ToPair((int row, int col)? position) => position.HasValue ? new[] { position.Value.row, position.Value.col } : null;
+            
+            var blacklist = GetBlacklist().OrderBy(n => n).ToList();
+            var whitelist = GetWhitelist().OrderBy(n => n).ToList();
+            var grid = GetGridSize();
+            
+            return new StateSnapshot
+            {
+                Members = GetFullStatistics()
+                    .Select(s => new MemberState
+                    {
+                        Number = s.Number,
+                        Position = ToPair(GetPositionOf(s.Number)),
+                        Count = s.Count,
+                        Probability = s.Probability,
+                        LastRound = s.LastRound
+                    })
+                    .ToList(),
+                Blacklist = blacklist,
+                Whitelist = whitelist,
+                WhitelistOnlyMode = _whitelistOnlyMode,
+                Config = new StateConfig
+                {
+                    MinPoolSize = _minPoolSize,
+                    MaxGapThreshold = _maxGapThreshold,
+                    ColdStartBoost = _coldStartBoost,
+                    DecayFactor = _decayFactor
+                },
+                CurrentRound = _currentRound,
+                TotalDraws = _totalDraws,
+                CandidatePool = GetCandidatePoolList(),
+                Rows = grid?.rows,
+                Cols = grid?.cols,
+                BlacklistPositions = grid.HasValue ? blacklist.Select(n => ToPair(GetPositionOf(n))).OfType<int[]>().ToList() : null,
+                WhitelistPositions = grid.HasValue ? whitelist.Select(n => ToPair(GetPositionOf(n))).OfType<int[]>().ToList() : null
+            };
+        }
+
+        /// <summary>
+        /// 抽取一次，outcome 不为null时记录抽取时的状态
+        /// </summary>
+        private int DrawCore(bool autoSave, DrawOutcome? outcome)
+        {
+            EnsureWritable();
+            
+            if (_roundRobin)
+            {
+                return DrawRoundRobin(autoSave, outcome);
+            }
+            
+            // 日期变化后重新计算候选池，使昨天达到每日上限的学号恢复
+            if (_dailyLimit.HasValue && _batchDrawn == null && _candidatePoolDate != ToLocalDate(_clock()))
+            {
+                UpdateCandidatePool();
+            }
+            
+            if (_candidatePool != null && _candidatePool.Count == 0)
+            {
+                ThrowIfWhitelistOnlyButEmpty();
+                ThrowIfDailyLimitReached();
+                ThrowIfGroupQuotasExhausted();
+                
+                if (_batchDrawn != null)
+                {
+                    // 批量模式下不重置，重新计算候选池并排除本批已抽中的学号
+                    UpdateCandidatePool();
+                    _candidatePool!.RemoveAll(n => _batchDrawn.Contains(n));
+                    if (_candidatePool.Count == 0)
+                        throw new InvalidOperationException("批量抽取时候选池已耗尽");
+                }
+                else if (_emptyPoolPolicy == EmptyPoolPolicy.Error)
+                {
+                    throw new InvalidOperationException("候选池为空，没有可抽取的学号");
+                }
+                else
+                {
+                    // 如果候选池为空，重置所有抽取次数（完成一个抽取周期）
+                    ResetDrawCounts();
+                    _cyclesCompleted++;
+                    if (_candidatePool!.Count == 0)
+                        throw new InvalidOperationException("没有可抽取的学号（所有分组可能都已达到抽取上限）");
+                }
+            }
+
+            _currentRound++;
+            
+            // 计算每个候选者的权重
+            var weights = CalculateWeights();
+            
+            // 根据权重进行随机抽取
+            int selectedNumber = WeightedRandomSelect(weights);
+            double totalWeight = weights.Values.Sum();
+            
+            if (outcome != null)
+            {
+                outcome.Number = selectedNumber;
+                outcome.Round = _currentRound;
+                outcome.Pool = _candidatePool!.OrderBy(n => n).ToList();
+                outcome.Weights = new Dictionary<int, double>(weights);
+                outcome.Probabilities = weights.ToDictionary(kvp => kvp.Key, kvp => kvp.Value / totalWeight);
+            }
+            
+            // 更新抽取记录
+            RecordDraw(selectedNumber, weights[selectedNumber] / totalWeight);
+            
+            if (_batchDrawn != null)
+            {
+                // 批量模式：只从候选池中移除本次抽中的学号，结束批量时再统一重新计算
+                _candidatePool!.Remove(selectedNumber);
+                _batchDrawn.Add(selectedNumber);
+                if (IsGroupQuotaReached(selectedNumber))
+                {
+                    _candidatePool.RemoveAll(IsGroupQuotaReached);
+                }
+            }
+            else
+            {
+                // 更新候选池和概率
+                UpdateCandidatePool();
+                UpdateProbabilities();
+                TakeProbabilitySnapshotIfDue();
+            }
+            
+            // 自动保存数据
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return selectedNumber;
+        }
+
+        /// <summary>
+        /// 轮流模式下抽取：取出队列中的第一个学号，队列为空时打乱所有可抽取的学号重新生成队列
+        /// </summary>
+        private int DrawRoundRobin(bool autoSave, DrawOutcome? outcome)
+        {
+            // 移除加入队列后变为不可抽取的学号（如被加入黑名单）
+            var eligible = GetRoundRobinEligible();
+            _roundRobinQueue.RemoveAll(n => !eligible.Contains(n));
+            
+            if (_roundRobinQueue.Count == 0)
+            {
+                if (eligible.Count == 0)
+                {
+                    ThrowIfWhitelistOnlyButEmpty();
+                    ThrowIfDailyLimitReached();
+                    ThrowIfGroupQuotasExhausted();
+                    throw new InvalidOperationException("没有可抽取的学号");
+                }
+                
+                _roundRobinQueue = eligible.OrderBy(n => n).ToList();
+                Shuffle(_roundRobinQueue);
+            }
+            
+            // 批量模式下跳过本批已抽中的学号（队列在批量中途重新填充时会包含它们），它们留在队列中等待之后的抽取
+            var selectable = _batchDrawn == null
+                ? _roundRobinQueue
+                : _roundRobinQueue.Where(n => !_batchDrawn.Contains(n)).ToList();
+            if (selectable.Count == 0)
+                throw new InvalidOperationException("批量抽取时候选池已耗尽");
+            
+            _currentRound++;
+            
+            int selectedNumber = selectable[0];
+            double probability = 1.0 / selectable.Count;
+            
+            if (outcome != null)
+            {
+                outcome.Number = selectedNumber;
+                outcome.Round = _currentRound;
+                outcome.Pool = selectable.OrderBy(n => n).ToList();
+                outcome.Weights = outcome.Pool.ToDictionary(n => n, _ => 1.0);
+                outcome.Probabilities = outcome.Pool.ToDictionary(n => n, _ => probability);
+            }
+            
+            _roundRobinQueue.Remove(selectedNumber);
+            RecordDraw(selectedNumber, probability);
+            
+            if (_batchDrawn != null)
+            {
+                _batchDrawn.Add(selectedNumber);
+            }
+            else
+            {
+                UpdateCandidatePool();
+                UpdateProbabilities();
+                TakeProbabilitySnapshotIfDue();
+            }
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return selectedNumber;
+        }
+
+        /// <summary>
+        /// 批量抽取多个学号
+        /// </summary>
+        /// <param name="count">抽取数量</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>抽取到的学号列表</returns>
+        public List<int> DrawMultiple(int count, bool autoSave = true)
+        {
+            return DrawMultiple(count, autoSave, (_, _) => { });
+        }
+
+        /// <summary>
+        /// 批量抽取多个学号，每抽中一个学号就调用一次 onEach（如用于逐个显示抽取结果的动画）
+        /// 与多次调用 Draw 不同，仍然只在全部抽取后保存一次；中途出错时先保存已完成的抽取再抛出异常
+        /// </summary>
+        /// <param name="count">抽取数量</param>
+        /// <param name="autoSave">是否自动保存数据</param>
+        /// <param name="onEach">每次抽取后调用，参数为本批中的序号（从0开始）和抽到的学号</param>
+        /// <returns>抽取到的学号列表</returns>
+        public List<int> DrawMultiple(int count, bool autoSave, Action<int, int> onEach)
+        {
+            EnsureWritable();
+            
+            if (count <= 0) 
+                throw new ArgumentException("抽取数量必须大于0");
+            if (_candidatePool != null && count > _candidatePool.Count)
+                throw new ArgumentException($"抽取数量不能超过候选池大小({_candidatePool.Count})");
+                
+            List<int> results = new List<int>();
+            
+            // 批量模式下同一批次不会重复抽中同一学号，候选池和概率只在最后重新计算一次
+            bool ownsBatch = !IsInBatch();
+            if (ownsBatch) BeginBatch();
+            try
+            {
+                for (int i = 0; i < count; i++)
+                {
+                    int number = Draw(false);
+                    results.Add(number);
+                    onEach(i, number);
+                }
+            }
+            catch
+            {
+                if (ownsBatch) EndBatch();
+                
+                // 已完成的抽取已经修改了内存中的记录，保存它们，避免内存与文件不一致
+                if (autoSave && results.Count > 0)
+                {
+                    SaveData();
+                }
+                throw;
+            }
+            
+            if (ownsBatch) EndBatch();
+            
+            // 只在全部抽取后保存
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return results;
+        }
+
+        /// <summary>
+        /// 只从指定的学号中抽取一次（如只从举手的学生中抽取），不修改白名单
+        /// 指定的学号与可抽取的学号取交集后按正常的公式计算权重，抽取记录和历史与 Draw 相同
+        /// </summary>
+        /// <param name="subset">允许抽取的学号</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>抽中的学号</returns>
+        /// <exception cref="ArgumentException">指定的学号都不存在或都在黑名单中</exception>
+        public int DrawFromSubset(IEnumerable<int> subset, bool autoSave = true)
+        {
+            ArgumentNullException.ThrowIfNull(subset);
+            
+            var members = (_whitelistOnlyMode ? _whitelist : GetActiveNumbers()).ToHashSet();
+            var intersection = subset.Where(members.Contains).Distinct().ToList();
+            if (intersection.Count == 0)
+                throw new ArgumentException("指定的学号都不在可抽取的学号中");
+            if (intersection.All(_blacklist.Contains))
+                throw new ArgumentException("指定的学号都在黑名单中");
+            
+            return DrawRestricted(intersection, autoSave);
+        }
+
+        /// <summary>
+        /// 抽取一次，但本次不抽取指定的学号（不修改黑名单），其余同 DrawFromSubset
+        /// </summary>
+        /// <param name="excluded">本次不抽取的学号</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>抽中的学号</returns>
+        public int DrawExcluding(IEnumerable<int> excluded, bool autoSave = true)
+        {
+            ArgumentNullException.ThrowIfNull(excluded);
+            
+            var excludedSet = excluded.ToHashSet();
+            var members = _whitelistOnlyMode ? _whitelist : GetActiveNumbers();
+            return DrawFromSubset(members.Where(n => !excludedSet.Contains(n)).ToList(), autoSave);
+        }
+
+        /// <summary>
+        /// 带限制条件的批量抽取（如抽取4人小组时必须包括或排除某个学号），限制只对本次抽取生效
+        /// 必须抽中的学号最先被抽中（计入抽取记录，顺序与 Include 相同），剩余的名额按权重从其余学号中抽取，
+        /// 同一学号不会被抽中两次，Exclude 中的学号不会被抽中。
+        /// 限制条件冲突或可抽取的学号不足时在修改任何状态前抛出 ArgumentException；
+        /// 中途失败时恢复到抽取前的状态。不能在批量模式或轮流模式下使用
+        /// </summary>
+        /// <param name="count">抽取数量（包括必须抽中的学号）</param>
+        /// <param name="constraints">限制条件</param>
+        /// <param name="autoSave">是否在全部抽取后保存数据（默认true）</param>
+        /// <returns>抽取到的学号列表</returns>
+        public List<int> DrawMultipleWith(int count, DrawConstraints constraints, bool autoSave = true)
+        {
+            EnsureWritable();
+            
+            ArgumentNullException.ThrowIfNull(constraints);
+            var include = constraints.Include ?? new List<int>();
+            var exclude = new HashSet<int>(constraints.Exclude ?? new List<int>());
+            
+            if (count <= 0)
+                throw new ArgumentException("抽取数量必须大于0");
+            if (_batchDrawn != null || _roundRobin)
+                throw new InvalidOperationException("批量模式或轮流模式下不能使用限制条件抽取");
+            if (include.Distinct().Count() != include.Count)
+                throw new ArgumentException("必须抽中的学号中有重复的学号");
+            if (include.Count > count)
+                throw new ArgumentException($"必须抽中的学号数量({include.Count})超过了抽取数量({count})");
+            
+            var conflicts = include.Where(exclude.Contains).ToList();
+            if (conflicts.Count > 0)
+                throw new ArgumentException($"学号同时在必须抽中和排除列表中: {string.Join(", ", conflicts)}");
+            
+            var drawable = (_whitelistOnlyMode ? _whitelist : GetActiveNumbers())
+                .Where(n => !_blacklist.Contains(n) && !IsGroupQuotaReached(n) && !IsDailyLimitReached(n) &&
+                            (_candidateFilter == null || _candidateFilter(n)))
+                .ToHashSet();
+            var notDrawable = include.Where(n => !drawable.Contains(n)).ToList();
+            if (notDrawable.Count > 0)
+                throw new ArgumentException($"必须抽中的学号不可抽取（不存在、在黑名单中、被过滤或已达到抽取上限）: {string.Join(", ", notDrawable)}");
+            
+            int available = drawable.Count(n => !exclude.Contains(n));
+            if (count > available)
+                throw new ArgumentException($"抽取数量不能超过排除后可抽取的学号数({available})");
+            
+            var state = CaptureDrawState();
+            var results = new List<int>(count);
+            try
+            {
+                foreach (var number in include)
+                {
+                    results.Add(DrawRestricted(new[] { number }, false));
+                }
+                
+                while (results.Count < count)
+                {
+                    // 优先从正常的候选池中抽取，候选池中没有可选的学号时从所有可抽取的学号中抽取
+                    var pool = (_candidatePool ?? new List<int>())
+                        .Where(n => !exclude.Contains(n) && !results.Contains(n))
+                        .ToList();
+                    if (pool.Count == 0)
+                    {
+                        pool = drawable.Where(n => !exclude.Contains(n) && !results.Contains(n)).ToList();
+                    }
+                    results.Add(DrawRestricted(pool, false));
+                }
+            }
+            catch
+            {
+                RestoreDrawState(state);
+                throw;
+            }
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return results;
+        }
+
+        /// <summary>
+        /// 排名抽取（如第1、2、3名）：依次按权重抽取 ranks 个不同的学号，名次按抽取顺序确定
+        /// 数量检查和候选池耗尽时的处理与 DrawMultiple 相同；名次会记录在抽取历史中。
+        /// 中途失败时恢复到抽取前的状态，不会留下部分结果；成功时只在最后保存一次
+        /// </summary>
+        /// <param name="ranks">名次数量</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>按名次排列的结果</returns>
+        public List<RankedDraw> DrawRanked(int ranks, bool autoSave = true)
+        {
+            EnsureWritable();
+            
+            if (ranks <= 0) 
+                throw new ArgumentException("名次数量必须大于0");
+            if (_candidatePool != null && ranks > _candidatePool.Count)
+                throw new ArgumentException($"名次数量不能超过候选池大小({_candidatePool.Count})");
+            
+            var state = CaptureDrawState();
+            var results = new List<RankedDraw>(ranks);
+            
+            bool ownsBatch = !IsInBatch();
+            if (ownsBatch) BeginBatch();
+            try
+            {
+                for (int rank = 1; rank <= ranks; rank++)
+                {
+                    int number = Draw(false);
+                    var record = _history[^1];
+                    record.Rank = rank;
+                    results.Add(new RankedDraw { Rank = rank, Number = number, Probability = record.Probability });
+                }
+            }
+            catch
+            {
+                // 恢复到抽取前的状态
+                if (ownsBatch) EndBatch();
+                RestoreDrawState(state);
+                throw;
+            }
+            
+            if (ownsBatch) EndBatch();
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return results;
+        }
+
+        /// <summary>
+        /// 批量抽取多个学号，中途出错时不抛出异常，而是返回出错前已抽到的学号和错误
+        /// 已完成的抽取会计入抽取记录，autoSave 为true时无论是否出错都会保存
+        /// </summary>
+        /// <param name="count">抽取数量</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>已抽到的学号和错误（全部完成时为null）</returns>
+        public BatchResult TryDrawMultiple(int count, bool autoSave = true)
+        {
+            var result = new BatchResult();
+            try
+            {
+                DrawMultiple(count, autoSave, (_, number) => result.Drawn.Add(number));
+            }
+            catch (Exception ex) when (ex is InvalidOperationException or ArgumentException)
+            {
+                result.Error = ex;
+            }
+            return result;
+        }
+
+        /// <summary>
+        /// 将所有可抽取的学号（不在黑名单中；白名单模式下为白名单中的学号）随机分成 k 组
+        /// 按抽取顺序依次分配到第1、2、…、k 组，人数不能整除时前面的组多一人；
+        /// 分组也会计入抽取次数，因此抽取次数较少的学号更容易先被抽到。
+        /// 每个学号只会被分到一个组中；因最小间隔、分组上限、每日上限等限制无法抽到所有学号时抛出 InvalidOperationException
+        /// </summary>
+        /// <param name="k">组数</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>每组的学号</returns>
+        public List<List<int>> PartitionTeams(int k, bool autoSave = true)
+        {
+            EnsureWritable();
+            
+            int count = GetDrawableCount();
+            if (k <= 0)
+                throw new ArgumentException("组数必须大于0");
+            if (k > count)
+                throw new ArgumentException($"组数不能超过可抽取的学号数({count})");
+            
+            var teams = Enumerable.Range(0, k).Select(_ => new List<int>()).ToList();
+            
+            // 批量模式下同一批次不会重复抽中同一学号，候选池用完后会从剩余的学号中重新计算
+            bool ownsBatch = !IsInBatch();
+            if (ownsBatch) BeginBatch();
+            try
+            {
+                for (int i = 0; i < count; i++)
+                {
+                    teams[i % k].Add(Draw(false));
+                }
+            }
+            finally
+            {
+                if (ownsBatch) EndBatch();
+            }
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return teams;
+        }
+
+        /// <summary>
+        /// 有放回地抽取 n 次（同一学号可以被多次抽中），返回原始的抽取序列
+        /// 与 DrawMultiple 不同，不保证结果互不相同；每次抽取都会正常更新抽取记录
+        /// </summary>
+        /// <param name="n">抽取次数</param>
+        /// <param name="autoSave">是否在全部抽取后保存数据（默认true）</param>
+        /// <returns>按抽取顺序排列的学号</returns>
+        public List<int> DrawWithReplacement(int n, bool autoSave = true)
+        {
+            EnsureWritable();
+            
+            if (n <= 0)
+                throw new ArgumentException("抽取次数必须大于0");
+            
+            var results = new List<int>(n);
+            for (int i = 0; i < n; i++)
+            {
+                results.Add(Draw(false));
+            }
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            return results;
+        }
+
+        /// <summary>
+        /// 逐个抽取学号，直到 predicate 对已抽到的学号序列返回 true，或达到 maxAttempts 次
+        /// 每次抽取都会正常更新抽取记录；未满足条件时，默认返回已抽到的学号，
+        /// throwIfNotMet 为 true 时抛出 InvalidOperationException（已进行的抽取仍然有效并会被保存）
+        /// </summary>
+        /// <param name="maxAttempts">最多抽取的次数</param>
+        /// <param name="predicate">停止条件，参数为按抽取顺序排列的已抽到的学号</param>
+        /// <param name="autoSave">是否在抽取结束后保存数据（默认true）</param>
+        /// <param name="throwIfNotMet">达到 maxAttempts 仍未满足条件时是否抛出异常（默认false）</param>
+        /// <returns>按抽取顺序排列的学号</returns>
+        public List<int> DrawUntil(int maxAttempts, Func<IReadOnlyList<int>, bool> predicate, bool autoSave = true, 
+                                   bool throwIfNotMet = false)
+        {
+            EnsureWritable();
+            
+            if (maxAttempts <= 0)
+                throw new ArgumentException("最大抽取次数必须大于0");
+            ArgumentNullException.ThrowIfNull(predicate);
+            
+            var results = new List<int>();
+            bool met = false;
+            while (!met && results.Count < maxAttempts)
+            {
+                results.Add(Draw(false));
+                met = predicate(results);
+            }
+            
+            if (autoSave)
+            {
+                SaveData();
+            }
+            
+            if (!met && throwIfNotMet)
+                throw new InvalidOperationException($"抽取{maxAttempts}次后仍未满足停止条件");
+            
+            return results;
+        }
+
+        /// <summary>
+        /// 统计抽取结果中每个学号出现的次数
+        /// </summary>
+        /// <param name="results">抽取结果</param>
+        /// <returns>学号 -> 出现次数</returns>
+        public static Dictionary<int, int> FrequencyTable(IEnumerable<int> results)
+        {
+            var table = new Dictionary<int, int>();
+            foreach (var number in results)
+            {
+                table[number] = table.TryGetValue(number, out var count) ? count + 1 : 1;
+            }
+            return table;
+        }
+
+        /// <summary>
+        /// 开始批量抽取
+        /// 批量模式下每次抽取只更新被抽中学号的记录，并将其从候选池中移除，
+        /// 候选池和概率在 EndBatch 时统一重新计算
+        /// </summary>
+        public void BeginBatch()
+        {
+            EnsureWritable();
+            
+            if (_batchDrawn != null)
+                throw new InvalidOperationException("已经处于批量抽取模式");
+            
+            _batchDrawn = new HashSet<int>();
+        }
+
+        /// <summary>
+        /// 结束批量抽取，重新计算候选池和概率
+        /// </summary>
+        public void EndBatch()
+        {
+            EnsureWritable();
+            
+            if (_batchDrawn == null) return;
+            
+            _batchDrawn = null;
+            UpdateCandidatePool();
+            UpdateProbabilities();
+            TakeProbabilitySnapshotIfDue();
+        }
+
+        /// <summary>
+        /// 是否处于批量抽取模式
+        /// </summary>
+        public bool IsInBatch() => _batchDrawn != null;
+
+        /// <summary>
+        /// 获取当前权重最高的候选学号（不进行抽取）
+        /// </summary>
+        /// <returns>权重最高的学号，权重相同时取较小的学号；候选池为空时返回null</returns>
+        public int? GetMostLikely()
+        {
+            var weights = CalculateWeights();
+            if (!weights.Any()) return null;
+            
+            return weights
+                .OrderByDescending(kvp => kvp.Value)
+                .ThenBy(kvp => kvp.Key)
+                .First().Key;
+        }
+
+        /// <summary>
+        /// 获取当前权重最低的候选学号（不进行抽取）
+        /// </summary>
+        /// <returns>权重最低的学号，权重相同时取较小的学号；候选池为空时返回null</returns>
+        public int? GetLeastLikely()
+        {
+            var weights = CalculateWeights();
+            if (!weights.Any()) return null;
+            
+            return weights
+                .OrderBy(kvp => kvp.Value)
+                .ThenBy(kvp => kvp.Key)
+                .First().Key;
+        }
+
+        /// <summary>
+        /// 获取长期未被抽中的学号（不包括黑名单中的学号）
+        /// </summary>
+        /// <param name="gapRounds">轮次间隔阈值，距上次被抽中超过该轮次数即视为长期未抽中</param>
+        /// <returns>长期未抽中的学号列表，按学号顺序排列；从未被抽中的学号总是包含在内</returns>
+        public List<int> GetStarvedNumbers(int gapRounds)
+        {
+            return GetActiveNumbers()
+                .Where(n => !_blacklist.Contains(n))
+                .Where(n =>
+                {
+                    int lastRound = _lastDrawRound.TryGetValue(n, out var round) ? round : -1;
+                    return lastRound < 0 || _currentRound - lastRound > gapRounds;
+                })
+                .ToList();
+        }
+
+        /// <summary>
+        /// 开始记录概率快照：每隔 everyNRounds 轮，在抽取后记录一次每个学号的概率和最大差距
+        /// 批量抽取时在批量结束后记录。超过 maxSnapshots 个时移除最早的快照
+        /// </summary>
+        /// <param name="everyNRounds">记录间隔（轮）</param>
+        /// <param name="maxSnapshots">最多保留的快照数量</param>
+        /// <param name="persist">是否将快照保存到数据文件（默认不保存，避免文件过大；记录设置总会被保存）</param>
+        public void EnableProbabilitySnapshots(int everyNRounds, int maxSnapshots, bool persist = false)
+        {
+            EnsureWritable();
+            
+            if (everyNRounds < 1)
+                throw new ArgumentException("记录间隔必须大于0");
+            if (maxSnapshots < 1)
+                throw new ArgumentException("快照数量上限必须大于0");
+            
+            _snapshotEvery = everyNRounds;
+            _maxSnapshots = maxSnapshots;
+            _persistSnapshots = persist;
+            TrimSnapshots();
+        }
+
+        /// <summary>
+        /// 停止记录概率快照，已记录的快照会保留
+        /// </summary>
+        public void DisableProbabilitySnapshots()
+        {
+            EnsureWritable();
+            
+            _snapshotEvery = 0;
+        }
+
+        /// <summary>
+        /// 清空已记录的概率快照
+        /// </summary>
+        public void ClearProbabilitySnapshots()
+        {
+            EnsureWritable();
+            
+            _snapshots.Clear();
+        }
+
+        /// <summary>
+        /// 获取已记录的概率快照（按轮次顺序）
+        /// </summary>
+        public List<ProbabilitySnapshot> GetProbabilitySnapshots() => CopySnapshots(_snapshots);
+
+        /// <summary>
+        /// 获取每个快照记录时的最大抽取次数差距（按轮次顺序）
+        /// </summary>
+        /// <returns>(轮次, 最大差距) 列表</returns>
+        public List<(int Round, int MaxGap)> GetGapHistory()
+        {
+            return _snapshots.Select(snapshot => (snapshot.Round, snapshot.MaxGap)).ToList();
+        }
+
+        /// <summary>
+        /// 距离上一次快照已经过了设置的轮数时记录一次快照
+        /// </summary>
+        private void TakeProbabilitySnapshotIfDue()
+        {
+            if (_snapshotEvery <= 0) return;
+            
+            int lastRound = _snapshots.Count > 0 ? _snapshots[^1].Round : 0;
+            if (_currentRound - lastRound < _snapshotEvery && _currentRound >= lastRound) return;
+            
+            _snapshots.Add(new ProbabilitySnapshot
+            {
+                Round = _currentRound,
+                Probabilities = _currentProbabilities.ToDictionary(),
+                MaxGap = GetMaxDrawCountGap()
+            });
+            TrimSnapshots();
+        }
+
+        private void TrimSnapshots()
+        {
+            if (_snapshots.Count > _maxSnapshots)
+            {
+                _snapshots.RemoveRange(0, _snapshots.Count - _maxSnapshots);
+            }
+        }
+
+        private static List<ProbabilitySnapshot> CopySnapshots(List<ProbabilitySnapshot> snapshots)
+        {
+            return snapshots.Select(snapshot => new ProbabilitySnapshot
+            {
+                Round = snapshot.Round,
+                Probabilities = new Dictionary<int, double>(snapshot.Probabilities),
+                MaxGap = snapshot.MaxGap
+            }).ToList();
+        }
+
+        /// <summary>
+        /// 获取抽取历史（按抽取顺序）
+        /// </summary>
+        public List<DrawRecord> GetHistory()
+        {
+            return new List<DrawRecord>(_history);
+        }
+
+        /// <summary>
+        /// 清空抽取历史（不影响抽取次数）
+        /// </summary>
+        public void ClearHistory()
+        {
+            EnsureWritable();
+            
+            _history.Clear();
+        }
+
+        /// <summary>
+        /// 将抽取历史导出为便于绘制图表的JSON文件（只导出，不会修改数据或数据文件）
+        /// 包含概要信息（调节参数、抽取次数等）和按顺序排列的抽取记录，时间为RFC3339格式（UTC）
+        /// </summary>
+        /// <param name="filePath">导出的文件路径</param>
+        public void ExportHistoryJson(string filePath)
+        {
+            var data = ToData();
+            var export = new HistoryExport
+            {
+                Summary = new HistoryExportSummary
+                {
+                    DataId = data.Id,
+                    Type = data.Type,
+                    DisplayName = _displayName,
+                    Config = GetConfig(),
+                    TotalDraws = _totalDraws,
+                    CurrentRound = _currentRound,
+                    DrawCounts = GetActiveNumbers().ToDictionary(n => n, n => _drawCounts.TryGetValue(n, out var c) ? c : 0),
+                    ExportedAt = ToRfc3339(_clock())
+                },
+                History = _history.Select(r => new HistoryExportEntry
+                {
+                    Round = r.Round,
+                    Number = r.Number,
+                    Label = GetLabel(r.Number),
+                    Probability = r.Probability,
+                    Timestamp = ToRfc3339(r.Timestamp)
+                }).ToList()
+            };
+            
+            File.WriteAllText(filePath, JsonSerializer.Serialize(export, new JsonSerializerOptions { WriteIndented = true }));
+        }
+
+        /// <summary>
+        /// 将UTC时间格式化为RFC3339字符串
+        /// </summary>
+        private static string ToRfc3339(DateTime utc)
+        {
+            return DateTime.SpecifyKind(utc, DateTimeKind.Utc)
+                .ToString("yyyy-MM-dd'T'HH:mm:ss.fff'Z'", System.Globalization.CultureInfo.InvariantCulture);
+        }
+
+        /// <summary>
+        /// 获取连续被抽中次数最多的学号
+        /// </summary>
+        /// <returns>学号和连续被抽中的次数，长度相同时取最早出现的；没有抽取历史时返回null</returns>
+        public (int Number, int Length)? GetLongestStreak()
+        {
+            if (_history.Count == 0) return null;
+            
+            int bestNumber = _history[0].Number;
+            int bestLength = 1;
+            int currentLength = 1;
+            for (int i = 1; i < _history.Count; i++)
+            {
+                currentLength = _history[i].Number == _history[i - 1].Number ? currentLength + 1 : 1;
+                if (currentLength > bestLength)
+                {
+                    bestLength = currentLength;
+                    bestNumber = _history[i].Number;
+                }
+            }
+            
+            return (bestNumber, bestLength);
+        }
+
+        /// <summary>
+        /// 根据抽取历史找出统计上不太可能出现的结果（如有人认为抽取“不公平”时用于核对）
+        /// 每个学号每次被抽中的预期概率取其目标比例（未设置目标比例时为平均分配），这是平衡抽取长期趋向的比例；
+        /// 历史中只记录了被抽中学号当时的概率，无法还原每次抽取时所有学号的概率。
+        /// FrequentDraws：最近 window 次抽取中的抽中次数，按二项分布计算不低于该次数的概率；
+        /// LongWait：距上次被抽中（从未被抽中时为历史开始）的抽取次数，按几何分布计算等待不短于该次数的概率。
+        /// 概率低于 pValueThreshold 的结果会被返回。黑名单中的学号不参与检测
+        /// </summary>
+        /// <param name="window">统计抽中次数时使用的最近抽取次数</param>
+        /// <param name="pValueThreshold">判定为异常的概率阈值（默认0.01）</param>
+        /// <returns>异常列表，按概率从小到大排列</returns>
+        public List<DrawAnomaly> DetectAnomalies(int window, double pValueThreshold = 0.01)
+        {
+            if (window < 1)
+                throw new ArgumentException("窗口大小必须大于0");
+            if (!(pValueThreshold > 0 && pValueThreshold < 1))
+                throw new ArgumentException("概率阈值必须在0到1之间");
+            
+            var anomalies = new List<DrawAnomaly>();
+            if (_history.Count == 0)
+                return anomalies;
+            
+            var recent = _history.Skip(Math.Max(_history.Count - window, 0)).ToList();
+            var recentCounts = recent.GroupBy(r => r.Number).ToDictionary(g => g.Key, g => g.Count());
+            var lastIndex = new Dictionary<int, int>();
+            for (int i = 0; i < _history.Count; i++)
+            {
+                lastIndex[_history[i].Number] = i;
+            }
+            
+            foreach (var number in GetActiveNumbers().Where(n => !_blacklist.Contains(n)))
+            {
+                double p = GetTargetShare(number);
+                if (!(p > 0)) continue;
+                
+                int count = recentCounts.TryGetValue(number, out var c) ? c : 0;
+                double countPValue = BinomialUpperTail(recent.Count, count, p);
+                if (count > 0 && countPValue < pValueThreshold)
+                {
+                    anomalies.Add(new DrawAnomaly
+                    {
+                        Kind = AnomalyKind.FrequentDraws,
+                        Number = number,
+                        Observed = count,
+                        Expected = recent.Count * p,
+                        PValue = countPValue
+                    });
+                }
+                
+                int wait = lastIndex.TryGetValue(number, out var index) ? _history.Count - 1 - index : _history.Count;
+                double waitPValue = Math.Pow(1 - p, wait);
+                if (waitPValue < pValueThreshold)
+                {
+                    anomalies.Add(new DrawAnomaly
+                    {
+                        Kind = AnomalyKind.LongWait,
+                        Number = number,
+                        Observed = wait,
+                        Expected = 1 / p - 1,
+                        PValue = waitPValue
+                    });
+                }
+            }
+            
+            return anomalies.OrderBy(a => a.PValue).ThenBy(a => a.Number).ToList();
+        }
+
+        /// <summary>
+        /// 二项分布 B(n, p) 中 X ≥ k 的概率
+        /// </summary>
+        private static double BinomialUpperTail(int n, int k, double p)
+        {
+            if (k <= 0) return 1.0;
+            if (k > n) return 0.0;
+            if (p >= 1) return 1.0;
+            
+            // 在对数空间中计算 P(X = k)，再逐项递推，避免 n 较大时下溢
+            double logPmf = k * Math.Log(p) + (n - k) * Math.Log(1 - p);
+            for (int j = 1; j <= k; j++)
+            {
+                logPmf += Math.Log(n - k + j) - Math.Log(j);
+            }
+            
+            double pmf = Math.Exp(logPmf);
+            double tail = 0.0;
+            for (int i = k; i <= n && pmf > 0; i++)
+            {
+                tail += pmf;
+                pmf *= (double)(n - i) / (i + 1) * p / (1 - p);
+            }
+            
+            return Math.Min(tail, 1.0);
+        }
+
+        /// <summary>
+        /// 按抽取记录对外部给出的学号排序（如显示建议的点名顺序），不会抽取也不会修改当前实例
+        /// 按抽取次数从少到多排序，次数相同时最后被抽中的轮次早的（或从未被抽中的）在前，再相同时当前概率高的在前，最后按学号；
+        /// 当前实例中不存在的学号排在最后，保持原来的顺序
+        /// </summary>
+        /// <param name="items">要排序的学号（可以包含重复的学号）</param>
+        /// <returns>排序后的新列表</returns>
+        public List<int> BalancedShuffle(IEnumerable<int> items)
+        {
+            var active = GetActiveNumbers().ToHashSet();
+            var list = items.ToList();
+            
+            var known = list
+                .Where(active.Contains)
+                .OrderBy(n => _drawCounts.TryGetValue(n, out var count) ? count : 0)
+                .ThenBy(n => _lastDrawRound.TryGetValue(n, out var round) ? round : -1)
+                .ThenByDescending(n => _currentProbabilities.TryGetValue(n, out var prob) ? prob : 0)
+                .ThenBy(n => n);
+            
+            return known.Concat(list.Where(n => !active.Contains(n))).ToList();
+        }
+
+        /// <summary>
+        /// 获取最近 window 次抽取中被抽中超过一次的学号
+        /// </summary>
+        /// <param name="window">统计的抽取次数</param>
+        /// <returns>学号列表，按学号顺序排列</returns>
+        public List<int> GetRecentRepeats(int window)
+        {
+            if (window < 0)
+                throw new ArgumentException("窗口大小不能小于0");
+            
+            return _history
+                .Skip(Math.Max(_history.Count - window, 0))
+                .GroupBy(r => r.Number)
+                .Where(g => g.Count() > 1)
+                .Select(g => g.Key)
+                .OrderBy(n => n)
+                .ToList();
+        }
+
+        /// <summary>
+        /// 获取当前抽取统计列表
+        /// </summary>
+        /// <returns>抽取次数列表，按学号顺序排列</returns>
+        public List<int> GetStatisticsList()
+        {
+            return GetActiveNumbers()
+                .Select(n => _drawCounts.TryGetValue(n, out var count) ? count : 0)
+                .ToList();
+        }
+
+        /// <summary>
+        /// 获取当前每个学号的抽取概率列表
+        /// </summary>
+        /// <returns>概率列表，按学号顺序排列</returns>
+        public List<double> GetProbabilityList()
+        {
+            return GetActiveNumbers()
+                .Select(n => _currentProbabilities.TryGetValue(n, out var prob) ? prob : 0)
+                .ToList();
+        }
+
+        /// <summary>
+        /// 获取当前每个学号的抽取概率百分比（用于界面显示）
+        /// 按最大余数法舍入，使所有学号的百分比之和在给定精度下正好为100
+        /// </summary>
+        /// <param name="decimals">保留的小数位数（0–6）</param>
+        /// <returns>(学号, 百分比) 列表，按学号顺序排列；所有概率都为0时百分比都为0</returns>
+        public List<(int Number, double Percent)> GetProbabilityPercents(int decimals)
+        {
+            if (decimals < 0 || decimals > 6)
+                throw new ArgumentException("小数位数必须在0到6之间");
+            
+            var numbers = GetActiveNumbers();
+            var probabilities = numbers
+                .Select(n => _currentProbabilities.TryGetValue(n, out var prob) ? Math.Max(prob, 0) : 0)
+                .ToList();
+            double total = probabilities.Sum();
+            long scale = (long)Math.Pow(10, decimals);
+            var units = new long[numbers.Count];
+            
+            if (total > 0)
+            {
+                // 先向下取整，再把剩余的单位依次分给余数最大的学号（余数相同时学号小的优先）
+                long target = 100 * scale;
+                var remainders = new double[numbers.Count];
+                for (int i = 0; i < numbers.Count; i++)
+                {
+                    double exact = probabilities[i] / total * target;
+                    units[i] = (long)Math.Floor(exact);
+                    remainders[i] = exact - units[i];
+                }
+                
+                long left = target - units.Sum();
+                foreach (int i in Enumerable.Range(0, numbers.Count)
+                             .OrderByDescending(i => remainders[i])
+                             .ThenBy(i => numbers[i])
+                             .Take((int)Math.Max(left, 0)))
+                {
+                    units[i]++;
+                }
+            }
+            
+            return numbers
+                .Select((n, i) => (n, Math.Round((double)units[i] / scale, decimals)))
+                .ToList();
+        }
+
+        /// <summary>
+        /// 获取每个学号的完整统计信息（一次返回抽取次数、当前概率和最后被抽中的轮次）
+        /// </summary>
+        /// <returns>按学号顺序排列的统计信息，从未被抽中时 LastRound 为-1</returns>
+        public List<(int Number, int Count, double Probability, int LastRound)> GetFullStatistics()
+        {
+            return GetActiveNumbers()
+                .Select(n => (
+                    n,
+                    _drawCounts.TryGetValue(n, out var count) ? count : 0,
+                    _currentProbabilities.TryGetValue(n, out var prob) ? prob : 0,
+                    _lastDrawRound.TryGetValue(n, out var round) ? round : -1))
+                .ToList();
+        }
+
+        /// <summary>
+        /// 重置所有抽取次数
+        /// </summary>
+        public void ResetDrawCounts()
+        {
+            EnsureWritable();
+            
+            // 重置原始学号范围的抽取次数
+            foreach (var number in _allNumbers)
+            {
+                _drawCounts[number] = 0;
+                _lastDrawRound[number] = -1;
+            }
+            
+            // 重置白名单学号的抽取次数
+            foreach (var number in _whitelist)
+            {
+                _drawCounts[number] = 0;
+                _lastDrawRound[number] = -1;
+            }
+            
+            _totalDraws = 0;
+            _currentRound = 0;
+            _lastResetAt = _clock();
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 开始新的抽取周期（如新学期）
+        /// 会清空：所有学号（包括白名单中的额外学号）的抽取次数和最后抽中轮次、当前轮次、总抽取次数、
+        /// 抽取历史、本次会话的分组抽取次数、今天的抽取次数、完成的抽取周期数、概率快照、自适应温度的进度；
+        /// keepLists 为false时还会清空黑名单、白名单并关闭白名单模式。
+        /// 不会改变：配置参数、显示名称、描述、学号姓名、分组和分组上限、随机数种子
+        /// </summary>
+        /// <param name="keepLists">是否保留黑名单和白名单</param>
+        public void ResetSession(bool keepLists)
+        {
+            EnsureWritable();
+            
+            if (!keepLists)
+            {
+                _blacklist.Clear();
+                _whitelist.Clear();
+                _whitelistOnlyMode = false;
+                InvalidateActiveNumbers();
+            }
+            
+            ClearDrawState();
+            _lastResetAt = _clock();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 设置按天统计时使用的时区（默认UTC），会被保存
+        /// </summary>
+        public void SetTimeZone(TimeZoneInfo timeZone)
+        {
+            EnsureWritable();
+            
+            _timeZone = timeZone ?? throw new ArgumentNullException(nameof(timeZone));
+        }
+
+        /// <summary>
+        /// 获取按天统计时使用的时区
+        /// </summary>
+        public TimeZoneInfo GetTimeZone() => _timeZone;
+
+        /// <summary>
+        /// 获取上次重置抽取次数的时间（UTC），从未重置时返回null
+        /// </summary>
+        public DateTime? GetLastResetAt() => _lastResetAt;
+
+        /// <summary>
+        /// 获取今天（按设置的时区计算）的抽取次数
+        /// </summary>
+        public int GetTodayDrawCount()
+        {
+            var today = ToLocalDate(_clock());
+            return _history.Count(r => ToLocalDate(r.Timestamp) == today);
+        }
+
+        /// <summary>
+        /// 设置获取当前时间（UTC）的方法，用于抽取记录的时间和按天统计，不会被保存
+        /// 默认使用系统时间，测试时可以替换为固定的时间
+        /// </summary>
+        /// <param name="utcNow">返回当前UTC时间的方法</param>
+        public void SetClock(Func<DateTime> utcNow)
+        {
+            _clock = utcNow ?? throw new ArgumentNullException(nameof(utcNow));
+            if (_dailyLimit.HasValue)
+            {
+                UpdateCandidatePool();
+                UpdateProbabilities();
+            }
+        }
+
+        /// <summary>
+        /// 设置每个学号每天（按设置的时区）最多被抽取的次数，为null时不限制
+        /// 达到上限的学号在当天不会进入候选池（补充候选池时也不会加入），第二天自动恢复；
+        /// 所有可抽取的学号都达到上限时 Draw 抛出 DailyLimitReachedException，而不是重置抽取次数。
+        /// 每天的抽取次数会被保存，ResetDrawCounts 不会清空它
+        /// </summary>
+        /// <param name="limit">每日上限，必须大于0</param>
+        public void SetDailyLimit(int? limit)
+        {
+            EnsureWritable();
+            
+            if (limit <= 0)
+                throw new ArgumentException("每日抽取上限必须大于0");
+            
+            _dailyLimit = limit;
+            UpdateCandidatePool();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取每日抽取上限，为null时不限制
+        /// </summary>
+        public int? GetDailyLimit() => _dailyLimit;
+
+        /// <summary>
+        /// 获取学号今天（按设置的时区）被抽取的次数
+        /// </summary>
+        public int GetDrawnToday(int number)
+        {
+            return GetDrawnToday(number, ToLocalDate(_clock()));
+        }
+
+        /// <summary>
+        /// 获取所有活跃学号今天被抽取的次数（按学号顺序）
+        /// </summary>
+        public Dictionary<int, int> GetDrawnTodayCounts()
+        {
+            var today = ToLocalDate(_clock());
+            return GetActiveNumbers().ToDictionary(n => n, n => GetDrawnToday(n, today));
+        }
+
+        /// <summary>
+        /// 将UTC时间转换为设置的时区中的日期
+        /// </summary>
+        private DateOnly ToLocalDate(DateTime utc)
+        {
+            var local = TimeZoneInfo.ConvertTimeFromUtc(DateTime.SpecifyKind(utc, DateTimeKind.Utc), _timeZone);
+            return DateOnly.FromDateTime(local);
+        }
+
+        /// <summary>
+        /// 获取当前候选池列表
+        /// </summary>
+        /// <returns>候选池学号列表，按学号顺序排列</returns>
+        public List<int> GetCandidatePoolList()
+        {
+            Debug.Assert(_candidatePool != null, nameof(_candidatePool) + " != null");
+            return _candidatePool.OrderBy(n => n).ToList();
+        }
+
+        /// <summary>
+        /// 获取平均抽取次数
+        /// </summary>
+        /// <returns>平均抽取次数</returns>
+        public double GetAverageDrawCount()
+        {
+            var allActiveNumbers = GetActiveNumbers();
+            
+            if (allActiveNumbers.Count == 0) return 0;
+            
+            // 只计算活跃学号的抽取次数
+            double total = 0;
+            foreach (var number in allActiveNumbers)
+            {
+                total += _drawCounts.TryGetValue(number, out var count) ? count : 0;
+            }
+            return total / allActiveNumbers.Count;
+        }
+
+        /// <summary>
+        /// 获取最大抽取次数差距
+        /// </summary>
+        /// <returns>最大差距</returns>
+        public int GetMaxDrawCountGap()
+        {
+            var allActiveNumbers = GetActiveNumbers();
+            
+            if (allActiveNumbers.Count == 0) return 0;
+            
+            int max = int.MinValue;
+            int min = int.MaxValue;
+            foreach (var number in allActiveNumbers)
+            {
+                int count = _drawCounts.TryGetValue(number, out var c) ? c : 0;
+                if (count > max) max = count;
+                if (count < min) min = count;
+            }
+            return max - min;
+        }
+
+        /// <summary>
+        /// 获取活跃学号（不在黑名单中）抽取次数的分位数和平均值
+        /// </summary>
+        public CountQuantiles GetCountQuantiles()
+        {
+            var counts = GetActiveNumbers()
+                .Where(number => !_blacklist.Contains(number))
+                .Select(number => _drawCounts.TryGetValue(number, out var count) ? count : 0)
+                .OrderBy(count => count)
+                .ToList();
+            
+            if (counts.Count == 0) return new CountQuantiles();
+            
+            return new CountQuantiles
+            {
+                Count = counts.Count,
+                P0 = counts[0],
+                P25 = Quantile(counts, 0.25),
+                P50 = Quantile(counts, 0.5),
+                P75 = Quantile(counts, 0.75),
+                P100 = counts[^1],
+                Mean = counts.Average()
+            };
+        }
+
+        /// <summary>
+        /// 获取用于监控的简要指标：总抽取次数、当前轮次、候选池大小、可抽取的学号数，
+        /// 以及不在黑名单中的学号的抽取次数差距、平均值、基尼系数和熵
+        /// </summary>
+        public DrawMetrics GetMetrics()
+        {
+            var counts = GetActiveNumbers()
+                .Where(number => !_blacklist.Contains(number))
+                .Select(number => _drawCounts.TryGetValue(number, out var count) ? count : 0)
+                .OrderBy(count => count)
+                .ToList();
+            
+            var metrics = new DrawMetrics
+            {
+                TotalDraws = _totalDraws,
+                CurrentRound = _currentRound,
+                PoolSize = CandidatePoolCount,
+                EligibleCount = GetDrawableCount()
+            };
+            if (counts.Count == 0) return metrics;
+            
+            long total = 0;
+            double weightedSum = 0;  // Σ (i+1) * c_i，用于计算基尼系数
+            for (int i = 0; i < counts.Count; i++)
+            {
+                total += counts[i];
+                weightedSum += (i + 1.0) * counts[i];
+            }
+            
+            metrics.MaxGap = counts[^1] - counts[0];
+            metrics.Mean = (double)total / counts.Count;
+            if (total > 0)
+            {
+                int n = counts.Count;
+                metrics.Gini = 2 * weightedSum / (n * (double)total) - (n + 1.0) / n;
+                foreach (var count in counts.Where(c => c > 0))
+                {
+                    double p = (double)count / total;
+                    metrics.Entropy -= p * Math.Log2(p);
+                }
+            }
+            
+            return metrics;
+        }
+
+        /// <summary>
+        /// 计算已排序列表的分位数（在相邻的两个值之间线性插值）
+        /// </summary>
+        private static double Quantile(List<int> sorted, double q)
+        {
+            double position = q * (sorted.Count - 1);
+            int lower = (int)Math.Floor(position);
+            int upper = Math.Min(lower + 1, sorted.Count - 1);
+            return sorted[lower] + (position - lower) * (sorted[upper] - sorted[lower]);
+        }
+
+        /// <summary>
+        /// 更新配置参数
+        /// </summary>
+        public void UpdateParameters(int? minPoolSize = null, int? maxGapThreshold = null,
+                                   double? coldStartBoost = null, double? decayFactor = null)
+        {
+            EnsureWritable();
+            
+            if (minPoolSize.HasValue && minPoolSize.Value > 0)
+                _minPoolSize = minPoolSize.Value;
+                
+            if (maxGapThreshold.HasValue && maxGapThreshold.Value >= 0)
+                _maxGapThreshold = maxGapThreshold.Value;
+                
+            if (coldStartBoost.HasValue && coldStartBoost.Value >= 1.0)
+                _coldStartBoost = coldStartBoost.Value;
+                
+            if (decayFactor.HasValue && decayFactor.Value > 0 && decayFactor.Value <= 1.0)
+                _decayFactor = decayFactor.Value;
+                
+            UpdateCandidatePool();
+            WarnIfMinPoolSizeTooLarge();
+        }
+
+        /// <summary>
+        /// 设置随机数种子，使之后的抽取序列可复现
+        /// 种子和已消耗的随机数个数会被保存，重新加载后从同一位置继续
+        /// </summary>
+        /// <param name="seed">随机数种子，为null时恢复为不可复现的随机序列</param>
+        public void SetSeed(int? seed)
+        {
+            EnsureWritable();
+            
+            _seed = seed;
+            _random = seed.HasValue ? new Random(seed.Value) : new Random(Guid.NewGuid().GetHashCode());
+            _rngDrawsConsumed = 0;
+        }
+
+        /// <summary>
+        /// 获取随机数种子
+        /// </summary>
+        public int? GetSeed() => _seed;
+
+        /// <summary>
+        /// 当前抽取序列是否可复现
+        /// </summary>
+        public bool IsDeterministic() => _seed.HasValue;
+
+        /// <summary>
+        /// 设置最小权重（默认0.01）
+        /// 权重按候选池中最大的权重归一化（最大为1），每个候选学号的权重不会低于该值。设置为很小的值会使平衡更激进，
+        /// 冷启动提升和衰减的效果更明显；设置为较大的值会使抽取更接近均匀随机
+        /// </summary>
+        /// <param name="minWeight">最小权重，必须大于0</param>
+        public void SetMinWeight(double minWeight)
+        {
+            EnsureWritable();
+            
+            if (!(minWeight > 0) || double.IsInfinity(minWeight))
+                throw new ArgumentException("最小权重必须是大于0的有限值");
+            
+            _minWeight = minWeight;
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 锁定学号的权重：记录该学号当前的权重，之后计算权重时直接使用记录的值，不再随抽取变化
+        /// 锁定的学号仍然可以被抽中，抽取次数也照常记录。
+        /// 记录的权重是相对于锁定时候选池中最大权重的值，已经应用了最小权重；
+        /// 之后修改最小权重不会影响已锁定的权重，需要重新锁定。
+        /// 预热阶段或关闭平衡时所有学号权重相同，锁定不生效
+        /// </summary>
+        /// <param name="number">学号</param>
+        public void LockNumber(int number)
+        {
+            EnsureWritable();
+            
+            if (!GetActiveNumbers().Contains(number))
+                throw new ArgumentException($"学号{number}不在抽取范围内");
+            
+            // 与候选池中其他未锁定的学号一起归一化
+            var numbers = (_candidatePool ?? new List<int>())
+                .Where(n => !_blacklist.Contains(n) && !_lockedWeights.ContainsKey(n))
+                .Append(number)
+                .Distinct();
+            _lockedWeights[number] = CalculateBalancedWeights(numbers)[number];
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 解除学号的权重锁定
+        /// </summary>
+        /// <param name="number">学号</param>
+        public void UnlockNumber(int number)
+        {
+            EnsureWritable();
+            
+            if (_lockedWeights.Remove(number))
+            {
+                UpdateProbabilities();
+            }
+        }
+
+        /// <summary>
+        /// 获取所有锁定的学号及其锁定的权重
+        /// </summary>
+        public Dictionary<int, double> GetLockedWeights()
+        {
+            return new Dictionary<int, double>(_lockedWeights);
+        }
+
+        /// <summary>
+        /// 设置偏好顺序：权重相同时，顺序中靠前的学号权重略高（如座位号小的优先）
+        /// 加成最多为权重的十亿分之一，不会改变平衡产生的权重差异，只使原本对称的情况有确定的先后；
+        /// 不在顺序中的学号没有加成。偏好顺序会被保存
+        /// </summary>
+        /// <param name="ordering">按偏好从高到低排列的学号，为空时清除偏好顺序</param>
+        public void SetPreferenceOrder(IEnumerable<int> ordering)
+        {
+            EnsureWritable();
+            
+            var order = ordering.ToList();
+            if (order.Distinct().Count() != order.Count)
+                throw new ArgumentException("偏好顺序中不能有重复的学号");
+            
+            _preferenceRanks = ToPreferenceRanks(order);
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取偏好顺序（按偏好从高到低排列），没有设置时返回空列表
+        /// </summary>
+        public List<int> GetPreferenceOrder()
+        {
+            return _preferenceRanks.OrderBy(kvp => kvp.Value).Select(kvp => kvp.Key).ToList();
+        }
+
+        private static Dictionary<int, int> ToPreferenceRanks(List<int> order)
+        {
+            var ranks = new Dictionary<int, int>();
+            foreach (var number in order)
+            {
+                ranks.TryAdd(number, ranks.Count);
+            }
+            return ranks;
+        }
+
+        /// <summary>
+        /// 按偏好顺序给权重加上很小的加成：第一个学号乘以 1 + PreferenceBonus，之后依次递减，最后一个接近1
+        /// </summary>
+        private void ApplyPreferenceBonus(Dictionary<int, double> weights)
+        {
+            if (_preferenceRanks.Count == 0) return;
+            
+            foreach (var number in weights.Keys.ToList())
+            {
+                if (_preferenceRanks.TryGetValue(number, out var rank))
+                {
+                    weights[number] *= 1 + PreferenceBonus * (_preferenceRanks.Count - rank) / _preferenceRanks.Count;
+                }
+            }
+        }
+
+        /// <summary>
+        /// 设置最小间隔：某个学号被抽中后，至少还要再抽取 spacing 次其他学号，才能再次抽到它
+        /// 间隔按实际发生的抽取次数计算；如果排除后候选池小于最小候选池大小，
+        /// 会按抽取次数从少到多放宽限制
+        /// </summary>
+        /// <param name="spacing">最小间隔，0表示不限制</param>
+        public void SetMinSpacing(int spacing)
+        {
+            EnsureWritable();
+            
+            if (spacing < 0)
+                throw new ArgumentException("最小间隔不能小于0");
+            
+            _minSpacing = spacing;
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 设置是否禁止连续两次抽中同一学号
+        /// 开启后上一次被抽中的学号不会出现在下一次的候选池中，除非候选池中只剩下它
+        /// </summary>
+        public void SetNoRepeat(bool enabled)
+        {
+            EnsureWritable();
+            
+            _noRepeat = enabled;
+            UpdateCandidatePool();
+        }
+
+        /// <summary>
+        /// 是否禁止连续两次抽中同一学号
+        /// </summary>
+        public bool GetNoRepeat() => _noRepeat;
+
+        /// <summary>
+        /// 设置轮流模式：所有可抽取的学号按打乱后的顺序各被抽中一次后，再重新打乱开始下一轮，
+        /// 不使用任何权重。剩余的队列会被保存，重新加载后继续当前一轮
+        /// </summary>
+        public void SetRoundRobin(bool enabled)
+        {
+            EnsureWritable();
+            
+            _roundRobin = enabled;
+            _roundRobinQueue.Clear();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 是否处于轮流模式
+        /// </summary>
+        public bool GetRoundRobin() => _roundRobin;
+
+        /// <summary>
+        /// 获取轮流模式中本轮剩余的学号（按抽取顺序）
+        /// </summary>
+        public List<int> GetRoundRobinQueue()
+        {
+            return new List<int>(_roundRobinQueue);
+        }
+
+        /// <summary>
+        /// 设置每个学号持有的彩票数量（用于抽奖模式），会被保存
+        /// 没有设置或数量为0的学号在抽奖模式下不会被抽中
+        /// </summary>
+        /// <param name="tickets">学号 -> 彩票数量，传入空字典清除</param>
+        public void SetTickets(Dictionary<int, int> tickets)
+        {
+            EnsureWritable();
+            
+            if (tickets.Values.Any(t => t < 0))
+                throw new ArgumentException("彩票数量不能小于0");
+            if (tickets.Count > 0 && tickets.Values.All(t => t == 0))
+                throw new ArgumentException("至少需要一个学号持有彩票");
+            
+            _tickets = tickets.Where(kvp => kvp.Value > 0).ToDictionary(kvp => kvp.Key, kvp => kvp.Value);
+            UpdateCandidatePool();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取每个学号持有的彩票数量（不包括数量为0的学号）
+        /// </summary>
+        public Dictionary<int, int> GetTickets()
+        {
+            return new Dictionary<int, int>(_tickets);
+        }
+
+        /// <summary>
+        /// 设置抽奖模式：被抽中的概率与持有的彩票数量成正比，不再按抽取次数平衡，也不按平均值过滤候选池。
+        /// 没有彩票的学号不会被抽中；禁止连续重复、最小间隔、分组上限等限制和抽取记录仍然照常生效。
+        /// 轮流模式优先于抽奖模式
+        /// </summary>
+        /// <param name="enabled">是否开启抽奖模式</param>
+        /// <param name="applyDecay">是否对已中奖的学号应用权重衰减（每中奖一次权重乘以衰减因子）</param>
+        public void SetLotteryMode(bool enabled, bool applyDecay = false)
+        {
+            EnsureWritable();
+            
+            if (enabled && _tickets.Count == 0)
+            {
+                Debug.WriteLine($"开启了抽奖模式但没有学号持有彩票，设置彩票前无法抽取: {_dataId}");
+            }
+            
+            _lotteryMode = enabled;
+            _lotteryDecay = applyDecay;
+            UpdateCandidatePool();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 是否处于抽奖模式
+        /// </summary>
+        public bool GetLotteryMode() => _lotteryMode;
+
+        /// <summary>
+        /// 抽奖模式下是否对已中奖的学号应用权重衰减
+        /// </summary>
+        public bool GetLotteryDecay() => _lotteryDecay;
+
+        /// <summary>
+        /// 抽取多个中奖者（通常在抽奖模式下使用）
+        /// </summary>
+        /// <param name="n">中奖者数量</param>
+        /// <param name="unique">true: 同一学号最多中奖一次（同 DrawMultiple）；false: 每次抽取后放回，同一学号可以多次中奖</param>
+        /// <param name="autoSave">是否在全部抽取后保存数据（默认true）</param>
+        /// <returns>按抽取顺序排列的中奖学号</returns>
+        public List<int> DrawWinners(int n, bool unique, bool autoSave = true)
+        {
+            return unique ? DrawMultiple(n, autoSave) : DrawWithReplacement(n, autoSave);
+        }
+
+        /// <summary>
+        /// 抽奖模式下学号的权重：彩票数量，开启衰减时再乘以 衰减因子^中奖次数
+        /// </summary>
+        private double CalculateLotteryWeight(int number)
+        {
+            double weight = _tickets.TryGetValue(number, out var tickets) ? tickets : 0;
+            if (_lotteryDecay)
+            {
+                weight *= Math.Pow(_decayFactor, _drawCounts.TryGetValue(number, out var count) ? count : 0);
+            }
+            return weight;
+        }
+
+        /// <summary>
+        /// 设置目标抽取比例，长期来看每个学号被抽中的次数会趋向于 目标比例 × 总抽取次数
+        /// 比例为相对值（如 2 表示是未设置学号的两倍），未设置的学号为1，内部会归一化；
+        /// 传入空字典恢复为平均分布
+        /// </summary>
+        /// <param name="shares">学号 -> 目标比例（必须大于0）</param>
+        public void SetTargetShares(Dictionary<int, double> shares)
+        {
+            EnsureWritable();
+            
+            if (shares.Any(kvp => !(kvp.Value > 0) || !double.IsFinite(kvp.Value)))
+                throw new ArgumentException("目标比例必须是大于0的有限值");
+            
+            _targetShares = new Dictionary<int, double>(shares);
+            _targetShareTotal = null;
+            UpdateCandidatePool();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取归一化后的目标抽取比例（按学号顺序，所有活跃学号之和为1）
+        /// </summary>
+        public Dictionary<int, double> GetTargetShares()
+        {
+            return GetActiveNumbers().ToDictionary(n => n, GetTargetShare);
+        }
+
+        /// <summary>
+        /// 获取每个学号实际抽取次数与目标次数（目标比例 × 总抽取次数）的差，正数表示抽取偏多
+        /// 没有设置目标比例时目标次数为平均值
+        /// </summary>
+        public Dictionary<int, double> GetTargetDeviations()
+        {
+            return GetActiveNumbers().ToDictionary(
+                n => n,
+                n => (_drawCounts.TryGetValue(n, out var count) ? count : 0) - GetTargetShare(n) * _totalDraws);
+        }
+
+        /// <summary>
+        /// 设置自定义的候选过滤条件（如根据出勤情况，或"今天不抽双号"这类规则），返回false的学号不会进入候选池
+        /// 与黑名单一起在最小候选池补充之前应用，每次更新候选池时都会重新调用；被过滤的学号不会因为候选池过小而被补充回来，
+        /// 候选池会改为从满足条件的学号中补充；
+        /// DrawFromSubset、DrawMultipleWith 等限制候选学号的抽取也会应用过滤条件。
+        /// 过滤条件不会被保存，程序重新启动或重新创建实例后需要重新设置。
+        /// 对所有学号都返回false时候选池为空，Draw 会按 SetEmptyPoolPolicy 设置的方式处理。
+        /// 过滤条件依赖的外部数据变化后，可再次调用本方法立即重新计算候选池
+        /// </summary>
+        /// <param name="filter">过滤条件，为null时移除</param>
+        public void SetCandidateFilter(Func<int, bool>? filter)
+        {
+            EnsureWritable();
+            
+            _candidateFilter = filter;
+            UpdateCandidatePool();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 设置审计日志文件：之后每次抽取都会向该文件追加一行JSON {"id", "round", "number", "timestamp"}
+        /// 与抽取历史不同，审计日志只追加、从不整体重写，也不受 ClearHistory、ResetDrawCounts 等影响，用于外部核对。
+        /// 追加失败时抽取会抛出异常（本次抽取已计入内存中的记录，但不会自动保存）。
+        /// DrawSilent 不写审计日志，Clone 等复制出的实例也不继承该设置。该设置不保存
+        /// </summary>
+        /// <param name="path">日志文件路径，为null时停止记录</param>
+        public void SetAuditLog(string? path)
+        {
+            EnsureWritable();
+            
+            _auditLogPath = path;
+        }
+
+        /// <summary>
+        /// 获取审计日志文件路径，未设置时为null
+        /// </summary>
+        public string? GetAuditLog() => _auditLogPath;
+
+        /// <summary>
+        /// 向审计日志追加一条抽取记录，失败时抛出异常
+        /// </summary>
+        private void AppendAuditLog(DrawRecord record)
+        {
+            if (_auditLogPath == null) return;
+            
+            string line = JsonSerializer.Serialize(new
+            {
+                id = _dataId,
+                round = record.Round,
+                number = record.Number,
+                timestamp = record.Timestamp
+            });
+            File.AppendAllText(_auditLogPath, line + "\n");
+        }
+
+        /// <summary>
+        /// 设置是否启用平衡权重
+        /// 关闭后候选池中的学号权重相同（等同于均匀随机），但抽取次数仍会被记录，重新启用后会根据历史继续平衡
+        /// </summary>
+        public void SetBalancingEnabled(bool enabled)
+        {
+            EnsureWritable();
+            
+            _balancingEnabled = enabled;
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 是否启用平衡权重
+        /// </summary>
+        public bool GetBalancingEnabled() => _balancingEnabled;
+
+        /// <summary>
+        /// 设置预热轮次：当前轮次不超过该值时使用相同的权重，之后才开始按抽取记录平衡
+        /// </summary>
+        /// <param name="rounds">预热轮次，0表示不预热</param>
+        public void SetWarmupRounds(int rounds)
+        {
+            EnsureWritable();
+            
+            if (rounds < 0)
+                throw new ArgumentException("预热轮次不能小于0");
+            
+            _warmupRounds = rounds;
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取预热轮次
+        /// </summary>
+        public int GetWarmupRounds() => _warmupRounds;
+
+        /// <summary>
+        /// 设置长期未被抽中的学号的权重提升（阈值、系数和曲线），用于调整系统补偿长期未抽中学号的力度
+        /// </summary>
+        public void SetGapBoost(GapBoost gapBoost)
+        {
+            EnsureWritable();
+            
+            if (!(gapBoost.ThresholdFraction >= 0) || double.IsInfinity(gapBoost.ThresholdFraction))
+                throw new ArgumentException("阈值必须是不小于0的有限值");
+            if (!(gapBoost.Scale >= 0) || double.IsInfinity(gapBoost.Scale))
+                throw new ArgumentException("提升系数必须是不小于0的有限值");
+            if (!Enum.IsDefined(gapBoost.Curve))
+                throw new ArgumentException($"未知的提升曲线: {gapBoost.Curve}");
+            
+            _gapBoost = gapBoost.Clone();
+            UpdateProbabilities();
+        }
+
+        /// <summary>
+        /// 获取长期未被抽中的学号的权重提升设置
+        /// </summary>
+        public GapBoost GetGapBoost() => _gapBoost.Clone();
+
+        /// <summary>
+        /// 设置自适应温度：权重会取温度次幂，温度为0时接近均匀随机，为1时为正常的平衡抽取，
+        /// 大于1时平衡更严格。温度在之后的 overDraws 次抽取中从 start 线性过渡到 end，之后保持 end
+        /// 参数和进度会被保存
+        /// </summary>
+        /// <param name="start">起始温度</param>
+        /// <param name="end">最终温度</param>
+        /// <param name="overDraws">过渡所需的抽取次数</param>
+        public void SetAdaptiveTemperature(double start, double end, int overDraws)
+        {
+            EnsureWritable();
+            
+            if (!(start >= 0) || double.IsInfinity(start) || !(end >= 0) || double.IsInfinity(end))
+                throw new ArgumentException("温度必须是不小于0的有限值");
+            if (overDraws < 0)
+                throw new ArgumentException("过渡抽取次数不能小于0");
+            
+            _adaptiveTemperature = true;
+            _temperatureStart = start;
+            _temperatureEnd = end;
+            _temperatureOverDraws = overDraws;
+            _temperatureProgress = 0;
+        }
+
+        /// <summary>
+        /// 取消自适应温度
+        /// </summary>
+        public void ClearAdaptiveTemperature()
+        {
+            EnsureWritable();
+            
+            _adaptiveTemperature = false;
+            _temperatureProgress = 0;
+        }
+
+        /// <summary>
+        /// 获取当前的有效温度（未设置自适应温度时为1）
+        /// </summary>
+        public double GetEffectiveTemperature()
+        {
+            if (!_adaptiveTemperature) return 1.0;
+            if (_temperatureProgress >= _temperatureOverDraws) return _temperatureEnd;
+            
+            double progress = (double)_temperatureProgress / _temperatureOverDraws;
+            return _temperatureStart + (_temperatureEnd - _temperatureStart) * progress;
+        }
+
+        /// <summary>
+        /// 当前候选池大小（供性能测试使用）
+        /// </summary>
+        internal int CandidatePoolCount => _candidatePool?.Count ?? 0;
+
+        /// <summary>
+        /// 重新计算候选池（供子类修改抽取限制后及性能测试使用）
+        /// </summary>
+        protected internal void RefreshCandidatePool() => UpdateCandidatePool();
+
+        /// <summary>
+        /// 获取学号的抽取次数（不存在的学号返回0）
+        /// </summary>
+        protected int GetDrawCountOf(int number) => _drawCounts.TryGetValue(number, out var count) ? count : 0;
+
+        /// <summary>
+        /// 只从指定的学号中抽取一次，其余与 Draw 相同（仍按抽取次数平衡权重并正常更新抽取记录）
+        /// 黑名单中、所在分组已达到上限、今天已达到每日上限和被 SetCandidateFilter 过滤的学号会被排除；不能在批量模式或轮流模式下使用
+        /// </summary>
+        /// <param name="numbers">允许抽取的学号</param>
+        /// <param name="autoSave">是否自动保存数据</param>
+        /// <exception cref="InvalidOperationException">排除后没有可抽取的学号</exception>
+        protected int DrawRestricted(IEnumerable<int> numbers, bool autoSave)
+        {
+            EnsureWritable();
+            
+            if (_batchDrawn != null)
+                throw new InvalidOperationException("批量抽取时不能限制候选学号");
+            if (_roundRobin)
+                throw new InvalidOperationException("轮流模式下不能限制候选学号");
+            
+            var pool = numbers
+                .Distinct()
+                .Where(n => !_blacklist.Contains(n) && !IsGroupQuotaReached(n) && !IsDailyLimitReached(n) &&
+                            (_candidateFilter == null || _candidateFilter(n)))
+                .ToList();
+            if (pool.Count == 0)
+                throw new InvalidOperationException("指定的学号中没有可抽取的学号");
+            
+            // 临时替换候选池，抽取后 DrawCore 会重新计算正常的候选池
+            _candidatePool = pool;
+            _candidatePoolDate = ToLocalDate(_clock());
+            try
+            {
+                return DrawCore(autoSave, null);
+            }
+            catch
+            {
+                UpdateCandidatePool();
+                throw;
+            }
+        }
+
+        /// <summary>
+        /// 获取上一次被抽中的学号（重置后或从未抽取时返回null）
+        /// </summary>
+        protected int? GetLastDrawnNumber()
+        {
+            if (_currentRound <= 0) return null;
+            
+            foreach (var kvp in _lastDrawRound)
+            {
+                if (kvp.Value == _currentRound)
+                    return kvp.Key;
+            }
+            return null;
+        }
+
+        /// <summary>
+        /// 设置的最小候选池大小超过可抽取的学号数时输出警告（这通常是配置错误，如黑名单过大）
+        /// </summary>
+        protected void WarnIfMinPoolSizeTooLarge()
+        {
+            int drawable = GetDrawableCount();
+            if (_minPoolSize > drawable)
+            {
+                Debug.WriteLine($"最小候选池大小{_minPoolSize}大于可抽取的学号数{drawable}，实际按{drawable}计算: {_dataId}");
+            }
+        }
+
+        /// <summary>
+        /// 子类额外排除的候选学号，在候选池计算的最后应用；排除后候选池为空时不排除
+        /// </summary>
+        protected virtual ISet<int>? GetExcludedCandidates() => null;
+
+        #region 私有方法
+
+        /// <summary>
+        /// 获取所有活跃学号（原始学号加上白名单中的额外学号，按学号排序）
+        /// 结果会被缓存，白名单或成员变化时需调用 InvalidateActiveNumbers
+        /// </summary>
+        private IReadOnlyList<int> GetActiveNumbers()
+        {
+            if (_activeNumbers == null)
+            {
+                _activeNumbers = _allNumbers
+                    .Concat(_whitelist.Where(n => !_allNumbersSet.Contains(n)))
+                    .OrderBy(n => n)
+                    .ToList();
+            }
+            return _activeNumbers;
+        }
+
+        /// <summary>
+        /// 移除既不在原始学号中也不在白名单中的学号的抽取记录（被移出白名单的额外学号）
+        /// </summary>
+        private void RemoveOrphanedRecords()
+        {
+            var orphaned = _drawCounts.Keys
+                .Concat(_lastDrawRound.Keys)
+                .Where(n => !_allNumbersSet.Contains(n) && !_whitelist.Contains(n))
+                .Distinct()
+                .ToList();
+            
+            foreach (var number in orphaned)
+            {
+                _drawCounts.Remove(number);
+                _lastDrawRound.Remove(number);
+                _currentProbabilities.Remove(number);
+            }
+        }
+
+        /// <summary>
+        /// 使活跃学号缓存失效
+        /// </summary>
+        private void InvalidateActiveNumbers()
+        {
+            _activeNumbers = null;
+            _targetShareTotal = null;
+        }
+
+        /// <summary>
+        /// 获取学号归一化后的目标比例（所有活跃学号之和为1）
+        /// </summary>
+        private double GetTargetShare(int number)
+        {
+            var activeNumbers = GetActiveNumbers();
+            if (_targetShares.Count == 0)
+                return 1.0 / activeNumbers.Count;
+            
+            _targetShareTotal ??= activeNumbers.Sum(n => _targetShares.TryGetValue(n, out var s) ? s : 1.0);
+            double share = _targetShares.TryGetValue(number, out var value) ? value : 1.0;
+            return share / _targetShareTotal.Value;
+        }
+
+        /// <summary>
+        /// 获取学号的等效抽取次数
+        /// 没有设置目标比例时就是抽取次数；设置后按目标比例换算为平均分布下的次数，
+        /// 如目标比例为平均值两倍的学号被抽中4次，等效次数为2
+        /// </summary>
+        private double GetEffectiveCount(int number)
+        {
+            int count = _drawCounts.TryGetValue(number, out var c) ? c : 0;
+            if (_targetShares.Count == 0)
+                return count;
+            
+            return count / (GetTargetShare(number) * GetActiveNumbers().Count);
+        }
+
+        /// <summary>
+        /// 获取活跃学号等效抽取次数的最大差距
+        /// </summary>
+        private double GetEffectiveCountGap()
+        {
+            if (_targetShares.Count == 0)
+                return GetMaxDrawCountGap();
+            
+            var activeNumbers = GetActiveNumbers();
+            if (activeNumbers.Count == 0) return 0;
+            
+            return activeNumbers.Max(GetEffectiveCount) - activeNumbers.Min(GetEffectiveCount);
+        }
+
+        /// <summary>
+        /// 更新候选池
+        /// </summary>
+        private void UpdateCandidatePool()
+        {
+            List<int> candidates;
+            double? excludedMaxCount = null;  // 最大差距保护排除的最大抽取次数（补充候选池时也不加入）
+            
+            if (_whitelistOnlyMode)
+            {
+                // 白名单模式：只从白名单中抽取
+                candidates = _whitelist.ToList();
+            }
+            else if (_lotteryMode)
+            {
+                // 抽奖模式：概率只由彩票数量决定，不按抽取次数过滤（白名单中的额外学号也可以持有彩票）
+                candidates = _allNumbers.Concat(_whitelist.Where(n => !_allNumbersSet.Contains(n))).ToList();
+            }
+            else
+            {
+                // 正常模式：从原始学号范围中筛选
+                if (_targetShares.Count > 0)
+                {
+                    // 目标比例过滤 - 只选择抽取次数≤自己的目标次数（目标比例 × 总抽取次数）的成员
+                    candidates = new List<int>(_allNumbers.Count);
+                    foreach (var n in _allNumbers)
+                    {
+                        if (_drawCounts[n] <= Math.Ceiling(GetTargetShare(n) * _totalDraws))
+                            candidates.Add(n);
+                    }
+                }
+                else
+                {
+                    // 计算平均抽取次数
+                    double average = GetAverageDrawCount();
+                    
+                    // 平均值过滤 - 只选择抽取次数≤平均值的成员（按学号数量预先分配，避免大量学号时反复扩容）
+                    double limit = Math.Ceiling(average); // 向上取整，增加容错
+                    candidates = new List<int>(_allNumbers.Count);
+                    foreach (var n in _allNumbers)
+                    {
+                        if (_drawCounts[n] <= limit)
+                            candidates.Add(n);
+                    }
+                }
+                
+                // 最大差距保护（设置了目标比例时按等效次数计算）
+                if (GetEffectiveCountGap() > _maxGapThreshold)
+                {
+                    // 排除极值并重新计算
+                    double maxCount = _drawCounts.Keys.Max(GetEffectiveCount);
+                    double minCount = _drawCounts.Keys.Min(GetEffectiveCount);
+                    
+                    // 排除抽取次数最多和最少的成员
+                    var filtered = candidates
+                        .Where(n => GetEffectiveCount(n) != maxCount && GetEffectiveCount(n) != minCount)
+                        .ToList();
+                    
+                    // 排除极值后没有可抽取的成员时（如只剩一个不在黑名单中的成员），保留原来的候选
+                    if (filtered.Any(n => !_blacklist.Contains(n)))
+                    {
+                        // 重新计算排除极值后的平均值
+                        double newAverage = filtered.Average(GetEffectiveCount);
+                        candidates = filtered
+                            .Where(n => GetEffectiveCount(n) <= Math.Ceiling(newAverage))
+                            .ToList();
+                        excludedMaxCount = maxCount;
+                    }
+                }
+                
+                // 加入白名单中的额外学号
+                var candidateSet = new HashSet<int>(candidates);
+                foreach (var number in _whitelist)
+                {
+                    if (candidateSet.Add(number))
+                    {
+                        candidates.Add(number);
+                    }
+                }
+            }
+            
+            // 移除黑名单中的学号和不满足自定义过滤条件的学号（补充候选池时也不会加入）
+            candidates = candidates
+                .Where(n => !_blacklist.Contains(n) && (_candidateFilter == null || _candidateFilter(n)))
+                .ToList();
+            
+            // 最小间隔：移除最近刚被抽中过的学号（候选池不足时会在下面补充）
+            if (_minSpacing > 0)
+            {
+                candidates = candidates
+                    .Where(n => !_lastDrawRound.TryGetValue(n, out var round) || round < 0 ||
+                                _currentRound - round >= _minSpacing)
+                    .ToList();
+            }
+            
+            // 分组配额：移除所在分组已达到抽取上限的学号（硬性限制，补充时也不会加入）
+            if (_groupQuotas.Count > 0)
+            {
+                candidates = candidates
+                    .Where(n => !IsGroupQuotaReached(n))
+                    .ToList();
+            }
+            
+            // 每日上限：移除今天已达到上限的学号（硬性限制，补充时也不会加入）
+            if (_dailyLimit.HasValue)
+            {
+                candidates = candidates
+                    .Where(n => !IsDailyLimitReached(n))
+                    .ToList();
+                _candidatePoolDate = ToLocalDate(_clock());
+            }
+            
+            // 候选池大小
+            int minPoolSize = GetEffectiveMinPoolSize();
+            if (candidates.Count < minPoolSize)
+            {
+                // 如果候选池太小，添加一些抽取次数较低的成员
+                var candidateSet = new HashSet<int>(candidates);
+                var allAvailableNumbers = _allNumbers
+                    .Concat(_whitelist.Where(n => !_allNumbersSet.Contains(n)))
+                    .Where(n => !_blacklist.Contains(n) && !candidateSet.Contains(n) && !IsGroupQuotaReached(n) && 
+                                !IsDailyLimitReached(n) && GetEffectiveCount(n) != excludedMaxCount &&
+                                (_candidateFilter == null || _candidateFilter(n)))
+                    .ToList();
+                
+                var allSorted = allAvailableNumbers
+                    .OrderBy(GetEffectiveCount)
+                    .ThenBy(n => _lastDrawRound.TryGetValue(n, out var round) ? round : int.MaxValue) // 长期未抽中的优先
+                    .ToList();
+                    
+                int needed = minPoolSize - candidates.Count;
+                foreach (var number in allSorted)
+                {
+                    if (needed <= 0) break;
+                    if (candidateSet.Add(number))
+                    {
+                        candidates.Add(number);
+                        needed--;
+                    }
+                }
+            }
+            
+            // 禁止连续重复：移除上一次被抽中的学号（候选池中只剩它时保留）
+            if (_noRepeat && _currentRound > 0 && candidates.Count > 1)
+            {
+                candidates.RemoveAll(n => _lastDrawRound.TryGetValue(n, out var round) && round == _currentRound);
+            }
+            
+            // 子类的额外限制
+            var excluded = GetExcludedCandidates();
+            if (excluded != null && excluded.Count > 0)
+            {
+                var remaining = candidates.Where(n => !excluded.Contains(n)).ToList();
+                if (remaining.Count > 0)
+                {
+                    candidates = remaining;
+                }
+            }
+            
+            // 抽奖模式：没有彩票的学号不能被抽中
+            if (_lotteryMode)
+            {
+                candidates.RemoveAll(n => !_tickets.ContainsKey(n));
+            }
+            
+            _candidatePool = candidates;
+        }
+
+        /// <summary>
+        /// 计算权重
+        /// </summary>
+        private Dictionary<int, double> CalculateWeights()
+        {
+            var weights = new Dictionary<int, double>();
+            
+            // 抽奖模式：权重为彩票数量
+            if (_lotteryMode)
+            {
+                if (_candidatePool != null)
+                    foreach (var number in _candidatePool.Where(n => !_blacklist.Contains(n)))
+                    {
+                        weights[number] = CalculateLotteryWeight(number);
+                    }
+                return weights;
+            }
+            
+            // 未启用平衡或处于预热阶段时，候选池中的学号权重相同
+            if (!_balancingEnabled || _currentRound <= _warmupRounds)
+            {
+                if (_candidatePool != null)
+                    foreach (var number in _candidatePool.Where(n => !_blacklist.Contains(n)))
+                    {
+                        weights[number] = 1.0;
+                    }
+                ApplyPreferenceBonus(weights);
+                return weights;
+            }
+
+            if (_candidatePool == null)
+                return weights;
+            
+            // 黑名单中的学号不应该出现在候选池中，但这里再次检查
+            var candidates = _candidatePool.Where(n => !_blacklist.Contains(n)).ToList();
+            
+            weights = CalculateBalancedWeights(candidates.Where(n => !_lockedWeights.ContainsKey(n)));
+            
+            // 锁定的学号使用锁定时的权重
+            foreach (var number in candidates.Where(_lockedWeights.ContainsKey))
+            {
+                weights[number] = _lockedWeights[number];
+            }
+            
+            // 总权重不是正的有限值时无法归一化为概率（会得到NaN），改为相同权重
+            double totalWeight = weights.Values.Sum();
+            if (weights.Count > 0 && !(totalWeight > 0 && double.IsFinite(totalWeight)))
+            {
+                Debug.WriteLine($"总权重{totalWeight}无效，候选池中的学号改为使用相同权重: {_dataId}");
+                foreach (var number in weights.Keys.ToList())
+                {
+                    weights[number] = 1.0;
+                }
+            }
+            
+            ApplyPreferenceBonus(weights);
+
+            return weights;
+        }
+
+        /// <summary>
+        /// 计算平衡权重
+        /// 在对数空间中计算，再除以其中最大的权重（最大的权重为1），最后应用最小权重。
+        /// 直接相乘时衰减项在抽取次数很大时会下溢为0，并且很快低于最小权重，使抽取次数不同的学号权重相同；
+        /// 按最大权重归一化后，只有与最大权重相差悬殊的学号才会被最小权重限制
+        /// </summary>
+        private Dictionary<int, double> CalculateBalancedWeights(IEnumerable<int> numbers)
+        {
+            var activeNumbersCount = GetActiveNumbers().Count;
+            double temperature = GetEffectiveTemperature();
+            
+            var logWeights = numbers.ToDictionary(n => n, n => CalculateLogWeight(n, activeNumbersCount, temperature));
+            if (logWeights.Count == 0)
+                return new Dictionary<int, double>();
+            
+            double maxLogWeight = logWeights.Values.Max();
+            return logWeights.ToDictionary(
+                kvp => kvp.Key,
+                kvp => Math.Max(Math.Exp(kvp.Value - maxLogWeight), _minWeight)); // 保证最小权重
+        }
+
+        /// <summary>
+        /// 计算单个学号权重的自然对数（未归一化，未应用最小权重）
+        /// </summary>
+        private double CalculateLogWeight(int number, int activeNumbersCount, double temperature)
+        {
+            double logWeight = 0.0;
+
+            // 获取抽取次数（白名单中的学号可能没有记录），设置了目标比例时使用等效次数
+            double drawCount = GetEffectiveCount(number);
+            
+            // 避免重复抽取
+            logWeight += drawCount * Math.Log(_decayFactor);
+
+            // 长期未被抽中的成员权重提升
+            int lastRound = _lastDrawRound.TryGetValue(number, out var round) ? round : -1;
+            
+            if (lastRound < 0) // 从未被抽中
+            {
+                logWeight += Math.Log(_coldStartBoost);
+            }
+            else
+            {
+                int roundsSinceLastDraw = _currentRound - lastRound;
+                logWeight += Math.Log(_gapBoost.GetMultiplier(roundsSinceLastDraw, activeNumbersCount)); // 超过阈值轮次未抽中时提升
+            }
+
+            // 3. 抽取次数倒数权重（抽取越多，权重越低）
+            logWeight -= Math.Log(drawCount + 1);
+
+            // 4. 白名单权重提升（如果是白名单中的额外学号）
+            // 与普通冷启动一致，只在从未被抽中时生效；一旦被抽中，
+            // 额外学号与普通成员按相同规则计算权重，最终收敛到公平水平
+            if (lastRound < 0 && !_allNumbersSet.Contains(number) && _whitelist.Contains(number))
+            {
+                logWeight += Math.Log(_coldStartBoost); // 白名单学号享受冷启动提升
+            }
+
+            // 5. 自适应温度
+            return logWeight * temperature;
+        }
+
+        /// <summary>
+        /// 根据权重进行随机选择
+        /// </summary>
+        private int WeightedRandomSelect(Dictionary<int, double> weights)
+        {
+            if (!weights.Any())
+                throw new InvalidOperationException("权重字典为空");
+                
+            // 计算总权重
+            double totalWeight = weights.Values.Sum();
+            
+            // 生成随机数
+            double randomValue = NextRandomDouble() * totalWeight;
+            
+            // 根据权重选择
+            double cumulative = 0;
+            foreach (var kvp in weights)
+            {
+                cumulative += kvp.Value;
+                if (randomValue <= cumulative)
+                {
+                    return kvp.Key;
+                }
+            }
+            
+            // 如果由于浮点精度问题未选择，返回最后一个
+            return weights.Keys.Last();
+        }
+
+        /// <summary>
+        /// 记录一次抽取结果
+        /// </summary>
+        private void RecordDraw(int selectedNumber, double probability)
+        {
+            if (_drawCounts.ContainsKey(selectedNumber))
+            {
+                _drawCounts[selectedNumber]++;
+            }
+            else
+            {
+                // 如果是白名单中的额外学号，需要初始化
+                _drawCounts[selectedNumber] = 1;
+            }
+            
+            _lastDrawRound[selectedNumber] = _currentRound;
+            _totalDraws++;
+            _history.Add(new DrawRecord
+            {
+                Round = _currentRound,
+                Number = selectedNumber,
+                Probability = probability,
+                Timestamp = _clock()
+            });
+            
+            if (_adaptiveTemperature)
+            {
+                _temperatureProgress++;
+            }
+            
+            if (_groups.TryGetValue(selectedNumber, out var groupId))
+            {
+                _groupDrawCounts[groupId] = _groupDrawCounts.TryGetValue(groupId, out var groupCount) ? groupCount + 1 : 1;
+            }
+            
+            var today = ToLocalDate(_history[^1].Timestamp);
+            _dailyCounts[selectedNumber] = new DailyCount
+            {
+                Date = today,
+                Count = GetDrawnToday(selectedNumber, today) + 1
+            };
+            
+            if (!_silentDraw)
+            {
+                AppendAuditLog(_history[^1]);
+            }
+        }
+
+        /// <summary>
+        /// 轮流模式中可以被抽取的学号
+        /// </summary>
+        private HashSet<int> GetRoundRobinEligible()
+        {
+            IEnumerable<int> numbers = _whitelistOnlyMode ? _whitelist : GetActiveNumbers();
+            return numbers
+                .Where(n => !_blacklist.Contains(n) && !IsGroupQuotaReached(n) && !IsDailyLimitReached(n))
+                .Where(n => _candidateFilter == null || _candidateFilter(n))
+                .ToHashSet();
+        }
+
+        /// <summary>
+        /// 随机打乱列表（Fisher-Yates）
+        /// </summary>
+        private void Shuffle(List<int> list)
+        {
+            for (int i = list.Count - 1; i > 0; i--)
+            {
+                int j = (int)(NextRandomDouble() * (i + 1));
+                if (j > i) j = i;
+                (list[i], list[j]) = (list[j], list[i]);
+            }
+        }
+
+        /// <summary>
+        /// 可抽取的学号数（不在黑名单中；白名单模式下只计算白名单中的学号）
+        /// </summary>
+        private int GetDrawableCount()
+        {
+            IEnumerable<int> numbers = _whitelistOnlyMode ? _whitelist : GetActiveNumbers();
+            return numbers.Count(n => !_blacklist.Contains(n));
+        }
+
+        /// <summary>
+        /// 学号所在的分组是否已达到本次会话的抽取上限
+        /// </summary>
+        private bool IsGroupQuotaReached(int number)
+        {
+            return _groups.TryGetValue(number, out var groupId) &&
+                   _groupQuotas.TryGetValue(groupId, out var quota) &&
+                   (_groupDrawCounts.TryGetValue(groupId, out var drawn) ? drawn : 0) >= quota;
+        }
+
+        /// <summary>
+        /// 获取学号在指定日期的抽取次数，记录的日期不是该日期时为0
+        /// </summary>
+        private int GetDrawnToday(int number, DateOnly today)
+        {
+            return _dailyCounts.TryGetValue(number, out var daily) && daily.Date == today ? daily.Count : 0;
+        }
+
+        /// <summary>
+        /// 学号今天是否已达到每日抽取上限
+        /// </summary>
+        private bool IsDailyLimitReached(int number)
+        {
+            return _dailyLimit.HasValue && GetDrawnToday(number, ToLocalDate(_clock())) >= _dailyLimit.Value;
+        }
+
+        /// <summary>
+        /// 处于白名单模式但白名单为空时抛出 WhitelistOnlyButEmptyException，避免重置抽取次数后仍然无法抽取
+        /// </summary>
+        private void ThrowIfWhitelistOnlyButEmpty()
+        {
+            if (_whitelistOnlyMode && _whitelist.Count == 0)
+                throw new WhitelistOnlyButEmptyException();
+        }
+
+        /// <summary>
+        /// 设置了每日上限且所有可抽取的学号今天都已达到上限时抛出 DailyLimitReachedException
+        /// </summary>
+        private void ThrowIfDailyLimitReached()
+        {
+            if (!_dailyLimit.HasValue) return;
+            
+            IEnumerable<int> numbers = _whitelistOnlyMode ? _whitelist : GetActiveNumbers();
+            var eligible = numbers
+                .Where(n => !_blacklist.Contains(n) && !IsGroupQuotaReached(n))
+                .ToList();
+            if (eligible.Count > 0 && eligible.All(IsDailyLimitReached))
+                throw new DailyLimitReachedException(_dailyLimit.Value);
+        }
+
+        /// <summary>
+        /// 所有可抽取的学号所在的分组都已达到本次会话的抽取上限时抛出 InvalidOperationException
+        /// 重置抽取次数不会清空分组抽取次数，因此在重置之前抛出，避免白白完成一个抽取周期
+        /// </summary>
+        private void ThrowIfGroupQuotasExhausted()
+        {
+            if (_groupQuotas.Count == 0) return;
+            
+            IEnumerable<int> numbers = _whitelistOnlyMode ? _whitelist : GetActiveNumbers();
+            var eligible = numbers.Where(n => !_blacklist.Contains(n)).ToList();
+            if (eligible.Count > 0 && eligible.All(IsGroupQuotaReached))
+                throw new InvalidOperationException("所有分组都已达到本次会话的抽取上限，请调用 ResetQuotas 开始新的会话");
+        }
+
+        /// <summary>
+        /// 重新设置种子并跳过已消耗的随机数，使随机数序列从保存的位置继续
+        /// 个数超过 MaxRngReplay 或小于0时（通常是数据文件被手动修改或损坏）不跳过，从种子的开头重新开始，避免加载时长时间卡住
+        /// </summary>
+        private void ReplayRandom(int seed, long consumed)
+        {
+            _random = new Random(seed);
+            _rngDrawsConsumed = 0;
+            
+            if (consumed < 0 || consumed > MaxRngReplay)
+            {
+                Debug.WriteLine($"已消耗的随机数个数{consumed}超出了可恢复的范围[0, {MaxRngReplay}]，从种子的开头重新开始: {_dataId}");
+                return;
+            }
+            
+            while (_rngDrawsConsumed < consumed)
+            {
+                NextRandomDouble();
+            }
+        }
+
+        /// <summary>
+        /// 生成下一个随机数并记录消耗次数
+        /// </summary>
+        private double NextRandomDouble()
+        {
+            _rngDrawsConsumed++;
+#if TESTING
+            if (_globalTestRandom != null)
+                return _globalTestRandom.NextDouble();
+#endif
+            return _random.NextDouble();
+        }
+
+#if TESTING
+        [ThreadStatic]
+        private static Random? _globalTestRandom;
+
+        /// <summary>
+        /// 仅用于测试：为当前线程上的所有实例设置共享的带种子随机数生成器，使不设置种子的抽取也可复现
+        /// 设置后当前线程上所有实例都从这个生成器取随机数（代替各自的随机数生成器，包括 SetSeed 设置的种子）。
+        /// 只在定义了 TESTING 编译符号时可用（构建时加上 -p:ClandomTesting=true），不要在正式使用中依赖它
+        /// </summary>
+        /// <param name="seed">随机数种子，为null时恢复为各实例自己的随机数生成器</param>
+        public static void SetGlobalTestSeed(int? seed)
+        {
+            _globalTestRandom = seed.HasValue ? new Random(seed.Value) : null;
+        }
+#endif
+
+        /// <summary>
+        /// 更新概率信息
+        /// </summary>
+        private void UpdateProbabilities()
+        {
+            _currentProbabilities.Clear();
+            
+            if (_roundRobin)
+            {
+                // 轮流模式：本轮剩余的学号概率相同
+                var remaining = _roundRobinQueue.Count > 0 
+                    ? new HashSet<int>(_roundRobinQueue) 
+                    : GetRoundRobinEligible();
+                foreach (var number in GetActiveNumbers())
+                {
+                    _currentProbabilities[number] = remaining.Contains(number) ? 1.0 / remaining.Count : 0;
+                }
+                return;
+            }
+            
+            if (_candidatePool != null && _candidatePool.Count == 0) return;
+            
+            var weights = CalculateWeights();
+            double totalWeight = weights.Values.Sum();
+            
+            foreach (var kvp in weights)
+            {
+                _currentProbabilities[kvp.Key] = kvp.Value / totalWeight;
+            }
+            
+            // 为不在候选池中的成员设置概率为0
+            var allActiveNumbers = GetActiveNumbers();
+            
+            var poolSet = new HashSet<int>(_candidatePool ?? new List<int>());
+            foreach (var number in allActiveNumbers.Where(n => _candidatePool != null && !poolSet.Contains(n)))
+            {
+                _currentProbabilities[number] = 0;
+            }
+        }
+
+        #endregion
+    }
+
+    /// <summary>
+    /// 按行列抽取
+    /// </summary>
+    public class BalancedRandPlane : BalancedRand
+    {
+        private int _rows;
+        private int _cols;
+        private string _dataIdPlane;
+        private bool _avoidAdjacentToLast;  // 不抽取与上一次抽中位置相邻的位置
+        
+        /// <summary>
+        /// 构造函数
+        /// </summary>
+        /// <param name="rows">行数</param>
+        /// <param name="cols">列数</param>
+        /// <param name="minPoolSize">最小候选池大小</param>
+        /// <param name="maxGapThreshold">最大抽取次数差距阈值</param>
+        /// <param name="coldStartBoost">冷启动提升系数</param>
+        /// <param name="decayFactor">权重衰减因子</param>
+        /// <param name="loadData">是否从文件加载历史数据（默认true）</param>
+        public BalancedRandPlane(int rows, int cols, int minPoolSize = 3, 
+                            int maxGapThreshold = 5, double coldStartBoost = 2.0, 
+                            double decayFactor = 0.7, bool loadData = true) 
+            : base(0, rows * cols - 1, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor, false)
+        {
+            _rows = rows;
+            _cols = cols;
+            
+            // 生成2D专用的数据ID
+            _dataIdPlane = BalancedRandDataManager.GenerateId("BalancedRandPlane", 
+                rows, cols, minPoolSize, maxGapThreshold, coldStartBoost, decayFactor);
+            
+            // 加载历史数据
+            if (loadData)
+            {
+                LoadData();
+            }
+            
+            WarnIfMinPoolSizeTooLarge();
+        }
+        
+        /// <summary>
+        /// 从文件加载数据
+        /// </summary>
+        public override void LoadData(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            try
+            {
+                var allData = BalancedRandDataManager.LoadAllData(filePath, ValidationMode.Skip, out var warnings);
+                foreach (var warning in warnings)
+                {
+                    Debug.WriteLine($"跳过不合法的数据: {warning}");
+                }
+                
+                // 优先使用2D专用ID，如果没有则尝试使用基类ID
+                if (allData.TryGetValue(_dataIdPlane, out var savedData) || 
+                    allData.TryGetValue(base.GetDataId(), out savedData))
+                {
+                    ApplySavedData(savedData);
+                    Debug.WriteLine($"已加载Plane数据: {_dataIdPlane}");
+                }
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"加载Plane数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 加载数据时依次尝试的数据ID（优先使用2D专用ID）
+        /// </summary>
+        protected override IEnumerable<string> GetStorageIds()
+        {
+            yield return _dataIdPlane;
+            yield return base.GetDataId();
+        }
+        
+        /// <summary>
+        /// 应用保存的数据
+        /// </summary>
+        protected override void ApplySavedData(BalancedRandData savedData)
+        {
+            // 在基类重新计算候选池之前设置
+            _avoidAdjacentToLast = savedData.Advanced?.AvoidAdjacentToLast ?? false;
+            
+            // 调用基类方法
+            base.ApplySavedData(savedData);
+            
+            // 2D特有的处理逻辑
+            if (savedData.Rows > 0 && savedData.Cols > 0)
+            {
+                // 如果保存的数据中有行列信息，可以在这里处理
+            }
+        }
+        
+        /// <summary>
+        /// 保存数据到文件
+        /// </summary>
+        public override void SaveData(string filePath = "balanced_rand_data.json")
+        {
+            EnsureWritable();
+            
+            try
+            {
+                var data = ToData();
+                BalancedRandDataManager.SaveEntry(data, filePath);
+                MarkSynced(data);
+                
+                Debug.WriteLine($"已保存Plane数据: {_dataIdPlane}");
+            }
+            catch (Exception ex)
+            {
+                Debug.WriteLine($"保存Plane数据失败: {ex.Message}");
+            }
+        }
+        
+        /// <summary>
+        /// 将当前状态转换为可保存的数据
+        /// </summary>
+        public override BalancedRandData ToData()
+        {
+            var data = base.ToData();
+            data.Id = _dataIdPlane;
+            data.Type = "BalancedRandPlane";
+            data.Rows = _rows;
+            data.Cols = _cols;
+            // 2D数据通过行列描述，不保存学号范围
+            data.NumberRangeStart = 0;
+            data.NumberRangeEnd = 0;
+            data.Advanced.AvoidAdjacentToLast = _avoidAdjacentToLast;
+            return data;
+        }
+        
+        /// <summary>
+        /// 获取数据ID（2D专用）
+        /// </summary>
+        public new string GetDataId() => _dataIdPlane;
+
+        /// <summary>
+        /// 设置是否避开上一次抽中位置的相邻位置
+        /// 开启后，与上一次抽中位置上下左右相邻的位置不会出现在下一次的候选池中；
+        /// 如果排除后没有可抽取的位置，则不排除
+        /// </summary>
+        public void SetAvoidAdjacentToLast(bool enabled)
+        {
+            EnsureWritable();
+            
+            _avoidAdjacentToLast = enabled;
+            RefreshCandidatePool();
+        }
+
+        /// <summary>
+        /// 是否避开上一次抽中位置的相邻位置
+        /// </summary>
+        public bool GetAvoidAdjacentToLast() => _avoidAdjacentToLast;
+
+        protected override ISet<int>? GetExcludedCandidates()
+        {
+            if (!_avoidAdjacentToLast) return null;
+            
+            var last = GetLastDrawnNumber();
+            if (last == null || GetPositionOf(last.Value) is not { } position) return null;
+            
+            var (row, col) = position;
+            var neighbors = new HashSet<int>();
+            if (row > 1) neighbors.Add(IndexOf(row - 1, col));
+            if (row < _rows) neighbors.Add(IndexOf(row + 1, col));
+            if (col > 1) neighbors.Add(IndexOf(row, col - 1));
+            if (col < _cols) neighbors.Add(IndexOf(row, col + 1));
+            return neighbors;
+        }
+
+        /// <summary>
+        /// 复制行列、配置参数、黑名单/白名单等设置，但不复制抽取记录
+        /// 副本的数据ID加上 suffix 后缀，与原实例互不影响
+        /// </summary>
+        /// <param name="suffix">数据ID后缀</param>
+        public new BalancedRandPlane CloneFresh(string suffix) => (BalancedRandPlane)base.CloneFresh(suffix);
+
+        /// <summary>
+        /// 完整复制当前实例（包括抽取记录），副本的数据ID加上 suffix 后缀
+        /// </summary>
+        /// <param name="suffix">数据ID后缀</param>
+        public new BalancedRandPlane CloneWithHistory(string suffix) => (BalancedRandPlane)base.CloneWithHistory(suffix);
+
+        protected override void ApplyIdSuffix(string suffix)
+        {
+            base.ApplyIdSuffix(suffix);
+            _dataIdPlane += suffix;
+        }
+
+        protected override void ApplyNamespace(string? oldNamespace, string? newNamespace)
+        {
+            base.ApplyNamespace(oldNamespace, newNamespace);
+            _dataIdPlane = BalancedRandDataManager.QualifyId(newNamespace, BalancedRandDataManager.UnqualifyId(oldNamespace, _dataIdPlane));
+        }
+        
+        /// <summary>
+        /// 抽取一个位置（返回行列，1-based索引）
+        /// 抽中网格外的白名单学号时返回的行列在网格之外，见 GetExtraWhitelistStats
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>(行, 列)，行和列从1开始</returns>
+        public (int row, int col) DrawPosition(bool autoSave = true)
+        {
+            return FromFlatIndex(Draw(autoSave));
+        }
+        
+        /// <summary>
+        /// 批量抽取多个位置（1-based索引）
+        /// </summary>
+        /// <param name="count">抽取数量</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        public List<(int row, int col)> DrawMultiplePositions(int count, bool autoSave = true)
+        {
+            return DrawMultiple(count, autoSave).Select(FromFlatIndex).ToList();
+        }
+        
+        /// <summary>
+        /// 逐个抽取位置，直到 predicate 对已抽到的位置序列返回 true，或达到 maxAttempts 次（如直到每一行都有人被抽到）
+        /// 行为同 DrawUntil，行列从1开始
+        /// </summary>
+        /// <param name="maxAttempts">最多抽取的次数</param>
+        /// <param name="predicate">停止条件，参数为按抽取顺序排列的已抽到的位置</param>
+        /// <param name="autoSave">是否在抽取结束后保存数据（默认true）</param>
+        /// <param name="throwIfNotMet">达到 maxAttempts 仍未满足条件时是否抛出异常（默认false）</param>
+        public List<(int row, int col)> DrawPositionsUntil(int maxAttempts, Func<IReadOnlyList<(int row, int col)>, bool> predicate, 
+                                                           bool autoSave = true, bool throwIfNotMet = false)
+        {
+            ArgumentNullException.ThrowIfNull(predicate);
+            
+            var positions = new List<(int row, int col)>();
+            DrawUntil(maxAttempts, numbers =>
+            {
+                positions.Add(FromFlatIndex(numbers[^1]));
+                return predicate(positions);
+            }, autoSave, throwIfNotMet);
+            return positions;
+        }
+        
+        /// <summary>
+        /// 只从指定的位置中抽取一次，行为同 DrawFromSubset，行和列从1开始（超出网格的位置被忽略）
+        /// </summary>
+        /// <param name="positions">允许抽取的位置</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        public (int row, int col) DrawPositionFromSubset(IEnumerable<(int row, int col)> positions, bool autoSave = true)
+        {
+            return FromFlatIndex(DrawFromSubset(ToIndices(positions), autoSave));
+        }
+        
+        /// <summary>
+        /// 抽取一个位置，但本次不抽取指定的位置，行为同 DrawExcluding，行和列从1开始
+        /// </summary>
+        /// <param name="positions">本次不抽取的位置</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        public (int row, int col) DrawPositionExcluding(IEnumerable<(int row, int col)> positions, bool autoSave = true)
+        {
+            return FromFlatIndex(DrawExcluding(ToIndices(positions), autoSave));
+        }
+        
+        /// <summary>
+        /// 带位置限制条件的批量抽取，行为同 DrawMultipleWith，行和列从1开始
+        /// </summary>
+        /// <param name="count">抽取数量（包括必须抽中的位置）</param>
+        /// <param name="include">必须抽中的位置</param>
+        /// <param name="exclude">本次不能抽中的位置</param>
+        /// <param name="autoSave">是否在全部抽取后保存数据（默认true）</param>
+        public List<(int row, int col)> DrawMultiplePositionsWith(int count, IEnumerable<(int row, int col)> include,
+                                                                  IEnumerable<(int row, int col)> exclude, bool autoSave = true)
+        {
+            var constraints = new DrawConstraints
+            {
+                Include = include.Select(ToIndex).ToList(),
+                Exclude = exclude.Select(ToIndex).ToList()
+            };
+            return DrawMultipleWith(count, constraints, autoSave).Select(FromFlatIndex).ToList();
+        }
+        
+        /// <summary>
+        /// 排名抽取位置（如第1、2、3名），行为同 DrawRanked，行和列从1开始
+        /// </summary>
+        /// <param name="ranks">名次数量</param>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>按名次排列的 (名次, 行, 列, 抽中时的概率)</returns>
+        public List<(int Rank, int row, int col, double Probability)> DrawRankedPositions(int ranks, bool autoSave = true)
+        {
+            return DrawRanked(ranks, autoSave)
+                .Select(r =>
+                {
+                    var (row, col) = FromFlatIndex(r.Number);
+                    return (r.Rank, row, col, r.Probability);
+                })
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 优先抽取从未被抽中过的位置（如检查设备时尽快覆盖所有座位）
+        /// 还有未覆盖的位置时只从这些位置中抽取；所有位置都已覆盖后按正常方式抽取，并通过 FromUncovered 为false表示
+        /// 黑名单中的位置不需要覆盖
+        /// </summary>
+        /// <param name="autoSave">是否自动保存数据（默认true）</param>
+        /// <returns>(行, 列, 是否从未覆盖的位置中抽取)，行和列从1开始</returns>
+        public (int row, int col, bool FromUncovered) DrawUncovered(bool autoSave = true)
+        {
+            var uncovered = GetUncoveredIndices();
+            if (uncovered.Count == 0)
+            {
+                var (row, col) = FromFlatIndex(Draw(autoSave));
+                return (row, col, false);
+            }
+            
+            var position = FromFlatIndex(DrawRestricted(uncovered, autoSave));
+            return (position.row, position.col, true);
+        }
+        
+        /// <summary>
+        /// 获取覆盖率：被抽中过至少一次的位置占需要覆盖的位置（不在黑名单中）的比例
+        /// 没有需要覆盖的位置时返回1
+        /// </summary>
+        public double GetCoverage()
+        {
+            int required = Enumerable.Range(0, _rows * _cols).Count(index => !IsInBlacklist(index));
+            if (required == 0) return 1.0;
+            
+            return (double)(required - GetUncoveredIndices().Count) / required;
+        }
+        
+        /// <summary>
+        /// 获取从未被抽中过的位置（不包括黑名单中的位置），按行优先排列，行和列从1开始
+        /// </summary>
+        public List<(int row, int col)> GetUncoveredPositions()
+        {
+            return GetUncoveredIndices().Select(FromFlatIndex).ToList();
+        }
+        
+        private List<int> GetUncoveredIndices()
+        {
+            return Enumerable.Range(0, _rows * _cols)
+                .Where(index => !IsInBlacklist(index) && GetDrawCountOf(index) == 0)
+                .ToList();
+        }
+        
+        /// <summary>
+        /// 获取位置统计信息列表（按位置顺序，行优先）
+        /// </summary>
+        public List<int> GetPositionStatisticsList()
+        {
+            return GetStatisticsList();
+        }
+        
+        /// <summary>
+        /// 获取每个位置的统计信息
+        /// </summary>
+        /// <param name="by">排序方式（默认按位置，行优先）</param>
+        /// <returns>(行, 列, 抽取次数, 当前概率, 最后被抽中的轮次) 列表，行列从1开始，从未被抽中时轮次为-1；
+        /// 不包括网格外的白名单学号（见 GetExtraWhitelistStats）</returns>
+        public List<(int Row, int Col, int Count, double Probability, int LastRound)> GetPositionStatisticsSorted(
+            PositionSortKey by = PositionSortKey.Position)
+        {
+            // 统计列表按活跃学号排序，可能包含网格外的白名单学号，需要按学号对应
+            var active = this.ToList();
+            var counts = GetStatisticsList();
+            var probabilities = GetProbabilityList();
+            var activeIndex = active.Select((number, i) => (number, i)).ToDictionary(x => x.number, x => x.i);
+            var lastRounds = GetLastDrawRounds();
+            
+            var stats = new List<(int Row, int Col, int Count, double Probability, int LastRound)>(_rows * _cols);
+            for (int index = 0; index < _rows * _cols; index++)
+            {
+                var (row, col) = PositionOf(index);
+                int i = activeIndex[index];
+                stats.Add((row, col, counts[i], probabilities[i], lastRounds[index]));
+            }
+            
+            return by switch
+            {
+                PositionSortKey.Count => stats
+                    .OrderByDescending(s => s.Count).ThenBy(s => s.Row).ThenBy(s => s.Col).ToList(),
+                PositionSortKey.Probability => stats
+                    .OrderByDescending(s => s.Probability).ThenBy(s => s.Row).ThenBy(s => s.Col).ToList(),
+                _ => stats
+            };
+        }
+        
+        /// <summary>
+        /// 获取网格外的白名单学号的统计信息
+        /// 2D抽取允许通过 AddToWhitelist/SetWhitelist 加入网格外的学号（0 ~ 行数×列数-1 以外），这些学号可以被抽中，
+        /// 但没有行列位置：按位置统计的方法（GetPositionStatisticsSorted、GetDrawCountsDict 等）只包含网格内的位置，
+        /// DrawPosition 等方法抽中它们时返回的行列在网格之外（可用 FlatIndex 判断，网格外的行列返回null）
+        /// </summary>
+        /// <returns>(学号, 抽取次数, 当前概率, 最后被抽中的轮次) 列表，按学号顺序排列，从未被抽中时轮次为-1</returns>
+        public List<(int Number, int Count, double Probability, int LastRound)> GetExtraWhitelistStats()
+        {
+            return GetFullStatistics()
+                .Where(s => GetPositionOf(s.Number) == null)
+                .ToList();
+        }
+        
+        protected override (int rows, int cols)? GetGridSize() => (_rows, _cols);
+        
+        protected override (int row, int col)? GetPositionOf(int number)
+        {
+            // 网格外的白名单学号没有位置
+            if (number < 0 || number >= _rows * _cols) return null;
+            return PositionOf(number);
+        }
+        
+        /// <summary>
+        /// 将行列（从1开始）转换为内部序号（行优先，从0开始）
+        /// </summary>
+        /// <param name="row">行号（1-based）</param>
+        /// <param name="col">列号（1-based）</param>
+        /// <exception cref="ArgumentOutOfRangeException">位置超出网格</exception>
+        public int IndexOf(int row, int col) => BalancedRandDataManager.PlaneIndexOf(_rows, _cols, row, col);
+        
+        /// <summary>
+        /// 将内部序号（行优先，从0开始）转换为行列（从1开始）
+        /// </summary>
+        /// <param name="index">内部序号</param>
+        /// <exception cref="ArgumentOutOfRangeException">序号超出网格（如白名单中的额外学号）</exception>
+        public (int row, int col) PositionOf(int index) => BalancedRandDataManager.PlanePositionOf(_rows, _cols, index);
+        
+        /// <summary>
+        /// 将行列（从1开始）转换为内部序号（行优先，从0开始）
+        /// </summary>
+        /// <param name="row">行号（1-based）</param>
+        /// <param name="col">列号（1-based）</param>
+        /// <returns>内部序号，位置超出网格时返回null</returns>
+        public int? FlatIndex(int row, int col)
+        {
+            if (row < 1 || row > _rows || col < 1 || col > _cols) return null;
+            return IndexOf(row, col);
+        }
+        
+        /// <summary>
+        /// 将内部序号（行优先，从0开始）转换为行列（从1开始）
+        /// 不检查范围：网格外的序号（如白名单中的额外学号）会得到网格外的行列
+        /// </summary>
+        /// <param name="index">内部序号</param>
+        public (int row, int col) FromFlatIndex(int index)
+        {
+            return (index / _cols + 1, index % _cols + 1);
+        }
+        
+        /// <summary>
+        /// 将行列（从1开始）转换为内部序号，超出网格时抛出 ArgumentOutOfRangeException
+        /// </summary>
+        private int ToIndex((int row, int col) position) => IndexOf(position.row, position.col);
+        
+        /// <summary>
+        /// 将行列转换为内部序号，忽略超出网格的位置
+        /// </summary>
+        private List<int> ToIndices(IEnumerable<(int row, int col)> positions)
+        {
+            var indices = new List<int>();
+            foreach (var (row, col) in positions)
+            {
+                if (FlatIndex(row, col) is { } index)
+                {
+                    indices.Add(index);
+                }
+                else
+                {
+                    Debug.WriteLine($"忽略超出{_rows}行{_cols}列范围的位置({row}, {col})");
+                }
+            }
+            return indices;
+        }
+        
+        /// <summary>
+        /// 获取位置概率信息列表（按位置顺序，行优先）
+        /// </summary>
+        public List<double> GetPositionProbabilityList()
+        {
+            return GetProbabilityList();
+        }
+        
+        /// <summary>
+        /// 获取当前各位置的权重（不读取文件），格式与 BalancedRandDataManager.GetWeightsByPlaneRange 相同
+        /// </summary>
+        /// <returns>以 [列, 行]（从0开始）为键的权重字典</returns>
+        public Dictionary<List<int>, double> GetWeightDict()
+        {
+            return BalancedRandDataManager.GetPlaneConfigWeightList(ToData());
+        }
+        
+        /// <summary>
+        /// 获取当前各位置的抽取次数（不读取文件），格式与 BalancedRandDataManager.GetDrawCountsByPlaneRange 相同
+        /// </summary>
+        /// <returns>以 [列, 行]（从0开始）为键的抽取次数字典，不包括网格外的白名单学号（见 GetExtraWhitelistStats）</returns>
+        public Dictionary<List<int>, int> GetDrawCountsDict()
+        {
+            return BalancedRandDataManager.GetPlaneConfigDrawCounts(ToData());
+        }
+        
+        #region 2D专用的黑名单/白名单功能
+        
+        /// <summary>
+        /// 设置黑名单位置（通过行列指定）
+        /// </summary>
+        /// <param name="positions">要禁止的位置列表，每个位置为(行, 列)</param>
+        public void SetBlacklistPositions(IEnumerable<(int row, int col)> positions)
+        {
+            SetBlacklist(ToIndices(positions));
+        }
+        
+        /// <summary>
+        /// 添加位置到黑名单（通过行列指定）
+        /// </summary>
+        /// <param name="positions">要添加到黑名单的位置</param>
+        public void AddToBlacklistPositions(params (int row, int col)[] positions)
+        {
+            AddToBlacklist(ToIndices(positions).ToArray());
+        }
+        
+        /// <summary>
+        /// 从黑名单中移除位置（通过行列指定）
+        /// </summary>
+        /// <param name="positions">要从黑名单中移除的位置</param>
+        public void RemoveFromBlacklistPositions(params (int row, int col)[] positions)
+        {
+            RemoveFromBlacklist(ToIndices(positions).ToArray());
+        }
+        
+        /// <summary>
+        /// 设置白名单位置（通过行列指定，超出网格的位置会被忽略）
+        /// </summary>
+        /// <param name="positions">要加入白名单的位置列表，每个位置为(行, 列)</param>
+        public void SetWhitelistPositions(IEnumerable<(int row, int col)> positions)
+        {
+            SetWhitelist(ToIndices(positions));
+        }
+        
+        /// <summary>
+        /// 添加位置到白名单（通过行列指定，超出网格的位置会被忽略）
+        /// </summary>
+        /// <param name="positions">要添加到白名单的位置</param>
+        public void AddToWhitelistPositions(params (int row, int col)[] positions)
+        {
+            AddToWhitelist(ToIndices(positions).ToArray());
+        }
+        
+        /// <summary>
+        /// 从白名单中移除位置（通过行列指定）
+        /// </summary>
+        /// <param name="positions">要从白名单中移除的位置</param>
+        public void RemoveFromWhitelistPositions(params (int row, int col)[] positions)
+        {
+            RemoveFromWhitelist(ToIndices(positions).ToArray());
+        }
+        
+        /// <summary>
+        /// 检查位置是否在黑名单中
+        /// </summary>
+        /// <param name="row">行号（1-based）</param>
+        /// <param name="col">列号（1-based）</param>
+        /// <returns>是否在黑名单中，位置超出网格（如行号或列号为0）时返回false</returns>
+        public bool IsPositionInBlacklist(int row, int col)
+        {
+            return FlatIndex(row, col) is { } index && IsInBlacklist(index);
+        }
+        
+        /// <summary>
+        /// 检查位置是否在白名单中
+        /// </summary>
+        /// <param name="row">行号（1-based）</param>
+        /// <param name="col">列号（1-based）</param>
+        /// <returns>是否在白名单中，位置超出网格（如行号或列号为0）时返回false</returns>
+        public bool IsPositionInWhitelist(int row, int col)
+        {
+            return FlatIndex(row, col) is { } index && IsInWhitelist(index);
+        }
+        
+        #endregion
+    }
+}
\ No newline at end of file
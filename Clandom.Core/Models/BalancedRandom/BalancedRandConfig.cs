@@ -0,0 +1,53 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 平衡随机抽取的调节参数
+    /// </summary>
+    public class BalancedRandConfig
+    {
+        /// <summary>
+        /// 最小候选池大小
+        /// </summary>
+        public int MinPoolSize { get; set; } = 3;
+        
+        /// <summary>
+        /// 最大抽取次数差距阈值
+        /// </summary>
+        public int MaxGapThreshold { get; set; } = 5;
+        
+        /// <summary>
+        /// 冷启动提升系数
+        /// </summary>
+        public double ColdStartBoost { get; set; } = 2.0;
+        
+        /// <summary>
+        /// 权重衰减因子
+        /// </summary>
+        public double DecayFactor { get; set; } = 0.7;
+        
+        /// <summary>
+        /// 最小权重
+        /// </summary>
+        public double MinWeight { get; set; } = 0.01;
+        
+        /// <summary>
+        /// 同一学号两次被抽中之间至少间隔的抽取次数
+        /// </summary>
+        public int MinSpacing { get; set; }
+        
+        /// <summary>
+        /// 是否禁止连续两次抽中同一学号
+        /// </summary>
+        public bool NoRepeat { get; set; }
+        
+        /// <summary>
+        /// 是否启用平衡权重（关闭时候选池中的学号权重相同）
+        /// </summary>
+        public bool BalancingEnabled { get; set; } = true;
+        
+        /// <summary>
+        /// 预热轮次：当前轮次不超过该值时使用相同的权重
+        /// </summary>
+        public int WarmupRounds { get; set; }
+    }
+}
@@ -0,0 +1,26 @@
+using System;
+using System.Collections.Generic;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 批量抽取（TryDrawMultiple）的结果：中途出错时包含出错前已抽到的学号和错误
+    /// </summary>
+    public class BatchResult
+    {
+        /// <summary>
+        /// 已抽到的学号（按抽取顺序），这些抽取已计入抽取记录
+        /// </summary>
+        public List<int> Drawn { get; } = new List<int>();
+        
+        /// <summary>
+        /// 导致批量抽取中止的错误，全部完成时为null
+        /// </summary>
+        public Exception? Error { get; set; }
+        
+        /// <summary>
+        /// 是否全部抽取完成
+        /// </summary>
+        public bool Succeeded => Error == null;
+    }
+}
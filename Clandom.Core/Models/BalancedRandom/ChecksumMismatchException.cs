@@ -0,0 +1,33 @@
+using System.IO;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 数据文件中保存的校验值与内容不一致（文件已损坏）
+    /// </summary>
+    public class ChecksumMismatchException : InvalidDataException
+    {
+        /// <summary>
+        /// 损坏的文件路径
+        /// </summary>
+        public string FilePath { get; }
+        
+        /// <summary>
+        /// 文件中保存的校验值
+        /// </summary>
+        public string Expected { get; }
+        
+        /// <summary>
+        /// 根据文件内容计算出的校验值
+        /// </summary>
+        public string Actual { get; }
+
+        public ChecksumMismatchException(string filePath, string expected, string actual)
+            : base($"数据文件校验失败，文件可能已损坏: {filePath}")
+        {
+            FilePath = filePath;
+            Expected = expected;
+            Actual = actual;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 两组参数在相同条件下模拟抽取的比较结果
+    /// </summary>
+    public class ComparisonReport
+    {
+        /// <summary>
+        /// 参数A的模拟结果
+        /// </summary>
+        public required SimulationResult ResultA { get; set; }
+        
+        /// <summary>
+        /// 参数B的模拟结果
+        /// </summary>
+        public required SimulationResult ResultB { get; set; }
+        
+        /// <summary>
+        /// 方差之差（B - A），小于0表示B更平均
+        /// </summary>
+        public double VarianceDelta => ResultB.Variance - ResultA.Variance;
+        
+        /// <summary>
+        /// 熵之差（B - A），大于0表示B更平均
+        /// </summary>
+        public double EntropyDelta => ResultB.Entropy - ResultA.Entropy;
+        
+        /// <summary>
+        /// 更平均的一方："A"、"B"，两者相同时为null
+        /// 优先比较方差，方差相同时比较熵
+        /// </summary>
+        public string? Winner
+        {
+            get
+            {
+                if (VarianceDelta < 0) return "B";
+                if (VarianceDelta > 0) return "A";
+                if (EntropyDelta > 0) return "B";
+                if (EntropyDelta < 0) return "A";
+                return null;
+            }
+        }
+    }
+}
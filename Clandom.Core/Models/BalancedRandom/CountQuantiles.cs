@@ -0,0 +1,48 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 抽取次数分布的分位数（可直接用于绘制箱线图）
+    /// 分位数在排序后的抽取次数之间线性插值；学号少于4个时四分位数由已有的值插值得到，
+    /// 只有1个学号时所有值都等于它的抽取次数，没有学号时所有值为0
+    /// </summary>
+    public class CountQuantiles
+    {
+        /// <summary>
+        /// 参与统计的学号数量
+        /// </summary>
+        public int Count { get; set; }
+        
+        /// <summary>
+        /// 最小值
+        /// </summary>
+        public double P0 { get; set; }
+        
+        /// <summary>
+        /// 下四分位数
+        /// </summary>
+        public double P25 { get; set; }
+        
+        /// <summary>
+        /// 中位数
+        /// </summary>
+        public double P50 { get; set; }
+        
+        /// <summary>
+        /// 上四分位数
+        /// </summary>
+        public double P75 { get; set; }
+        
+        /// <summary>
+        /// 最大值
+        /// </summary>
+        public double P100 { get; set; }
+        
+        /// <summary>
+        /// 平均值
+        /// </summary>
+        public double Mean { get; set; }
+
+        public override string ToString() =>
+            $"n={Count}, min={P0}, p25={P25}, median={P50}, p75={P75}, max={P100}, mean={Mean:F2}";
+    }
+}
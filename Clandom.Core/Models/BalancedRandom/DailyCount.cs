@@ -0,0 +1,27 @@
+using System;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 学号在某一天的抽取次数（用于每日抽取上限）
+    /// </summary>
+    public class DailyCount
+    {
+        /// <summary>
+        /// 日期（按设置的时区）
+        /// </summary>
+        public DateOnly Date { get; set; }
+        
+        /// <summary>
+        /// 当天的抽取次数
+        /// </summary>
+        public int Count { get; set; }
+
+        public override bool Equals(object? obj)
+        {
+            return obj is DailyCount other && Date == other.Date && Count == other.Count;
+        }
+
+        public override int GetHashCode() => HashCode.Combine(Date, Count);
+    }
+}
@@ -0,0 +1,22 @@
+using System;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 所有可抽取的学号今天都已达到每日抽取上限时 Draw 抛出的异常
+    /// 与候选池为空不同，这种情况不会重置抽取次数，需要等到第二天
+    /// </summary>
+    public class DailyLimitReachedException : InvalidOperationException
+    {
+        /// <summary>
+        /// 每日抽取上限
+        /// </summary>
+        public int DailyLimit { get; }
+
+        public DailyLimitReachedException(int dailyLimit)
+            : base($"所有可抽取的学号今天都已被抽取{dailyLimit}次，达到每日上限")
+        {
+            DailyLimit = dailyLimit;
+        }
+    }
+}
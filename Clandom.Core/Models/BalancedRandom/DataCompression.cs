@@ -0,0 +1,43 @@
+using System.IO.Compression;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 数据文件的压缩方式
+    /// 读取时会根据文件头自动识别是否为gzip，因此这里只影响保存
+    /// </summary>
+    public sealed class DataCompression
+    {
+        private DataCompression(bool isGzip, CompressionLevel level)
+        {
+            IsGzip = isGzip;
+            Level = level;
+        }
+
+        /// <summary>
+        /// 不压缩（保存为带缩进的JSON）
+        /// </summary>
+        public static DataCompression None { get; } = new DataCompression(false, CompressionLevel.NoCompression);
+
+        /// <summary>
+        /// 使用gzip压缩（文件名通常为 .json.gz）
+        /// </summary>
+        /// <param name="level">压缩级别</param>
+        public static DataCompression Gzip(CompressionLevel level = CompressionLevel.Optimal)
+        {
+            return new DataCompression(true, level);
+        }
+
+        /// <summary>
+        /// 是否使用gzip压缩
+        /// </summary>
+        public bool IsGzip { get; }
+        
+        /// <summary>
+        /// gzip压缩级别（不压缩时无意义）
+        /// </summary>
+        public CompressionLevel Level { get; }
+
+        public override string ToString() => IsGzip ? $"Gzip({Level})" : "None";
+    }
+}
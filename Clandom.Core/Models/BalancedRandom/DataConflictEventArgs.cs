@@ -0,0 +1,26 @@
+using System;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 数据文件被其他程序修改，同时本实例也有未保存的修改
+    /// </summary>
+    public class DataConflictEventArgs : EventArgs
+    {
+        /// <summary>
+        /// 本实例当前的数据（未保存）
+        /// </summary>
+        public BalancedRandData Local { get; }
+        
+        /// <summary>
+        /// 数据文件中的数据
+        /// </summary>
+        public BalancedRandData External { get; }
+
+        public DataConflictEventArgs(BalancedRandData local, BalancedRandData external)
+        {
+            Local = local;
+            External = external;
+        }
+    }
+}
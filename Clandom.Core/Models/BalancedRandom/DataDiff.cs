@@ -0,0 +1,41 @@
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 两份平衡随机抽取数据之间的差异
+    /// </summary>
+    public class DataDiff
+    {
+        /// <summary>
+        /// 抽取次数发生变化的学号 -> (变化前, 变化后)
+        /// </summary>
+        public Dictionary<int, (int Before, int After)> ChangedCounts { get; } = new Dictionary<int, (int Before, int After)>();
+        
+        // 黑名单/白名单变化
+        public List<int> BlacklistAdded { get; } = new List<int>();
+        public List<int> BlacklistRemoved { get; } = new List<int>();
+        public List<int> WhitelistAdded { get; } = new List<int>();
+        public List<int> WhitelistRemoved { get; } = new List<int>();
+        
+        /// <summary>
+        /// 发生变化的配置参数名 -> (变化前, 变化后)
+        /// </summary>
+        public Dictionary<string, (object? Before, object? After)> ChangedParameters { get; } = new Dictionary<string, (object? Before, object? After)>();
+        
+        // 统计信息变化量
+        public int TotalDrawsDelta { get; set; }
+        public int CurrentRoundDelta { get; set; }
+
+        /// <summary>
+        /// 是否没有任何变化
+        /// </summary>
+        public bool IsEmpty =>
+            !ChangedCounts.Any() &&
+            !BlacklistAdded.Any() && !BlacklistRemoved.Any() &&
+            !WhitelistAdded.Any() && !WhitelistRemoved.Any() &&
+            !ChangedParameters.Any() &&
+            TotalDrawsDelta == 0 && CurrentRoundDelta == 0;
+    }
+}
@@ -0,0 +1,80 @@
+using System;
+using System.Security.Cryptography;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 加密数据文件使用的密钥（AES-256-GCM，32字节）
+    /// 可以直接使用随机生成的密钥，也可以从口令派生；从口令派生时需要自行保存盐值，
+    /// 相同的口令、盐值和迭代次数总是得到相同的密钥
+    /// </summary>
+    public sealed class DataEncryptionKey
+    {
+        /// <summary>
+        /// 密钥长度（字节）
+        /// </summary>
+        public const int KeySize = 32;
+        
+        /// <summary>
+        /// 从口令派生密钥时默认的 PBKDF2 迭代次数
+        /// </summary>
+        public const int DefaultIterations = 600_000;
+
+        private readonly byte[] _key;
+
+        /// <summary>
+        /// 使用已有的32字节密钥
+        /// </summary>
+        public DataEncryptionKey(byte[] key)
+        {
+            ArgumentNullException.ThrowIfNull(key);
+            if (key.Length != KeySize)
+                throw new ArgumentException($"密钥长度必须为{KeySize}字节");
+            
+            _key = (byte[])key.Clone();
+        }
+
+        /// <summary>
+        /// 生成随机密钥
+        /// </summary>
+        public static DataEncryptionKey Generate()
+        {
+            return new DataEncryptionKey(RandomNumberGenerator.GetBytes(KeySize));
+        }
+
+        /// <summary>
+        /// 生成用于 FromPassphrase 的随机盐值（16字节）
+        /// </summary>
+        public static byte[] CreateSalt()
+        {
+            return RandomNumberGenerator.GetBytes(16);
+        }
+
+        /// <summary>
+        /// 使用 PBKDF2-SHA256 从口令派生密钥
+        /// </summary>
+        /// <param name="passphrase">口令</param>
+        /// <param name="salt">盐值（至少8字节），可由 CreateSalt 生成</param>
+        /// <param name="iterations">迭代次数</param>
+        public static DataEncryptionKey FromPassphrase(string passphrase, byte[] salt, int iterations = DefaultIterations)
+        {
+            if (string.IsNullOrEmpty(passphrase))
+                throw new ArgumentException("口令不能为空");
+            ArgumentNullException.ThrowIfNull(salt);
+            if (salt.Length < 8)
+                throw new ArgumentException("盐值至少需要8字节");
+            if (iterations <= 0)
+                throw new ArgumentException("迭代次数必须大于0");
+            
+            return new DataEncryptionKey(
+                Rfc2898DeriveBytes.Pbkdf2(passphrase, salt, iterations, HashAlgorithmName.SHA256, KeySize));
+        }
+
+        /// <summary>
+        /// 获取密钥内容的副本
+        /// </summary>
+        public byte[] ToArray() => (byte[])_key.Clone();
+
+        internal ReadOnlySpan<byte> AsSpan() => _key;
+    }
+}
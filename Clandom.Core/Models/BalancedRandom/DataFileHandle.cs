@@ -0,0 +1,106 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics.CodeAnalysis;
+using System.IO;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 数据文件句柄，缓存已解析的数据
+    /// 文件的修改时间和大小未变化时直接使用缓存，被其他进程修改后会重新读取；
+    /// 多个实例共享同一个句柄时可避免每次保存都重新解析整个文件
+    /// </summary>
+    public class DataFileHandle
+    {
+        private Dictionary<string, BalancedRandData>? _cache;
+        private DateTime _lastWriteTimeUtc;
+        private long _length;
+
+        public DataFileHandle(string filePath = "balanced_rand_data.json")
+        {
+            FilePath = filePath;
+        }
+
+        /// <summary>
+        /// 数据文件路径
+        /// </summary>
+        public string FilePath { get; }
+
+        /// <summary>
+        /// 保存时使用的压缩方式，为null时使用 BalancedRandDataManager.Compression
+        /// </summary>
+        public DataCompression? Compression { get; set; }
+
+        /// <summary>
+        /// 获取所有数据的副本
+        /// </summary>
+        public Dictionary<string, BalancedRandData> LoadAll()
+        {
+            return new Dictionary<string, BalancedRandData>(GetData());
+        }
+
+        /// <summary>
+        /// 查找指定ID的数据
+        /// </summary>
+        public bool TryGetEntry(string id, [MaybeNullWhen(false)] out BalancedRandData data)
+        {
+            return GetData().TryGetValue(id, out data);
+        }
+
+        /// <summary>
+        /// 保存一条数据（会先合并其他进程写入的内容）
+        /// </summary>
+        public void SaveEntry(BalancedRandData data)
+        {
+            // 与 BalancedRandDataManager 的其他写入互斥，检查修改时间和写入之间文件不会被同一进程中的其他保存修改
+            lock (BalancedRandDataManager.FileLock)
+            {
+                var allData = GetData();
+                allData[data.Id] = data;
+
+                try
+                {
+                    BalancedRandDataManager.WriteAllData(allData, FilePath, Compression);
+                    RefreshStamp();
+                }
+                catch
+                {
+                    // 写入失败时缓存与文件不一致，下次读取时重新加载
+                    Invalidate();
+                    throw;
+                }
+            }
+        }
+
+        /// <summary>
+        /// 丢弃缓存，下次访问时重新读取文件
+        /// </summary>
+        public void Invalidate()
+        {
+            _cache = null;
+        }
+
+        private Dictionary<string, BalancedRandData> GetData()
+        {
+            var file = new FileInfo(FilePath);
+            var lastWriteTimeUtc = file.Exists ? file.LastWriteTimeUtc : DateTime.MinValue;
+            var length = file.Exists ? file.Length : -1;
+
+            if (_cache == null || lastWriteTimeUtc != _lastWriteTimeUtc || length != _length)
+            {
+                _cache = BalancedRandDataManager.ReadAllData(FilePath);
+                _lastWriteTimeUtc = lastWriteTimeUtc;
+                _length = length;
+            }
+
+            return _cache;
+        }
+
+        private void RefreshStamp()
+        {
+            var file = new FileInfo(FilePath);
+            _lastWriteTimeUtc = file.Exists ? file.LastWriteTimeUtc : DateTime.MinValue;
+            _length = file.Exists ? file.Length : -1;
+        }
+    }
+}
@@ -0,0 +1,23 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 导入导出配置时使用的文件格式
+    /// </summary>
+    public enum DataFormat
+    {
+        /// <summary>
+        /// JSON（与数据文件相同的格式）
+        /// </summary>
+        Json,
+        
+        /// <summary>
+        /// YAML
+        /// </summary>
+        Yaml,
+        
+        /// <summary>
+        /// TOML（不能表示null，值为null的字段不会导出）
+        /// </summary>
+        Toml
+    }
+}
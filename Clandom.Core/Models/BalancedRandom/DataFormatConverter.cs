@@ -0,0 +1,392 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Text.Json;
+using System.Text.Json.Nodes;
+using System.Text.Json.Serialization.Metadata;
+using System.Text.RegularExpressions;
+using Tomlyn;
+using Tomlyn.Model;
+using YamlDotNet.Core;
+using YamlDotNet.RepresentationModel;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// BalancedRandData 与 YAML / TOML 之间的转换
+    /// 
+    /// 转换都以JSON为中间格式：导出时先按数据文件的格式序列化为JSON再转换，导入时先转换为JSON再反序列化，
+    /// 因此字段名、枚举和日期的写法与数据文件相同。导入时不认识的字段会被拒绝，并给出所在的行列。
+    /// </summary>
+    internal static class DataFormatConverter
+    {
+        #region 导出
+
+        public static string ToJson(BalancedRandData data)
+        {
+            return JsonSerializer.Serialize(data, BalancedRandDataManager.JsonOptions);
+        }
+
+        public static string ToYaml(BalancedRandData data)
+        {
+            var root = ToYamlNode(JsonSerializer.SerializeToElement(data, BalancedRandDataManager.JsonOptions));
+            
+            using var writer = new StringWriter(CultureInfo.InvariantCulture);
+            new YamlStream(new YamlDocument(root)).Save(writer, false);
+            return writer.ToString();
+        }
+
+        public static string ToToml(BalancedRandData data)
+        {
+            var element = JsonSerializer.SerializeToElement(data, BalancedRandDataManager.JsonOptions);
+            var builder = new StringBuilder();
+            WriteTomlTable(builder, element, new List<string>(), isArrayItem: false);
+            return builder.ToString();
+        }
+
+        private static YamlNode ToYamlNode(JsonElement element)
+        {
+            switch (element.ValueKind)
+            {
+                case JsonValueKind.Object:
+                    var mapping = new YamlMappingNode();
+                    foreach (var property in element.EnumerateObject())
+                    {
+                        mapping.Add(property.Name, ToYamlNode(property.Value));
+                    }
+                    return mapping;
+                case JsonValueKind.Array:
+                    var sequence = new YamlSequenceNode();
+                    foreach (var item in element.EnumerateArray())
+                    {
+                        sequence.Add(ToYamlNode(item));
+                    }
+                    return sequence;
+                case JsonValueKind.String:
+                    // 字符串总是加引号，避免被读成数字或布尔值
+                    return new YamlScalarNode(element.GetString()) { Style = ScalarStyle.DoubleQuoted };
+                case JsonValueKind.Null:
+                    return new YamlScalarNode("null");
+                default:
+                    return new YamlScalarNode(element.GetRawText());
+            }
+        }
+
+        /// <summary>
+        /// 写入一个表：先写入普通的值，再写入子表和表数组
+        /// </summary>
+        private static void WriteTomlTable(StringBuilder builder, JsonElement table, List<string> path, bool isArrayItem)
+        {
+            var properties = table.EnumerateObject().Where(p => p.Value.ValueKind != JsonValueKind.Null).ToList();
+            
+            if (path.Count > 0)
+            {
+                if (builder.Length > 0) builder.AppendLine();
+                string header = string.Join(".", path.Select(TomlKey));
+                builder.AppendLine(isArrayItem ? $"[[{header}]]" : $"[{header}]");
+            }
+            
+            foreach (var property in properties.Where(p => !IsTomlTable(p.Value) && !IsTomlTableArray(p.Value)))
+            {
+                builder.Append(TomlKey(property.Name)).Append(" = ").AppendLine(TomlInlineValue(property.Value));
+            }
+            
+            foreach (var property in properties.Where(p => IsTomlTable(p.Value)))
+            {
+                WriteTomlTable(builder, property.Value, new List<string>(path) { property.Name }, isArrayItem: false);
+            }
+            
+            foreach (var property in properties.Where(p => IsTomlTableArray(p.Value)))
+            {
+                foreach (var item in property.Value.EnumerateArray())
+                {
+                    WriteTomlTable(builder, item, new List<string>(path) { property.Name }, isArrayItem: true);
+                }
+            }
+        }
+
+        private static bool IsTomlTable(JsonElement element) => element.ValueKind == JsonValueKind.Object;
+
+        private static bool IsTomlTableArray(JsonElement element)
+        {
+            return element.ValueKind == JsonValueKind.Array &&
+                   element.GetArrayLength() > 0 &&
+                   element.EnumerateArray().All(item => item.ValueKind == JsonValueKind.Object);
+        }
+
+        private static string TomlInlineValue(JsonElement element)
+        {
+            switch (element.ValueKind)
+            {
+                case JsonValueKind.Object:
+                    var pairs = element.EnumerateObject()
+                        .Where(p => p.Value.ValueKind != JsonValueKind.Null)
+                        .Select(p => $"{TomlKey(p.Name)} = {TomlInlineValue(p.Value)}");
+                    return $"{{ {string.Join(", ", pairs)} }}";
+                case JsonValueKind.Array:
+                    var items = element.EnumerateArray()
+                        .Where(item => item.ValueKind != JsonValueKind.Null)
+                        .Select(TomlInlineValue);
+                    return $"[{string.Join(", ", items)}]";
+                case JsonValueKind.String:
+                    return TomlString(element.GetString()!);
+                case JsonValueKind.True:
+                    return "true";
+                case JsonValueKind.False:
+                    return "false";
+                default:
+                    return element.GetRawText();
+            }
+        }
+
+        private static string TomlKey(string key)
+        {
+            return Regex.IsMatch(key, "^[A-Za-z0-9_-]+$") ? key : TomlString(key);
+        }
+
+        private static string TomlString(string value)
+        {
+            var builder = new StringBuilder("\"");
+            foreach (char c in value)
+            {
+                switch (c)
+                {
+                    case '"': builder.Append("\\\""); break;
+                    case '\\': builder.Append("\\\\"); break;
+                    case '\n': builder.Append("\\n"); break;
+                    case '\r': builder.Append("\\r"); break;
+                    case '\t': builder.Append("\\t"); break;
+                    default:
+                        if (char.IsControl(c))
+                            builder.Append($"\\u{(int)c:X4}");
+                        else
+                            builder.Append(c);
+                        break;
+                }
+            }
+            return builder.Append('"').ToString();
+        }
+
+        #endregion
+
+        #region 导入
+
+        public static BalancedRandData FromJson(string json)
+        {
+            JsonNode? node;
+            try
+            {
+                node = JsonNode.Parse(json);
+            }
+            catch (JsonException ex)
+            {
+                throw new InvalidDataException($"第{ex.LineNumber + 1}行第{ex.BytePositionInLine + 1}列: JSON格式错误: {ex.Message}", ex);
+            }
+            
+            return Deserialize(node, path => null);
+        }
+
+        public static BalancedRandData FromYaml(string yaml)
+        {
+            var stream = new YamlStream();
+            try
+            {
+                stream.Load(new StringReader(yaml));
+            }
+            catch (YamlException ex)
+            {
+                throw new InvalidDataException($"第{ex.Start.Line}行第{ex.Start.Column}列: YAML格式错误: {ex.Message}", ex);
+            }
+            
+            if (stream.Documents.Count == 0)
+                throw new InvalidDataException("YAML内容为空");
+            
+            var locations = new Dictionary<string, (long Line, long Column)>();
+            var node = FromYamlNode(stream.Documents[0].RootNode, "$", locations);
+            return Deserialize(node, path => locations.TryGetValue(path, out var location) ? location : null);
+        }
+
+        public static BalancedRandData FromToml(string toml)
+        {
+            TomlTable table;
+            try
+            {
+                table = Toml.ToModel(toml);
+            }
+            catch (TomlException ex)
+            {
+                // 错误信息中包含行列
+                throw new InvalidDataException($"TOML格式错误: {ex.Message}", ex);
+            }
+            
+            var lines = toml.Split('\n');
+            return Deserialize(FromTomlValue(table), path => FindTomlKey(lines, path));
+        }
+
+        private static JsonNode? FromYamlNode(YamlNode node, string path, Dictionary<string, (long Line, long Column)> locations)
+        {
+            locations[path] = (node.Start.Line, node.Start.Column);
+            
+            switch (node)
+            {
+                case YamlMappingNode mapping:
+                    var obj = new JsonObject();
+                    foreach (var child in mapping.Children)
+                    {
+                        string key = ((YamlScalarNode)child.Key).Value ?? string.Empty;
+                        string childPath = $"{path}.{key}";
+                        obj[key] = FromYamlNode(child.Value, childPath, locations);
+                        locations[childPath] = (child.Key.Start.Line, child.Key.Start.Column);
+                    }
+                    return obj;
+                case YamlSequenceNode sequence:
+                    var array = new JsonArray();
+                    int index = 0;
+                    foreach (var child in sequence.Children)
+                    {
+                        array.Add(FromYamlNode(child, $"{path}[{index++}]", locations));
+                    }
+                    return array;
+                case YamlScalarNode scalar:
+                    return scalar.Style == ScalarStyle.Plain ? FromPlainScalar(scalar.Value) : JsonValue.Create(scalar.Value);
+                default:
+                    throw new InvalidDataException($"第{node.Start.Line}行第{node.Start.Column}列: 不支持的YAML内容");
+            }
+        }
+
+        /// <summary>
+        /// 不带引号的YAML值按 null、布尔值、数字、字符串的顺序识别
+        /// </summary>
+        private static JsonNode? FromPlainScalar(string? value)
+        {
+            if (string.IsNullOrEmpty(value) || value == "~" || value == "null")
+                return null;
+            if (value == "true" || value == "false")
+                return JsonValue.Create(value == "true");
+            if (long.TryParse(value, NumberStyles.Integer, CultureInfo.InvariantCulture, out var integer))
+                return JsonValue.Create(integer);
+            if (double.TryParse(value, NumberStyles.Float, CultureInfo.InvariantCulture, out var number) && double.IsFinite(number))
+                return JsonValue.Create(number);
+            return JsonValue.Create(value);
+        }
+
+        private static JsonNode? FromTomlValue(object? value)
+        {
+            switch (value)
+            {
+                case TomlTable table:
+                    var obj = new JsonObject();
+                    foreach (var kvp in table)
+                    {
+                        obj[kvp.Key] = FromTomlValue(kvp.Value);
+                    }
+                    return obj;
+                case TomlTableArray tables:
+                    return new JsonArray(tables.Select(t => FromTomlValue(t)).ToArray());
+                case TomlArray items:
+                    return new JsonArray(items.Select(FromTomlValue).ToArray());
+                case TomlDateTime dateTime:
+                    return JsonValue.Create(dateTime.DateTime);
+                case string s:
+                    return JsonValue.Create(s);
+                case long l:
+                    return JsonValue.Create(l);
+                case double d:
+                    return JsonValue.Create(d);
+                case bool b:
+                    return JsonValue.Create(b);
+                default:
+                    return null;
+            }
+        }
+
+        /// <summary>
+        /// 在TOML文本中查找字段所在的行列（按字段名查找赋值或表头，找不到时返回null）
+        /// </summary>
+        private static (long Line, long Column)? FindTomlKey(string[] lines, string path)
+        {
+            string key = Regex.Replace(path, @"\[\d+\]$", "");
+            key = key.Substring(key.LastIndexOf('.') + 1);
+            var pattern = new Regex($@"^\s*(\[\[?[^\]]*)?""?{Regex.Escape(key)}""?\s*(=|\]|\.)");
+            
+            for (int i = 0; i < lines.Length; i++)
+            {
+                var match = pattern.Match(lines[i]);
+                if (match.Success)
+                    return (i + 1, lines[i].IndexOf(key, StringComparison.Ordinal) + 1);
+            }
+            return null;
+        }
+
+        /// <summary>
+        /// 检查不认识的字段后反序列化
+        /// </summary>
+        private static BalancedRandData Deserialize(JsonNode? node, Func<string, (long Line, long Column)?> locate)
+        {
+            if (node is not JsonObject)
+                throw new InvalidDataException("内容必须是一个对象");
+            
+            CheckUnknownFields(node, typeof(BalancedRandData), "$", locate);
+            
+            try
+            {
+                return node.Deserialize<BalancedRandData>(BalancedRandDataManager.JsonOptions)
+                    ?? throw new InvalidDataException("内容为空");
+            }
+            catch (JsonException ex)
+            {
+                throw new InvalidDataException($"{Describe(ex.Path ?? "$", locate)}: {ex.Message}", ex);
+            }
+        }
+
+        private static void CheckUnknownFields(JsonNode? node, Type type, string path, Func<string, (long Line, long Column)?> locate)
+        {
+            if (node == null) return;
+            
+            type = Nullable.GetUnderlyingType(type) ?? type;
+            var typeInfo = BalancedRandDataManager.JsonOptions.GetTypeInfo(type);
+            
+            switch (typeInfo.Kind)
+            {
+                case JsonTypeInfoKind.Object when node is JsonObject obj:
+                    var properties = typeInfo.Properties
+                        .Where(p => !p.IsExtensionData)
+                        .ToDictionary(p => p.Name, p => p.PropertyType);
+                    foreach (var kvp in obj)
+                    {
+                        string childPath = $"{path}.{kvp.Key}";
+                        if (!properties.TryGetValue(kvp.Key, out var propertyType))
+                            throw new InvalidDataException($"{Describe(childPath, locate)}: 不认识的字段 {kvp.Key}");
+                        
+                        CheckUnknownFields(kvp.Value, propertyType, childPath, locate);
+                    }
+                    break;
+                case JsonTypeInfoKind.Dictionary when node is JsonObject dictionary:
+                    foreach (var kvp in dictionary)
+                    {
+                        CheckUnknownFields(kvp.Value, typeInfo.ElementType!, $"{path}.{kvp.Key}", locate);
+                    }
+                    break;
+                case JsonTypeInfoKind.Enumerable when node is JsonArray array:
+                    for (int i = 0; i < array.Count; i++)
+                    {
+                        CheckUnknownFields(array[i], typeInfo.ElementType!, $"{path}[{i}]", locate);
+                    }
+                    break;
+            }
+        }
+
+        private static string Describe(string path, Func<string, (long Line, long Column)?> locate)
+        {
+            var location = locate(path);
+            return location.HasValue
+                ? $"第{location.Value.Line}行第{location.Value.Column}列（{path}）"
+                : path;
+        }
+
+        #endregion
+    }
+}
@@ -0,0 +1,22 @@
+using System;
+using System.IO;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 加密的数据文件无法解密：密钥错误，或文件被篡改、截断
+    /// </summary>
+    public class DecryptionFailedException : InvalidDataException
+    {
+        /// <summary>
+        /// 无法解密的文件路径
+        /// </summary>
+        public string FilePath { get; }
+
+        public DecryptionFailedException(string filePath, Exception? innerException = null)
+            : base($"数据文件解密失败，密钥错误或文件已被修改: {filePath}", innerException)
+        {
+            FilePath = filePath;
+        }
+    }
+}
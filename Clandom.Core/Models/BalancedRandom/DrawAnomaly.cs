@@ -0,0 +1,57 @@
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 异常的类型
+    /// </summary>
+    public enum AnomalyKind
+    {
+        /// <summary>
+        /// 在最近的抽取中被抽中的次数明显多于预期
+        /// </summary>
+        FrequentDraws,
+        
+        /// <summary>
+        /// 距上次被抽中的抽取次数明显长于预期
+        /// </summary>
+        LongWait
+    }
+
+    /// <summary>
+    /// 统计上不太可能出现的抽取结果（供界面显示，序列化后的字段名保持稳定）
+    /// </summary>
+    public class DrawAnomaly
+    {
+        [JsonPropertyName("kind")]
+        [JsonConverter(typeof(JsonStringEnumConverter))]
+        public AnomalyKind Kind { get; set; }
+        
+        [JsonPropertyName("number")]
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 观察到的值：FrequentDraws 为窗口内的抽中次数，LongWait 为距上次被抽中的抽取次数
+        /// </summary>
+        [JsonPropertyName("observed")]
+        public int Observed { get; set; }
+        
+        /// <summary>
+        /// 预期值：FrequentDraws 为窗口内的预期抽中次数，LongWait 为预期的等待抽取次数
+        /// </summary>
+        [JsonPropertyName("expected")]
+        public double Expected { get; set; }
+        
+        /// <summary>
+        /// 在公平抽取下出现不低于观察值的结果的概率
+        /// </summary>
+        [JsonPropertyName("pValue")]
+        public double PValue { get; set; }
+
+        /// <summary>
+        /// 序列化为单行JSON
+        /// </summary>
+        public string ToJson() => JsonSerializer.Serialize(this);
+    }
+}
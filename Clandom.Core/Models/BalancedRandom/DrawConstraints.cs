@@ -0,0 +1,20 @@
+using System.Collections.Generic;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 只对一次批量抽取（DrawMultipleWith）生效的限制条件
+    /// </summary>
+    public class DrawConstraints
+    {
+        /// <summary>
+        /// 必须抽中的学号，会最先被抽中并计入抽取记录
+        /// </summary>
+        public List<int> Include { get; set; } = new List<int>();
+        
+        /// <summary>
+        /// 本次不能抽中的学号
+        /// </summary>
+        public List<int> Exclude { get; set; } = new List<int>();
+    }
+}
@@ -0,0 +1,49 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 实例状态的简要指标（用于监控面板，可以频繁获取）
+    /// 抽取次数相关的指标只统计不在黑名单中的活跃学号
+    /// </summary>
+    public class DrawMetrics
+    {
+        /// <summary>
+        /// 总抽取次数
+        /// </summary>
+        public int TotalDraws { get; set; }
+        
+        /// <summary>
+        /// 当前轮次
+        /// </summary>
+        public int CurrentRound { get; set; }
+        
+        /// <summary>
+        /// 当前候选池大小
+        /// </summary>
+        public int PoolSize { get; set; }
+        
+        /// <summary>
+        /// 可抽取的学号数（不在黑名单中；白名单模式下为白名单中的学号）
+        /// </summary>
+        public int EligibleCount { get; set; }
+        
+        /// <summary>
+        /// 抽取次数最多与最少的学号之间的差距
+        /// </summary>
+        public int MaxGap { get; set; }
+        
+        /// <summary>
+        /// 平均抽取次数
+        /// </summary>
+        public double Mean { get; set; }
+        
+        /// <summary>
+        /// 抽取次数的基尼系数（0为完全平均，越接近1越集中），没有抽取时为0
+        /// </summary>
+        public double Gini { get; set; }
+        
+        /// <summary>
+        /// 抽取分布的香农熵（以2为底，越大越平均），没有抽取时为0
+        /// </summary>
+        public double Entropy { get; set; }
+    }
+}
@@ -0,0 +1,35 @@
+using System.Collections.Generic;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 一次抽取的结果及做出选择时的完整状态（用于记录和审计）
+    /// </summary>
+    public class DrawOutcome
+    {
+        /// <summary>
+        /// 被抽中的学号
+        /// </summary>
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 本次抽取的轮次
+        /// </summary>
+        public int Round { get; set; }
+        
+        /// <summary>
+        /// 抽取前的候选池，按学号顺序排列
+        /// </summary>
+        public List<int> Pool { get; set; } = new List<int>();
+        
+        /// <summary>
+        /// 抽取时每个候选学号的权重
+        /// </summary>
+        public Dictionary<int, double> Weights { get; set; } = new Dictionary<int, double>();
+        
+        /// <summary>
+        /// 抽取时每个候选学号被抽中的概率
+        /// </summary>
+        public Dictionary<int, double> Probabilities { get; set; } = new Dictionary<int, double>();
+    }
+}
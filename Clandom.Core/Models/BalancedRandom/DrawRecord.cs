@@ -0,0 +1,37 @@
+using System;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 一次抽取的记录
+    /// </summary>
+    public class DrawRecord
+    {
+        /// <summary>
+        /// 抽取时的轮次
+        /// </summary>
+        public int Round { get; set; }
+        
+        /// <summary>
+        /// 被抽中的学号
+        /// </summary>
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 抽取时该学号被抽中的概率
+        /// </summary>
+        public double Probability { get; set; }
+        
+        /// <summary>
+        /// 抽取时间（UTC）
+        /// </summary>
+        public DateTime Timestamp { get; set; }
+        
+        /// <summary>
+        /// 排名抽取（DrawRanked）时的名次，普通抽取为null
+        /// </summary>
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public int? Rank { get; set; }
+    }
+}
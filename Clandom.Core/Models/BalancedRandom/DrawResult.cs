@@ -0,0 +1,44 @@
+using System;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 一次抽取的结果（供界面和命令行输出使用，序列化后的字段名保持稳定）
+    /// </summary>
+    public class DrawResult
+    {
+        [JsonPropertyName("number")]
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 2D抽取时的位置 [行, 列]（从1开始），不是2D抽取或不在网格中时为null
+        /// </summary>
+        [JsonPropertyName("position")]
+        public int[]? Position { get; set; }
+        
+        /// <summary>
+        /// 抽取时该学号被抽中的概率
+        /// </summary>
+        [JsonPropertyName("probability")]
+        public double Probability { get; set; }
+        
+        /// <summary>
+        /// 本次抽取的轮次
+        /// </summary>
+        [JsonPropertyName("round")]
+        public int Round { get; set; }
+        
+        /// <summary>
+        /// 抽取时间（UTC）
+        /// </summary>
+        [JsonPropertyName("timestamp")]
+        public DateTime Timestamp { get; set; }
+
+        /// <summary>
+        /// 序列化为单行JSON
+        /// </summary>
+        public string ToJson() => JsonSerializer.Serialize(this);
+    }
+}
@@ -0,0 +1,18 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 候选池为空时 Draw 的处理方式
+    /// </summary>
+    public enum EmptyPoolPolicy
+    {
+        /// <summary>
+        /// 重置所有抽取次数后继续抽取（默认）
+        /// </summary>
+        Reset,
+        
+        /// <summary>
+        /// 抛出 InvalidOperationException
+        /// </summary>
+        Error
+    }
+}
@@ -0,0 +1,74 @@
+using System;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 长期未被抽中的学号的权重提升曲线
+    /// </summary>
+    public enum GapBoostCurve
+    {
+        /// <summary>
+        /// 按间隔轮次的自然对数增长（默认，增长最缓）
+        /// </summary>
+        Log,
+        
+        /// <summary>
+        /// 按间隔轮次线性增长（增长最快）
+        /// </summary>
+        Linear,
+        
+        /// <summary>
+        /// 按间隔轮次的平方根增长
+        /// </summary>
+        Sqrt
+    }
+
+    /// <summary>
+    /// 长期未被抽中的学号的权重提升设置
+    /// 距上次被抽中的轮次超过 活跃学号数 × ThresholdFraction 时，权重乘以 1 + Scale × 曲线(间隔轮次)。
+    /// 默认值与之前固定的公式 1 + ln(间隔 + 1) / 10（超过一半轮次未抽中时）相同
+    /// </summary>
+    public class GapBoost
+    {
+        /// <summary>
+        /// 开始提升的间隔（占活跃学号数的比例）
+        /// </summary>
+        public double ThresholdFraction { get; set; } = 0.5;
+        
+        /// <summary>
+        /// 提升系数，0表示不提升
+        /// </summary>
+        public double Scale { get; set; } = 0.1;
+        
+        /// <summary>
+        /// 提升曲线
+        /// </summary>
+        public GapBoostCurve Curve { get; set; } = GapBoostCurve.Log;
+
+        /// <summary>
+        /// 计算间隔为 gap 轮时的权重倍数（未超过阈值时为1）
+        /// </summary>
+        internal double GetMultiplier(int gap, int activeNumbersCount)
+        {
+            if (gap <= (int)(activeNumbersCount * ThresholdFraction))
+                return 1.0;
+            
+            double value = Curve switch
+            {
+                GapBoostCurve.Linear => gap,
+                GapBoostCurve.Sqrt => Math.Sqrt(gap),
+                _ => Math.Log(gap + 1)
+            };
+            return 1.0 + Scale * value;
+        }
+
+        internal GapBoost Clone() => new GapBoost { ThresholdFraction = ThresholdFraction, Scale = Scale, Curve = Curve };
+
+        public override bool Equals(object? obj)
+        {
+            return obj is GapBoost other && ThresholdFraction == other.ThresholdFraction && Scale == other.Scale && Curve == other.Curve;
+        }
+
+        public override int GetHashCode() => HashCode.Combine(ThresholdFraction, Scale, Curve);
+    }
+}
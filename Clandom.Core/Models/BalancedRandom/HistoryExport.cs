@@ -0,0 +1,90 @@
+using System.Collections.Generic;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 导出的抽取历史（用于绘制图表，只用于导出，不能重新加载）
+    /// </summary>
+    public class HistoryExport
+    {
+        /// <summary>
+        /// 概要信息
+        /// </summary>
+        [JsonPropertyName("summary")]
+        public HistoryExportSummary Summary { get; set; } = new HistoryExportSummary();
+        
+        /// <summary>
+        /// 按抽取顺序排列的抽取记录
+        /// </summary>
+        [JsonPropertyName("history")]
+        public List<HistoryExportEntry> History { get; set; } = new List<HistoryExportEntry>();
+    }
+
+    /// <summary>
+    /// 导出的抽取历史的概要信息
+    /// </summary>
+    public class HistoryExportSummary
+    {
+        [JsonPropertyName("dataId")]
+        public string DataId { get; set; } = string.Empty;
+        
+        [JsonPropertyName("type")]
+        public string Type { get; set; } = string.Empty;
+        
+        [JsonPropertyName("displayName")]
+        public string? DisplayName { get; set; }
+        
+        /// <summary>
+        /// 导出时的调节参数
+        /// </summary>
+        [JsonPropertyName("config")]
+        public BalancedRandConfig Config { get; set; } = new BalancedRandConfig();
+        
+        [JsonPropertyName("totalDraws")]
+        public int TotalDraws { get; set; }
+        
+        [JsonPropertyName("currentRound")]
+        public int CurrentRound { get; set; }
+        
+        /// <summary>
+        /// 学号 -> 抽取次数
+        /// </summary>
+        [JsonPropertyName("drawCounts")]
+        public Dictionary<int, int> DrawCounts { get; set; } = new Dictionary<int, int>();
+        
+        /// <summary>
+        /// 导出时间（RFC3339，UTC）
+        /// </summary>
+        [JsonPropertyName("exportedAt")]
+        public string ExportedAt { get; set; } = string.Empty;
+    }
+
+    /// <summary>
+    /// 导出的一次抽取记录
+    /// </summary>
+    public class HistoryExportEntry
+    {
+        [JsonPropertyName("round")]
+        public int Round { get; set; }
+        
+        [JsonPropertyName("number")]
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 显示名称，没有设置时不输出
+        /// </summary>
+        [JsonPropertyName("label")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public string? Label { get; set; }
+        
+        [JsonPropertyName("probability")]
+        public double Probability { get; set; }
+        
+        /// <summary>
+        /// 抽取时间（RFC3339，UTC）
+        /// </summary>
+        [JsonPropertyName("timestamp")]
+        public string Timestamp { get; set; } = string.Empty;
+    }
+}
@@ -0,0 +1,35 @@
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 黑名单/白名单替换前后的变化
+    /// </summary>
+    public class ListDiff
+    {
+        /// <summary>
+        /// 新加入名单的学号
+        /// </summary>
+        public List<int> Added { get; } = new List<int>();
+        
+        /// <summary>
+        /// 从名单中移除的学号
+        /// </summary>
+        public List<int> Removed { get; } = new List<int>();
+        
+        /// <summary>
+        /// 因不合法（如不在学号范围内）而未加入名单的学号
+        /// </summary>
+        public List<int> Rejected { get; } = new List<int>();
+
+        internal static ListDiff Create(HashSet<int> previous, HashSet<int> current, HashSet<int> rejected)
+        {
+            var diff = new ListDiff();
+            diff.Added.AddRange(current.Except(previous).OrderBy(n => n));
+            diff.Removed.AddRange(previous.Except(current).OrderBy(n => n));
+            diff.Rejected.AddRange(rejected.OrderBy(n => n));
+            return diff;
+        }
+    }
+}
@@ -0,0 +1,18 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 合并另一个实例的抽取次数时的合并方式
+    /// </summary>
+    public enum MergeStrategy
+    {
+        /// <summary>
+        /// 两边的抽取次数相加（两个实例分别抽取了不同的轮次，如代课老师使用了另一个实例）
+        /// </summary>
+        Sum,
+        
+        /// <summary>
+        /// 取两边抽取次数的较大值（两个实例有共同的抽取记录，如从同一份数据复制出来）
+        /// </summary>
+        Max
+    }
+}
@@ -0,0 +1,195 @@
+using System.Collections;
+using System.Collections.Generic;
+using System.Diagnostics.CodeAnalysis;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 学号 -> 值 的映射表
+    /// 连续学号范围使用数组存储（按 学号 - 起始值 索引），范围外的学号和列表类型使用字典存储，
+    /// 对外行为与 Dictionary 一致
+    /// </summary>
+    internal sealed class NumberMap<TValue> : IEnumerable<KeyValuePair<int, TValue>>
+    {
+        // 连续范围部分
+        private readonly int _offset;
+        private readonly TValue[] _values;
+        private readonly bool[] _present;
+        private int _denseCount;
+
+        // 稀疏部分（列表类型或范围外的学号）
+        private readonly Dictionary<int, TValue> _sparse;
+
+        /// <summary>
+        /// 创建只使用字典存储的映射表
+        /// </summary>
+        public NumberMap() : this(0)
+        {
+        }
+
+        /// <summary>
+        /// 创建只使用字典存储的映射表，并预先分配 capacity 个学号的空间（避免大量学号时反复扩容）
+        /// </summary>
+        /// <param name="capacity">预计的学号数量</param>
+        public NumberMap(int capacity)
+        {
+            _values = System.Array.Empty<TValue>();
+            _present = System.Array.Empty<bool>();
+            _sparse = new Dictionary<int, TValue>(capacity);
+        }
+
+        /// <summary>
+        /// 创建为连续学号范围使用数组存储的映射表
+        /// </summary>
+        /// <param name="offset">范围起始学号</param>
+        /// <param name="length">范围长度</param>
+        public NumberMap(int offset, int length)
+        {
+            _offset = offset;
+            _values = new TValue[length];
+            _present = new bool[length];
+            _sparse = new Dictionary<int, TValue>();
+        }
+
+        private NumberMap(NumberMap<TValue> other)
+        {
+            _offset = other._offset;
+            _values = (TValue[])other._values.Clone();
+            _present = (bool[])other._present.Clone();
+            _denseCount = other._denseCount;
+            _sparse = new Dictionary<int, TValue>(other._sparse);
+        }
+
+        /// <summary>
+        /// 创建副本
+        /// </summary>
+        public NumberMap<TValue> Clone() => new NumberMap<TValue>(this);
+
+        /// <summary>
+        /// 是否使用数组存储
+        /// </summary>
+        public bool IsDense => _values.Length > 0;
+
+        public int Count => _denseCount + _sparse.Count;
+
+        public TValue this[int number]
+        {
+            get
+            {
+                if (TryGetValue(number, out var value))
+                    return value;
+                throw new KeyNotFoundException($"学号不存在: {number}");
+            }
+            set
+            {
+                int index = number - _offset;
+                if (IsDenseIndex(number, index))
+                {
+                    _values[index] = value;
+                    if (!_present[index])
+                    {
+                        _present[index] = true;
+                        _denseCount++;
+                    }
+                }
+                else
+                {
+                    _sparse[number] = value;
+                }
+            }
+        }
+
+        public bool TryGetValue(int number, [MaybeNullWhen(false)] out TValue value)
+        {
+            int index = number - _offset;
+            if (IsDenseIndex(number, index))
+            {
+                value = _values[index];
+                return _present[index];
+            }
+            return _sparse.TryGetValue(number, out value);
+        }
+
+        public bool ContainsKey(int number)
+        {
+            int index = number - _offset;
+            if (IsDenseIndex(number, index))
+                return _present[index];
+            return _sparse.ContainsKey(number);
+        }
+
+        public bool Remove(int number)
+        {
+            int index = number - _offset;
+            if (IsDenseIndex(number, index))
+            {
+                if (!_present[index]) return false;
+                _present[index] = false;
+                _values[index] = default!;
+                _denseCount--;
+                return true;
+            }
+            return _sparse.Remove(number);
+        }
+
+        public void Clear()
+        {
+            System.Array.Clear(_values);
+            System.Array.Clear(_present);
+            _denseCount = 0;
+            _sparse.Clear();
+        }
+
+        public IEnumerable<int> Keys
+        {
+            get
+            {
+                foreach (var kvp in this)
+                    yield return kvp.Key;
+            }
+        }
+
+        public IEnumerable<TValue> Values
+        {
+            get
+            {
+                foreach (var kvp in this)
+                    yield return kvp.Value;
+            }
+        }
+
+        /// <summary>
+        /// 转换为字典（用于保存数据）
+        /// </summary>
+        public Dictionary<int, TValue> ToDictionary()
+        {
+            var result = new Dictionary<int, TValue>(Count);
+            foreach (var kvp in this)
+            {
+                result[kvp.Key] = kvp.Value;
+            }
+            return result;
+        }
+
+        public IEnumerator<KeyValuePair<int, TValue>> GetEnumerator()
+        {
+            for (int i = 0; i < _values.Length; i++)
+            {
+                if (_present[i])
+                    yield return new KeyValuePair<int, TValue>(i + _offset, _values[i]);
+            }
+            foreach (var kvp in _sparse)
+            {
+                yield return kvp;
+            }
+        }
+
+        IEnumerator IEnumerable.GetEnumerator() => GetEnumerator();
+
+        private bool IsDenseIndex(int number, int index)
+        {
+            // 防止 number - _offset 溢出后落入数组范围
+            return index >= 0 && index < _values.Length && (long)number - _offset == index;
+        }
+    }
+}
@@ -0,0 +1,73 @@
+using System;
+using System.Collections.Generic;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 持有一组实例，释放时一次性保存所有实例的数据
+    /// 用于程序退出前保存（如窗口关闭时调用 Dispose，或使用 using），
+    /// 保存方式与 BalancedRandDataManager.SaveEntries 相同，只读写一次数据文件
+    /// </summary>
+    public sealed class PersistenceGuard : IDisposable
+    {
+        private readonly List<BalancedRand> _instances = new List<BalancedRand>();
+        private bool _disposed;
+
+        public PersistenceGuard(string filePath = "balanced_rand_data.json")
+        {
+            FilePath = filePath;
+        }
+
+        /// <summary>
+        /// 数据文件路径
+        /// </summary>
+        public string FilePath { get; }
+
+        /// <summary>
+        /// 添加需要在退出时保存的实例
+        /// </summary>
+        public void Add(BalancedRand instance)
+        {
+            ObjectDisposedException.ThrowIf(_disposed, this);
+            
+            if (!_instances.Contains(instance))
+            {
+                _instances.Add(instance);
+            }
+        }
+
+        /// <summary>
+        /// 移除实例，移除后不会再保存它
+        /// </summary>
+        public bool Remove(BalancedRand instance)
+        {
+            return _instances.Remove(instance);
+        }
+
+        /// <summary>
+        /// 获取持有的实例
+        /// </summary>
+        public IReadOnlyList<BalancedRand> GetInstances() => _instances.AsReadOnly();
+
+        /// <summary>
+        /// 立即保存所有实例的数据
+        /// </summary>
+        public void Flush()
+        {
+            if (_instances.Count == 0) return;
+            
+            BalancedRandDataManager.SaveEntries(_instances, FilePath);
+        }
+
+        /// <summary>
+        /// 保存所有实例的数据，只在第一次调用时保存
+        /// </summary>
+        public void Dispose()
+        {
+            if (_disposed) return;
+            _disposed = true;
+            
+            Flush();
+        }
+    }
+}
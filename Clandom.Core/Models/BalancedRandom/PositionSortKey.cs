@@ -0,0 +1,23 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 位置统计的排序方式
+    /// </summary>
+    public enum PositionSortKey
+    {
+        /// <summary>
+        /// 按位置（行优先）
+        /// </summary>
+        Position,
+        
+        /// <summary>
+        /// 按抽取次数从多到少，次数相同时按位置
+        /// </summary>
+        Count,
+        
+        /// <summary>
+        /// 按当前概率从高到低，概率相同时按位置
+        /// </summary>
+        Probability
+    }
+}
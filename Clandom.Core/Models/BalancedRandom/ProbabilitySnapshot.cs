@@ -0,0 +1,36 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 某一轮抽取后每个学号的概率（用于绘制公平性随时间变化的图表）
+    /// </summary>
+    public class ProbabilitySnapshot
+    {
+        /// <summary>
+        /// 记录时的轮次
+        /// </summary>
+        public int Round { get; set; }
+        
+        /// <summary>
+        /// 学号 -> 被抽中的概率
+        /// </summary>
+        public Dictionary<int, double> Probabilities { get; set; } = new Dictionary<int, double>();
+        
+        /// <summary>
+        /// 记录时抽取次数最多与最少的学号之间的差距
+        /// </summary>
+        public int MaxGap { get; set; }
+
+        public override bool Equals(object? obj)
+        {
+            return obj is ProbabilitySnapshot other && Round == other.Round && MaxGap == other.MaxGap &&
+                   Probabilities.Count == other.Probabilities.Count &&
+                   Probabilities.All(kvp => other.Probabilities.TryGetValue(kvp.Key, out var p) && p == kvp.Value);
+        }
+
+        public override int GetHashCode() => HashCode.Combine(Round, MaxGap);
+    }
+}
@@ -0,0 +1,23 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 排名抽取（DrawRanked）中的一个名次
+    /// </summary>
+    public class RankedDraw
+    {
+        /// <summary>
+        /// 名次（从1开始）
+        /// </summary>
+        public int Rank { get; set; }
+        
+        /// <summary>
+        /// 抽中的学号
+        /// </summary>
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 抽取该名次时这个学号被抽中的概率
+        /// </summary>
+        public double Probability { get; set; }
+    }
+}
@@ -0,0 +1,35 @@
+using System.Collections.Generic;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 模拟抽取的结果
+    /// </summary>
+    public class SimulationResult
+    {
+        /// <summary>
+        /// 每个学号被抽中的次数
+        /// </summary>
+        public Dictionary<int, int> Counts { get; set; } = new Dictionary<int, int>();
+        
+        /// <summary>
+        /// 抽取次数的方差（越小越平均）
+        /// </summary>
+        public double Variance { get; set; }
+        
+        /// <summary>
+        /// 抽取分布的香农熵（以2为底，越大越平均）
+        /// </summary>
+        public double Entropy { get; set; }
+        
+        /// <summary>
+        /// 抽取次数最多与最少的学号之间的差距
+        /// </summary>
+        public int MaxGap { get; set; }
+        
+        /// <summary>
+        /// 模拟过程中单个学号在一次抽取中被抽中的最大概率
+        /// </summary>
+        public double MaxProbability { get; set; }
+    }
+}
@@ -0,0 +1,120 @@
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 界面刷新时一次获取的完整状态（序列化后的字段名保持稳定，前端依赖这些名称）
+    /// </summary>
+    public class StateSnapshot
+    {
+        /// <summary>
+        /// 每个活跃学号的状态，按学号顺序排列
+        /// </summary>
+        [JsonPropertyName("members")]
+        public List<MemberState> Members { get; set; } = new List<MemberState>();
+        
+        [JsonPropertyName("blacklist")]
+        public List<int> Blacklist { get; set; } = new List<int>();
+        
+        [JsonPropertyName("whitelist")]
+        public List<int> Whitelist { get; set; } = new List<int>();
+        
+        [JsonPropertyName("whitelistOnlyMode")]
+        public bool WhitelistOnlyMode { get; set; }
+        
+        [JsonPropertyName("config")]
+        public StateConfig Config { get; set; } = new StateConfig();
+        
+        [JsonPropertyName("currentRound")]
+        public int CurrentRound { get; set; }
+        
+        [JsonPropertyName("totalDraws")]
+        public int TotalDraws { get; set; }
+        
+        /// <summary>
+        /// 当前候选池，按学号顺序排列
+        /// </summary>
+        [JsonPropertyName("candidatePool")]
+        public List<int> CandidatePool { get; set; } = new List<int>();
+        
+        /// <summary>
+        /// 2D抽取时的行数，不是2D抽取时为null（不输出）
+        /// </summary>
+        [JsonPropertyName("rows")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public int? Rows { get; set; }
+        
+        /// <summary>
+        /// 2D抽取时的列数，不是2D抽取时为null（不输出）
+        /// </summary>
+        [JsonPropertyName("cols")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public int? Cols { get; set; }
+        
+        /// <summary>
+        /// 2D抽取时黑名单中的位置 [行, 列]（从1开始，不含网格外的学号），不是2D抽取时为null（不输出）
+        /// </summary>
+        [JsonPropertyName("blacklistPositions")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public List<int[]>? BlacklistPositions { get; set; }
+        
+        /// <summary>
+        /// 2D抽取时白名单中的位置 [行, 列]（从1开始，不含网格外的学号），不是2D抽取时为null（不输出）
+        /// </summary>
+        [JsonPropertyName("whitelistPositions")]
+        [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+        public List<int[]>? WhitelistPositions { get; set; }
+
+        /// <summary>
+        /// 序列化为单行JSON
+        /// </summary>
+        public string ToJson() => JsonSerializer.Serialize(this);
+    }
+
+    /// <summary>
+    /// 单个学号的状态
+    /// </summary>
+    public class MemberState
+    {
+        [JsonPropertyName("number")]
+        public int Number { get; set; }
+        
+        /// <summary>
+        /// 2D抽取时的位置 [行, 列]（从1开始），不是2D抽取或不在网格中时为null
+        /// </summary>
+        [JsonPropertyName("position")]
+        public int[]? Position { get; set; }
+        
+        [JsonPropertyName("count")]
+        public int Count { get; set; }
+        
+        [JsonPropertyName("probability")]
+        public double Probability { get; set; }
+        
+        /// <summary>
+        /// 最后被抽中的轮次，从未被抽中时为-1
+        /// </summary>
+        [JsonPropertyName("lastRound")]
+        public int LastRound { get; set; }
+    }
+
+    /// <summary>
+    /// 构造参数
+    /// </summary>
+    public class StateConfig
+    {
+        [JsonPropertyName("minPoolSize")]
+        public int MinPoolSize { get; set; }
+        
+        [JsonPropertyName("maxGapThreshold")]
+        public int MaxGapThreshold { get; set; }
+        
+        [JsonPropertyName("coldStartBoost")]
+        public double ColdStartBoost { get; set; }
+        
+        [JsonPropertyName("decayFactor")]
+        public double DecayFactor { get; set; }
+    }
+}
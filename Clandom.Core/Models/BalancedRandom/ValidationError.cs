@@ -0,0 +1,42 @@
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 保存的数据中的一项不合法内容
+    /// </summary>
+    public class ValidationError
+    {
+        /// <summary>
+        /// 不合法的字段名
+        /// </summary>
+        public string Field { get; }
+        
+        /// <summary>
+        /// 错误说明
+        /// </summary>
+        public string Message { get; }
+
+        public ValidationError(string field, string message)
+        {
+            Field = field;
+            Message = message;
+        }
+
+        public override string ToString() => $"{Field}: {Message}";
+    }
+
+    /// <summary>
+    /// 加载数据时对不合法数据的处理方式
+    /// </summary>
+    public enum ValidationMode
+    {
+        /// <summary>
+        /// 跳过不合法的数据并返回警告
+        /// </summary>
+        Skip,
+        
+        /// <summary>
+        /// 遇到不合法的数据时抛出异常
+        /// </summary>
+        Strict
+    }
+}
@@ -0,0 +1,16 @@
+using System;
+
+namespace Clandom.Models.BalancedRandom
+{
+    /// <summary>
+    /// 处于白名单模式但白名单为空时 Draw 抛出的异常
+    /// 这种情况下重置抽取次数也无法得到可抽取的学号，需要添加白名单或关闭白名单模式
+    /// </summary>
+    public class WhitelistOnlyButEmptyException : InvalidOperationException
+    {
+        public WhitelistOnlyButEmptyException()
+            : base("处于白名单模式但白名单为空，没有可抽取的学号；请添加白名单学号或关闭白名单模式")
+        {
+        }
+    }
+}